@@ -0,0 +1,814 @@
+use std::mem;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{add_months, days_in_month, get_diff, EomPolicy, Interval, Unit};
+
+/// How the difference of two dates is broken down into years, months and
+/// days. SQL engines and date libraries disagree around month ends and for
+/// negative differences, so each variant replicates one of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum DiffAlgorithm {
+    /// The algorithm of ```get_diff```.
+    #[default]
+    Default,
+    /// MySQL's ```TIMESTAMPDIFF```, which counts complete units elapsed for
+    /// each unit independently, see ```timestamp_diff```. For dates this
+    /// gives the same breakdown as ```get_diff```, including end of month
+    /// pairs like Jan 31 to Feb 29 being 0 months 29 days.
+    MySql,
+    /// PostgreSQL's ```age(end, start)```. When the end day of month is
+    /// below the start day, days are borrowed from the start date's month,
+    /// while ```get_diff``` borrows from the month before the end date.
+    /// So 1957-06-13 to 2001-04-10 is 43 years 9 months 27 days here (June
+    /// has 30 days) but 28 days with ```get_diff``` (March has 31). A
+    /// negative difference borrows from the month of the earlier date too.
+    PostgresAge,
+    /// Java's ```java.time.Period.between(start, end)```. A positive
+    /// difference is the same as ```get_diff```, but a negative one is
+    /// counted backwards from ```start``` and borrows the length of the
+    /// month of ```end```, the earlier date. So 2019-03-10 to 2019-01-15 is
+    /// 1 month 26 days behind here and 1 month 23 days with ```get_diff```.
+    JavaPeriod,
+    /// Python's ```dateutil.relativedelta(end, start)```. Months are
+    /// counted as long as ```start``` moved by them, clamped to the end of
+    /// the month, doesn't pass ```end```. So Jan 31 to Feb 29 is 1 month,
+    /// and 2020-02-29 to 2021-02-28 is 1 year, where ```get_diff``` gives
+    /// 29 days and 11 months 30 days.
+    RelativeDelta,
+    /// PHP's ```DateTime::diff``` as of PHP 8.1, see ```php_diff``` for
+    /// its ```days``` field. A positive difference borrows from the months
+    /// before the end date as often as needed, without clamping the start
+    /// day, so 2020-01-31 to 2020-03-01 is 30 days where ```get_diff```
+    /// gives 1 month 1 day. A negative one borrows from the earlier date's
+    /// month, like ```DiffAlgorithm::PostgresAge```.
+    Php,
+    /// Guarantees ```interval.apply_to(start, EomPolicy::Clamp) == end```.
+    /// A positive difference is the same as ```get_diff```, which already
+    /// has this property, but a negative one is counted backwards from
+    /// ```start``` like ```DiffAlgorithm::RelativeDelta``` does. So
+    /// 2021-03-30 to 2021-02-28 is 1 month 2 days behind with ```get_diff```,
+    /// which leads to Feb 26, and exactly 1 month behind here.
+    RoundTrip,
+    /// The 30/360 day count convention of commercial interest terms, 30/360
+    /// bond basis in ISDA terms, where every month has 30 days and every year
+    /// 360. A 31st is taken as the 30th, only at the end if the start is the
+    /// 30th or 31st too, so Jan 31 to Mar 1 is 1 month 1 day and Feb 28 to
+    /// Mar 31 is 1 month 3 days. See ```days_360``` for the day count.
+    Thirty360,
+}
+
+impl DiffAlgorithm {
+    /// Tells whether swapping the dates always gives the inverse interval,
+    /// i.e. ```get_diff_by(a, b)``` is ```-get_diff_by(b, a)```. Algorithms
+    /// that count backwards for negative differences don't, see
+    /// ```DiffOptions::with_symmetric``` to make them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::DiffAlgorithm;
+    ///
+    /// # assert!(DiffAlgorithm::Default.is_symmetric());
+    /// # assert!(!DiffAlgorithm::JavaPeriod.is_symmetric());
+    /// ```
+    pub fn is_symmetric(&self) -> bool {
+        match self {
+            DiffAlgorithm::Default
+            | DiffAlgorithm::MySql
+            | DiffAlgorithm::PostgresAge
+            | DiffAlgorithm::Thirty360 => true,
+            DiffAlgorithm::JavaPeriod
+            | DiffAlgorithm::RelativeDelta
+            | DiffAlgorithm::Php
+            | DiffAlgorithm::RoundTrip => false,
+        }
+    }
+}
+
+/// Tells whether ```get_diff(start, end)``` applied to ```start``` with
+/// ```EomPolicy::Clamp``` leads back to ```end```. That always holds when
+/// ```start <= end```, but not always for negative differences, see
+/// ```DiffAlgorithm::RoundTrip``` for a breakdown that does.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::verify_roundtrip;
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// # assert!(verify_roundtrip(&date(2021, 2, 28), &date(2021, 3, 30)));
+/// # assert!(!verify_roundtrip(&date(2021, 3, 30), &date(2021, 2, 28)));
+/// ```
+pub fn verify_roundtrip(start: &NaiveDate, end: &NaiveDate) -> bool {
+    get_diff(start, end).checked_apply_to(*start, EomPolicy::Clamp) == Some(*end)
+}
+
+/// What PHP's ```DateTime::diff``` gives, see ```php_diff```.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PhpDateInterval {
+    interval: Interval,
+    days: u32,
+}
+
+impl PhpDateInterval {
+    /// The ```y```, ```m```, ```d``` and ```invert``` fields.
+    pub fn interval(&self) -> Interval {
+        self.interval
+    }
+    /// The ```days``` field, the total number of days between the dates
+    /// regardless of sign.
+    pub fn days(&self) -> u32 {
+        self.days
+    }
+}
+
+/// Takes two ```chrono::NaiveDate``` and gives the difference as Interval,
+/// broken down by ```algorithm```.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::{get_diff_by, DiffAlgorithm};
+///
+/// let start_date = NaiveDate::from_ymd_opt(2003, 2, 1).unwrap();
+/// let end_date = NaiveDate::from_ymd_opt(2003, 5, 1).unwrap();
+///
+/// println!("Duration is {}", get_diff_by(&start_date, &end_date, DiffAlgorithm::MySql));
+/// # assert_eq!(get_diff_by(&start_date, &end_date, DiffAlgorithm::MySql).months(), 3);
+/// ```
+pub fn get_diff_by(start: &NaiveDate, end: &NaiveDate, algorithm: DiffAlgorithm) -> Interval {
+    match algorithm {
+        DiffAlgorithm::Default => get_diff(start, end),
+        DiffAlgorithm::MySql => mysql_diff(start, end),
+        DiffAlgorithm::PostgresAge => postgres_age(start, end),
+        DiffAlgorithm::JavaPeriod => java_period(start, end),
+        DiffAlgorithm::RelativeDelta => relativedelta(start, end),
+        DiffAlgorithm::Php => php_diff(start, end).interval,
+        DiffAlgorithm::RoundTrip if start <= end => get_diff(start, end),
+        DiffAlgorithm::RoundTrip => relativedelta(start, end),
+        DiffAlgorithm::Thirty360 => {
+            let days = days_360(start, end);
+            Interval::from_months_days(days / 30, days % 30)
+        }
+    }
+}
+
+/// Complete months elapsed from ```start``` to a later ```end```, as MySQL counts them.
+fn mysql_months(start: &NaiveDate, end: &NaiveDate) -> i64 {
+    let months = (i64::from(end.year()) - i64::from(start.year())) * 12 + i64::from(end.month())
+        - i64::from(start.month());
+    if end.day() < start.day() {
+        months - 1
+    } else {
+        months
+    }
+}
+
+fn mysql_diff(start: &NaiveDate, end: &NaiveDate) -> Interval {
+    let (mut start, mut end) = (*start, *end);
+    let positive = start <= end;
+    if !positive {
+        mem::swap(&mut start, &mut end);
+    }
+    let months = mysql_months(&start, &end);
+    //DATE_ADD clamps to the end of the month
+    let days = end
+        .signed_duration_since(add_months(start, months))
+        .num_days();
+    Interval::from_months_days(months, days).with_sign(positive)
+}
+
+fn postgres_age(start: &NaiveDate, end: &NaiveDate) -> Interval {
+    let (mut start, mut end) = (*start, *end);
+    let positive = start <= end;
+    if !positive {
+        mem::swap(&mut start, &mut end);
+    }
+    let mut days = i64::from(end.day()) - i64::from(start.day());
+    let mut months = (i64::from(end.year()) - i64::from(start.year())) * 12
+        + i64::from(end.month())
+        - i64::from(start.month());
+    if days < 0 {
+        //borrow the length of the earlier date's month, as timestamp_age does
+        days += i64::from(days_in_month(start.year(), start.month()));
+        months -= 1;
+    }
+    Interval::from_months_days(months, days).with_sign(positive)
+}
+
+fn java_period(start: &NaiveDate, end: &NaiveDate) -> Interval {
+    let mut months = (i64::from(end.year()) - i64::from(start.year())) * 12
+        + i64::from(end.month())
+        - i64::from(start.month());
+    let mut days = i64::from(end.day()) - i64::from(start.day());
+    if months > 0 && days < 0 {
+        months -= 1;
+        //plusMonths clamps to the end of the month
+        days = end
+            .signed_duration_since(add_months(*start, months))
+            .num_days();
+    } else if months < 0 && days > 0 {
+        months += 1;
+        days -= i64::from(days_in_month(end.year(), end.month()));
+    }
+    Interval::from_months_days(months, days)
+}
+
+fn relativedelta(start: &NaiveDate, end: &NaiveDate) -> Interval {
+    let mut months = (i64::from(end.year()) - i64::from(start.year())) * 12
+        + i64::from(end.month())
+        - i64::from(start.month());
+    //step back while the clamped date overshoots end
+    let step = if end < start { 1 } else { -1 };
+    let mut reached = add_months(*start, months);
+    while (step < 0 && *end < reached) || (step > 0 && *end > reached) {
+        months += step;
+        reached = add_months(*start, months);
+    }
+    let days = end.signed_duration_since(reached).num_days();
+    Interval::from_months_days(months, days)
+}
+
+/// Replicates ```$start->diff($end)``` in PHP, giving the breakdown of
+/// ```DiffAlgorithm::Php``` together with the total days.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::php_diff;
+///
+/// let start = NaiveDate::from_ymd_opt(2010, 1, 15).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2010, 3, 10).unwrap();
+///
+/// let diff = php_diff(&start, &end);
+/// println!("{} or {} days", diff.interval(), diff.days());
+/// # assert_eq!(diff.days(), 54);
+/// # assert_eq!(diff.interval(), datediff::Interval::new(0, 1, 23, true).unwrap());
+/// ```
+pub fn php_diff(start: &NaiveDate, end: &NaiveDate) -> PhpDateInterval {
+    let (mut start, mut end) = (*start, *end);
+    let positive = start <= end;
+    if !positive {
+        mem::swap(&mut start, &mut end);
+    }
+    let mut months = (i64::from(end.year()) - i64::from(start.year())) * 12
+        + i64::from(end.month())
+        - i64::from(start.month());
+    let mut days = i64::from(end.day()) - i64::from(start.day());
+    //timelib borrows from the months before the later date, or from the
+    //earlier date's month onwards when inverted
+    let (mut year, mut month) = if positive {
+        (end.year(), end.month())
+    } else {
+        (start.year(), start.month())
+    };
+    while days < 0 {
+        if positive {
+            let (y, m) = if month == 1 {
+                (year - 1, 12)
+            } else {
+                (year, month - 1)
+            };
+            year = y;
+            month = m;
+            days += i64::from(days_in_month(year, month));
+        } else {
+            days += i64::from(days_in_month(year, month));
+            let (y, m) = if month == 12 {
+                (year + 1, 1)
+            } else {
+                (year, month + 1)
+            };
+            year = y;
+            month = m;
+        }
+        months -= 1;
+    }
+    PhpDateInterval {
+        interval: Interval::from_months_days(months, days).with_sign(positive),
+        days: end.signed_duration_since(start).num_days() as u32,
+    }
+}
+
+/// Days from ```start``` to ```end``` under the 30/360 bond basis, see
+/// ```DiffAlgorithm::Thirty360```. Negative when ```end``` is before
+/// ```start```, counted from the earlier date either way.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::days_360;
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// # assert_eq!(days_360(&date(2021, 1, 15), &date(2021, 7, 15)), 180);
+/// # assert_eq!(days_360(&date(2021, 1, 31), &date(2021, 3, 31)), 60);
+/// # assert_eq!(days_360(&date(2021, 3, 31), &date(2021, 1, 31)), -60);
+/// ```
+pub fn days_360(start: &NaiveDate, end: &NaiveDate) -> i64 {
+    let (mut start, mut end) = (*start, *end);
+    let sign = if start <= end { 1 } else { -1 };
+    if sign < 0 {
+        mem::swap(&mut start, &mut end);
+    }
+    let start_day = start.day().min(30);
+    let end_day = if end.day() == 31 && start_day == 30 {
+        30
+    } else {
+        end.day()
+    };
+    sign * ((i64::from(end.year()) - i64::from(start.year())) * 360
+        + (i64::from(end.month()) - i64::from(start.month())) * 30
+        + i64::from(end_day)
+        - i64::from(start_day))
+}
+
+/// Fractional months from ```start``` to ```end``` like Oracle's
+/// ```MONTHS_BETWEEN(end, start)```. Whole months are counted from the
+/// month numbers, and the difference of the days of month is divided by 31
+/// no matter how long the months really are. When both dates are on the same
+/// day of month, or both are the last day of their month, the result is a
+/// whole number. Negative when ```end``` is before ```start```.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::months_between;
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// // example from the Oracle SQL reference
+/// let months = months_between(&date(1995, 1, 1), &date(1995, 2, 2));
+/// # assert!((months - 1.032_258_06).abs() < 1e-8);
+/// # assert_eq!(months_between(&date(2020, 1, 31), &date(2020, 2, 29)), 1.0);
+/// ```
+pub fn months_between(start: &NaiveDate, end: &NaiveDate) -> f64 {
+    let months = (i64::from(end.year()) - i64::from(start.year())) * 12 + i64::from(end.month())
+        - i64::from(start.month());
+    let is_last_day = |date: &NaiveDate| date.day() == days_in_month(date.year(), date.month());
+    if start.day() == end.day() || (is_last_day(start) && is_last_day(end)) {
+        months as f64
+    } else {
+        months as f64 + (f64::from(end.day()) - f64::from(start.day())) / 31.0
+    }
+}
+
+/// Counts complete ```unit```s elapsed from ```start``` to ```end``` like
+/// MySQL's ```TIMESTAMPDIFF(unit, start, end)```, each unit on its own.
+/// Negative when ```end``` is before ```start```.
+///
+/// Unlike ```diff_in```, Dec 31 to Jan 1 is 0 years since no complete year
+/// has passed. MySQL has no ```Unit::SemiMonth``` and ```Unit::Fortnight```,
+/// they count complete months as 2 half months plus one for every further
+/// 15 days, and 14 day periods.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::{timestamp_diff, Unit};
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// // examples from the MySQL reference manual
+/// # assert_eq!(timestamp_diff(&date(2003, 2, 1), &date(2003, 5, 1), Unit::Month), 3);
+/// # assert_eq!(timestamp_diff(&date(2002, 5, 1), &date(2001, 1, 1), Unit::Year), -1);
+/// ```
+pub fn timestamp_diff(start: &NaiveDate, end: &NaiveDate, unit: Unit) -> i64 {
+    let (mut start, mut end) = (*start, *end);
+    let sign = if start <= end { 1 } else { -1 };
+    if sign < 0 {
+        mem::swap(&mut start, &mut end);
+    }
+    let days = end.signed_duration_since(start).num_days();
+    sign * match unit {
+        Unit::Year => mysql_months(&start, &end) / 12,
+        Unit::Quarter => mysql_months(&start, &end) / 3,
+        Unit::Month => mysql_months(&start, &end),
+        Unit::SemiMonth => {
+            //a complete month and another 15 days is 3 half months
+            let months = mysql_months(&start, &end);
+            let rest = end
+                .signed_duration_since(add_months(start, months))
+                .num_days();
+            months * 2 + rest / 15
+        }
+        Unit::Fortnight => days / 14,
+        Unit::Week => days / 7,
+        Unit::Day => days,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDate};
+
+    use crate::{
+        days_360, get_diff, get_diff_by, months_between, php_diff, timestamp_diff,
+        verify_roundtrip, DiffAlgorithm, EomPolicy, Interval, Unit,
+    };
+
+    #[test]
+    fn mysql_vectors() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // TIMESTAMPDIFF(<unit>, start, end), the first two are the examples
+        // from the MySQL reference manual
+        let cases = [
+            (date(2003, 2, 1), date(2003, 5, 1), [0, 1, 3, 12, 89]),
+            (date(2002, 5, 1), date(2001, 1, 1), [-1, -5, -16, -69, -485]),
+            (date(2019, 12, 31), date(2020, 1, 1), [0, 0, 0, 0, 1]),
+            (date(2020, 1, 31), date(2020, 2, 29), [0, 0, 0, 4, 29]),
+            (date(2020, 1, 31), date(2020, 3, 31), [0, 0, 2, 8, 60]),
+            (date(2020, 2, 29), date(2021, 2, 28), [0, 3, 11, 52, 365]),
+            (date(2020, 2, 29), date(2024, 2, 29), [4, 16, 48, 208, 1461]),
+        ];
+        let units = [
+            Unit::Year,
+            Unit::Quarter,
+            Unit::Month,
+            Unit::Week,
+            Unit::Day,
+        ];
+        for (start, end, expected) in cases.iter() {
+            for (unit, expected) in units.iter().zip(expected.iter()) {
+                assert_eq!(
+                    timestamp_diff(start, end, *unit),
+                    *expected,
+                    "{} {} {:?}",
+                    start,
+                    end,
+                    unit
+                );
+            }
+        }
+
+        let payroll = [
+            (date(2021, 1, 1), date(2021, 1, 16), [1, 1]),
+            (date(2021, 1, 31), date(2021, 3, 14), [2, 3]),
+            (date(2021, 1, 31), date(2021, 3, 16), [3, 3]),
+            (date(2021, 3, 16), date(2021, 1, 31), [-3, -3]),
+        ];
+        for (start, end, [semi_months, fortnights]) in payroll.iter() {
+            assert_eq!(timestamp_diff(start, end, Unit::SemiMonth), *semi_months);
+            assert_eq!(timestamp_diff(start, end, Unit::Fortnight), *fortnights);
+        }
+
+        assert_eq!(
+            get_diff_by(&date(2020, 2, 29), &date(2021, 2, 28), DiffAlgorithm::MySql),
+            Interval::new(0, 11, 30, true).unwrap()
+        );
+        assert_eq!(
+            get_diff_by(&date(2020, 3, 1), &date(2020, 1, 31), DiffAlgorithm::MySql),
+            Interval::new(0, 1, 1, false).unwrap()
+        );
+    }
+
+    #[test]
+    fn mysql_matches_default() {
+        let start = NaiveDate::from_ymd_opt(2019, 12, 1).unwrap();
+        for offset in 0..120 {
+            let a = start + Duration::days(offset);
+            for length in (0..800).step_by(7) {
+                let b = a + Duration::days(length);
+                assert_eq!(
+                    get_diff_by(&a, &b, DiffAlgorithm::MySql),
+                    get_diff(&a, &b),
+                    "{} {}",
+                    a,
+                    b
+                );
+                assert_eq!(
+                    get_diff_by(&b, &a, DiffAlgorithm::MySql),
+                    get_diff(&b, &a),
+                    "{} {}",
+                    b,
+                    a
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn postgres_vectors() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let age = |end, start| get_diff_by(&start, &end, DiffAlgorithm::PostgresAge);
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        // SELECT age(end, start), the first one is from the PostgreSQL manual
+        assert_eq!(
+            age(date(2001, 4, 10), date(1957, 6, 13)),
+            interval(43, 9, 27, true)
+        );
+        assert_eq!(
+            age(date(2021, 3, 1), date(2021, 1, 31)),
+            interval(0, 1, 1, true)
+        );
+        assert_eq!(
+            age(date(2020, 3, 15), date(2020, 1, 20)),
+            interval(0, 1, 26, true)
+        );
+        assert_eq!(
+            age(date(2020, 1, 20), date(2020, 3, 15)),
+            interval(0, 1, 26, false)
+        );
+        assert_eq!(
+            age(date(2021, 2, 28), date(2020, 2, 29)),
+            interval(0, 11, 28, true)
+        );
+        assert_eq!(
+            age(date(2020, 1, 5), date(2019, 12, 25)),
+            interval(0, 0, 11, true)
+        );
+        assert_eq!(age(date(2020, 1, 1), date(2020, 1, 1)), Interval::zero());
+
+        // where get_diff borrows from a different month
+        assert_eq!(
+            get_diff(&date(1957, 6, 13), &date(2001, 4, 10)),
+            interval(43, 9, 28, true)
+        );
+    }
+
+    #[test]
+    fn java_vectors() {
+        let date = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        // Period.between(start, end) on OpenJDK
+        let cases = [
+            ("2020-01-31", "2020-03-01", interval(0, 1, 1, true)),
+            ("2020-01-31", "2020-02-29", interval(0, 0, 29, true)),
+            ("2019-01-15", "2019-03-10", interval(0, 1, 23, true)),
+            ("2019-03-10", "2019-01-15", interval(0, 1, 26, false)),
+            ("2020-03-01", "2020-01-31", interval(0, 1, 1, false)),
+            ("2020-02-29", "2021-02-28", interval(0, 11, 30, true)),
+            ("2021-02-28", "2020-02-29", interval(0, 11, 28, false)),
+            ("2020-02-29", "2024-02-29", interval(4, 0, 0, true)),
+            ("1957-06-13", "2001-04-10", interval(43, 9, 28, true)),
+            ("2001-04-10", "1957-06-13", interval(43, 9, 27, false)),
+            ("2019-04-30", "2019-03-31", interval(0, 0, 30, false)),
+            ("2020-05-31", "2020-06-30", interval(0, 0, 30, true)),
+            ("2020-06-30", "2020-05-31", interval(0, 0, 30, false)),
+            ("2020-01-01", "2020-01-01", Interval::zero()),
+            ("2019-12-25", "2020-01-05", interval(0, 0, 11, true)),
+            ("2020-01-05", "2019-12-25", interval(0, 0, 11, false)),
+        ];
+        for (start, end, expected) in cases.iter() {
+            let (start, end) = (date(start), date(end));
+            assert_eq!(
+                get_diff_by(&start, &end, DiffAlgorithm::JavaPeriod),
+                *expected,
+                "{} {}",
+                start,
+                end
+            );
+            if start <= end {
+                assert_eq!(get_diff(&start, &end), *expected, "{} {}", start, end);
+            }
+        }
+
+        // where get_diff counts forward from the earlier date instead
+        assert_eq!(
+            get_diff(&date("2019-03-10"), &date("2019-01-15")),
+            interval(0, 1, 23, false)
+        );
+    }
+
+    #[test]
+    fn relativedelta_vectors() {
+        let date = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        // relativedelta(end, start) from python-dateutil 2.9
+        let cases = [
+            ("2020-01-31", "2020-03-01", interval(0, 1, 1, true)),
+            ("2020-01-31", "2020-02-29", interval(0, 1, 0, true)),
+            ("2019-01-31", "2019-02-28", interval(0, 1, 0, true)),
+            ("2019-01-15", "2019-03-10", interval(0, 1, 23, true)),
+            ("2019-03-10", "2019-01-15", interval(0, 1, 26, false)),
+            ("2020-03-01", "2020-01-31", interval(0, 1, 1, false)),
+            ("2020-02-29", "2021-02-28", interval(1, 0, 0, true)),
+            ("2021-02-28", "2020-02-29", interval(0, 11, 28, false)),
+            ("2020-02-29", "2024-02-29", interval(4, 0, 0, true)),
+            ("1957-06-13", "2001-04-10", interval(43, 9, 28, true)),
+            ("2001-04-10", "1957-06-13", interval(43, 9, 27, false)),
+            ("2019-04-30", "2019-03-31", interval(0, 0, 30, false)),
+            ("2019-03-31", "2019-04-30", interval(0, 1, 0, true)),
+            ("2020-05-31", "2020-06-30", interval(0, 1, 0, true)),
+            ("2020-06-30", "2020-05-31", interval(0, 0, 30, false)),
+            ("2020-03-31", "2020-02-29", interval(0, 1, 0, false)),
+            ("2020-01-01", "2020-01-01", Interval::zero()),
+            ("2019-12-25", "2020-01-05", interval(0, 0, 11, true)),
+        ];
+        for (start, end, expected) in cases.iter() {
+            let (start, end) = (date(start), date(end));
+            assert_eq!(
+                get_diff_by(&start, &end, DiffAlgorithm::RelativeDelta),
+                *expected,
+                "{} {}",
+                start,
+                end
+            );
+        }
+    }
+
+    #[test]
+    fn php_vectors() {
+        let date = |s: &str| NaiveDate::parse_from_str(s, "%Y-%m-%d").unwrap();
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        // $start->diff($end), following timelib's do_range_limit_days_relative
+        let cases = [
+            ("2010-01-15", "2010-03-10", interval(0, 1, 23, true), 54),
+            ("2010-03-10", "2010-01-15", interval(0, 1, 26, false), 54),
+            ("2020-01-31", "2020-03-01", interval(0, 0, 30, true), 30),
+            ("2020-03-01", "2020-01-31", interval(0, 1, 1, false), 30),
+            ("2021-01-31", "2021-03-01", interval(0, 0, 29, true), 29),
+            ("2020-01-31", "2020-02-29", interval(0, 0, 29, true), 29),
+            ("2020-02-29", "2021-02-28", interval(0, 11, 30, true), 365),
+            ("2021-02-28", "2020-02-29", interval(0, 11, 28, false), 365),
+            ("2020-02-29", "2024-02-29", interval(4, 0, 0, true), 1461),
+            ("2019-12-25", "2020-01-05", interval(0, 0, 11, true), 11),
+            ("2020-01-01", "2020-01-01", Interval::zero(), 0),
+        ];
+        for (start, end, expected, days) in cases.iter() {
+            let (start, end) = (date(start), date(end));
+            let diff = php_diff(&start, &end);
+            assert_eq!(diff.interval(), *expected, "{} {}", start, end);
+            assert_eq!(diff.days(), *days, "{} {}", start, end);
+            assert_eq!(get_diff_by(&start, &end, DiffAlgorithm::Php), *expected);
+        }
+    }
+
+    #[test]
+    fn symmetry() {
+        let start = NaiveDate::from_ymd_opt(2019, 12, 1).unwrap();
+        let algorithms = [
+            DiffAlgorithm::Default,
+            DiffAlgorithm::MySql,
+            DiffAlgorithm::PostgresAge,
+            DiffAlgorithm::JavaPeriod,
+            DiffAlgorithm::RelativeDelta,
+            DiffAlgorithm::Php,
+            DiffAlgorithm::RoundTrip,
+            DiffAlgorithm::Thirty360,
+        ];
+        for algorithm in algorithms.iter() {
+            let mut symmetric = true;
+            for offset in 0..120 {
+                let a = start + Duration::days(offset);
+                for length in (0..800).step_by(11) {
+                    let b = a + Duration::days(length);
+                    symmetric &=
+                        get_diff_by(&a, &b, *algorithm) == -get_diff_by(&b, &a, *algorithm);
+                }
+            }
+            assert_eq!(symmetric, algorithm.is_symmetric(), "{:?}", algorithm);
+        }
+    }
+
+    #[test]
+    fn roundtrip() {
+        let start = NaiveDate::from_ymd_opt(2019, 12, 1).unwrap();
+        for offset in 0..120 {
+            let a = start + Duration::days(offset);
+            for length in (0..800).step_by(3) {
+                let b = a + Duration::days(length);
+                assert!(verify_roundtrip(&a, &b), "{} {}", a, b);
+                for (from, to) in [(a, b), (b, a)].iter() {
+                    let interval = get_diff_by(from, to, DiffAlgorithm::RoundTrip);
+                    assert_eq!(
+                        interval.apply_to(*from, EomPolicy::Clamp),
+                        *to,
+                        "{} {}",
+                        from,
+                        to
+                    );
+                }
+            }
+        }
+
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert!(!verify_roundtrip(&date(2021, 3, 30), &date(2021, 2, 28)));
+        assert_eq!(
+            get_diff_by(
+                &date(2021, 3, 30),
+                &date(2021, 2, 28),
+                DiffAlgorithm::RoundTrip
+            ),
+            Interval::new(0, 1, 0, false).unwrap()
+        );
+    }
+
+    #[test]
+    fn thirty_360() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        let cases = [
+            (
+                date(2021, 1, 15),
+                date(2021, 7, 15),
+                180,
+                interval(0, 6, 0, true),
+            ),
+            (
+                date(2021, 1, 31),
+                date(2021, 3, 1),
+                31,
+                interval(0, 1, 1, true),
+            ),
+            (
+                date(2021, 1, 30),
+                date(2021, 3, 31),
+                60,
+                interval(0, 2, 0, true),
+            ),
+            (
+                date(2021, 1, 29),
+                date(2021, 3, 31),
+                62,
+                interval(0, 2, 2, true),
+            ),
+            (
+                date(2021, 2, 28),
+                date(2021, 3, 31),
+                33,
+                interval(0, 1, 3, true),
+            ),
+            (
+                date(2020, 2, 29),
+                date(2021, 2, 28),
+                359,
+                interval(0, 11, 29, true),
+            ),
+            (
+                date(2019, 6, 30),
+                date(2021, 12, 31),
+                900,
+                interval(2, 6, 0, true),
+            ),
+            (
+                date(2021, 3, 31),
+                date(2021, 1, 31),
+                -60,
+                interval(0, 2, 0, false),
+            ),
+            (date(2021, 5, 5), date(2021, 5, 5), 0, Interval::zero()),
+        ];
+        for (start, end, days, expected) in cases.iter() {
+            assert_eq!(days_360(start, end), *days, "{} {}", start, end);
+            assert_eq!(
+                get_diff_by(start, end, DiffAlgorithm::Thirty360),
+                *expected,
+                "{} {}",
+                start,
+                end
+            );
+        }
+    }
+
+    #[test]
+    fn oracle_months_between() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let close = |a: f64, b: f64| (a - b).abs() < 1e-9;
+
+        assert!(close(
+            months_between(&date(1995, 1, 1), &date(1995, 2, 2)),
+            1.0 + 1.0 / 31.0
+        ));
+        assert!(close(
+            months_between(&date(1995, 2, 2), &date(1995, 1, 1)),
+            -1.0 - 1.0 / 31.0
+        ));
+        assert_eq!(months_between(&date(2020, 1, 15), &date(2021, 3, 15)), 14.0);
+        // both end of month
+        assert_eq!(months_between(&date(2019, 2, 28), &date(2019, 4, 30)), 2.0);
+        assert_eq!(months_between(&date(2020, 4, 30), &date(2020, 2, 29)), -2.0);
+        // Feb 28 isn't the last day in a leap year, so 30 - 28 days are added
+        assert!(close(
+            months_between(&date(2020, 2, 28), &date(2020, 3, 30)),
+            1.0 + 2.0 / 31.0
+        ));
+        assert!(close(
+            months_between(&date(2020, 1, 31), &date(2020, 3, 1)),
+            2.0 - 30.0 / 31.0
+        ));
+        assert_eq!(months_between(&date(2020, 1, 1), &date(2020, 1, 1)), 0.0);
+    }
+}
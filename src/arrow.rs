@@ -0,0 +1,210 @@
+use std::convert::TryFrom;
+
+use arrow_buffer::IntervalMonthDayNano;
+
+use crate::{Interval, IntervalError, SignedInterval};
+
+impl Interval {
+    /// Total months with the sign applied, the value of an Arrow
+    /// ```IntervalYearMonth```. Fails with ```IntervalError::NotWholeMonths```
+    /// when the interval has days and ```IntervalError::YearsOutOfRange```
+    /// when the months don't fit in an ```i32```.
+    ///
+    /// Needs the ```arrow``` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "arrow")]
+    /// # {
+    /// use datediff::{Interval, IntervalError};
+    ///
+    /// # assert_eq!(Interval::new(2, 3, 0, false).unwrap().to_arrow_year_month(), Ok(-27));
+    /// # assert_eq!(Interval::new(2, 3, 4, true).unwrap().to_arrow_year_month(), Err(IntervalError::NotWholeMonths(4)));
+    /// # }
+    /// ```
+    pub fn to_arrow_year_month(&self) -> Result<i32, IntervalError> {
+        if self.days != 0 {
+            return Err(IntervalError::NotWholeMonths(self.days));
+        }
+        i32::try_from(self.total_months()).map_err(|_| IntervalError::YearsOutOfRange(self.years))
+    }
+
+    /// Interval of an Arrow ```IntervalYearMonth``` value, i.e. of signed
+    /// total ```months```, split into years and months.
+    ///
+    /// Needs the ```arrow``` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "arrow")]
+    /// # {
+    /// use datediff::Interval;
+    ///
+    /// # assert_eq!(Interval::from_arrow_year_month(-27), Interval::new(2, 3, 0, false).unwrap());
+    /// # }
+    /// ```
+    pub fn from_arrow_year_month(months: i32) -> Interval {
+        Interval::from_months_days(i64::from(months), 0)
+    }
+}
+
+/// Total months and days with the sign applied, no nanoseconds. Fails with
+/// ```IntervalError::YearsOutOfRange``` when the months don't fit in an
+/// ```i32```.
+///
+/// Needs the ```arrow``` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "arrow")]
+/// # {
+/// use std::convert::TryFrom;
+///
+/// use arrow_buffer::IntervalMonthDayNano;
+///
+/// use datediff::Interval;
+///
+/// let interval = Interval::new(2, 3, 4, false).unwrap();
+///
+/// # assert_eq!(IntervalMonthDayNano::try_from(interval), Ok(IntervalMonthDayNano::new(-27, -4, 0)));
+/// # }
+/// ```
+impl TryFrom<Interval> for IntervalMonthDayNano {
+    type Error = IntervalError;
+
+    fn try_from(interval: Interval) -> Result<IntervalMonthDayNano, IntervalError> {
+        IntervalMonthDayNano::try_from(SignedInterval::from(interval))
+    }
+}
+
+/// Converts when the value has no nanoseconds and months and days agree in
+/// sign. Fails with ```IntervalError::NotWholeDays``` or
+/// ```IntervalError::MixedSigns``` otherwise, use ```SignedInterval``` for
+/// the latter. Days are kept as they are, Arrow doesn't carry them into
+/// months either.
+///
+/// Needs the ```arrow``` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "arrow")]
+/// # {
+/// use std::convert::TryFrom;
+///
+/// use arrow_buffer::IntervalMonthDayNano;
+///
+/// use datediff::{Interval, IntervalError};
+///
+/// # assert_eq!(Interval::try_from(IntervalMonthDayNano::new(27, 4, 0)), Interval::new(2, 3, 4, true));
+/// # assert_eq!(Interval::try_from(IntervalMonthDayNano::new(1, -3, 0)), Err(IntervalError::MixedSigns));
+/// # assert_eq!(Interval::try_from(IntervalMonthDayNano::new(0, 0, 1)), Err(IntervalError::NotWholeDays(1)));
+/// # }
+/// ```
+impl TryFrom<IntervalMonthDayNano> for Interval {
+    type Error = IntervalError;
+
+    fn try_from(value: IntervalMonthDayNano) -> Result<Interval, IntervalError> {
+        Interval::try_from(SignedInterval::try_from(value)?)
+    }
+}
+
+/// Like ```TryFrom<Interval>```, except months and days keep their own
+/// signs, e.g. 1 month -3 days gives ```IntervalMonthDayNano::new(1, -3, 0)```.
+///
+/// Needs the ```arrow``` feature.
+impl TryFrom<SignedInterval> for IntervalMonthDayNano {
+    type Error = IntervalError;
+
+    fn try_from(signed: SignedInterval) -> Result<IntervalMonthDayNano, IntervalError> {
+        let (months, days) = signed.to_months_days()?;
+        Ok(IntervalMonthDayNano::new(months, days, 0))
+    }
+}
+
+/// Splits the months into years and months and keeps days as they are,
+/// whatever their signs. Fails with ```IntervalError::NotWholeDays``` on
+/// nanoseconds.
+///
+/// Needs the ```arrow``` feature.
+impl TryFrom<IntervalMonthDayNano> for SignedInterval {
+    type Error = IntervalError;
+
+    fn try_from(value: IntervalMonthDayNano) -> Result<SignedInterval, IntervalError> {
+        SignedInterval::from_months_days(value.months, value.days, value.nanoseconds)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::TryFrom;
+
+    use arrow_buffer::IntervalMonthDayNano;
+
+    use crate::{Interval, IntervalError, SignedInterval};
+
+    #[test]
+    fn month_day_nano() {
+        let interval = Interval::new(2, 3, 4, false).unwrap();
+        assert_eq!(
+            IntervalMonthDayNano::try_from(interval),
+            Ok(IntervalMonthDayNano::new(-27, -4, 0))
+        );
+        assert_eq!(
+            Interval::try_from(IntervalMonthDayNano::new(-27, -4, 0)),
+            Ok(interval)
+        );
+        assert_eq!(
+            IntervalMonthDayNano::try_from(Interval::MAX),
+            Err(IntervalError::YearsOutOfRange(Interval::MAX_YEARS))
+        );
+        assert_eq!(
+            Interval::try_from(IntervalMonthDayNano::new(0, 1, -1)),
+            Err(IntervalError::NotWholeDays(-1))
+        );
+
+        // mixed signs only fit a SignedInterval
+        let value = IntervalMonthDayNano::new(1, -3, 0);
+        assert_eq!(Interval::try_from(value), Err(IntervalError::MixedSigns));
+        assert_eq!(
+            SignedInterval::try_from(value),
+            Ok(SignedInterval::new(0, 1, -3))
+        );
+        assert_eq!(
+            IntervalMonthDayNano::try_from(SignedInterval::new(0, 1, -3)),
+            Ok(value)
+        );
+    }
+
+    #[test]
+    fn year_month() {
+        for &(interval, months) in &[
+            (Interval::zero(), 0),
+            (Interval::new(0, 11, 0, true).unwrap(), 11),
+            (Interval::new(2, 3, 0, false).unwrap(), -27),
+            (Interval::new(178_956_970, 7, 0, true).unwrap(), i32::MAX),
+            (Interval::new(178_956_970, 8, 0, false).unwrap(), i32::MIN),
+        ] {
+            assert_eq!(interval.to_arrow_year_month(), Ok(months), "{}", interval);
+            assert_eq!(
+                Interval::from_arrow_year_month(months),
+                interval,
+                "{}",
+                months
+            );
+        }
+        assert_eq!(
+            Interval::new(0, 1, 1, true).unwrap().to_arrow_year_month(),
+            Err(IntervalError::NotWholeMonths(1))
+        );
+        assert_eq!(
+            Interval::new(178_956_970, 8, 0, true)
+                .unwrap()
+                .to_arrow_year_month(),
+            Err(IntervalError::YearsOutOfRange(178_956_970))
+        );
+    }
+}
@@ -0,0 +1,105 @@
+use chrono::{Local, NaiveDate, NaiveDateTime};
+
+use crate::{get_diff, Interval};
+
+/// Source of the current date and time, so code measuring from "today" can
+/// be tested with a ```FixedClock``` instead of the system clock.
+pub trait Clock {
+    /// Current local date and time.
+    fn now(&self) -> NaiveDateTime;
+
+    /// Current local date.
+    fn today(&self) -> NaiveDate {
+        self.now().date()
+    }
+}
+
+/// The system clock, read in the local time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> NaiveDateTime {
+        Local::now().naive_local()
+    }
+}
+
+/// A clock that always shows the same date and time.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::{since_today, FixedClock, Interval};
+///
+/// let clock = FixedClock::on(NaiveDate::from_ymd_opt(2021, 3, 1).unwrap());
+/// let born = NaiveDate::from_ymd_opt(1990, 2, 1).unwrap();
+///
+/// # assert_eq!(since_today(&born, &clock), Interval::new(31, 1, 0, true).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedClock {
+    now: NaiveDateTime,
+}
+
+impl FixedClock {
+    pub fn new(now: NaiveDateTime) -> FixedClock {
+        FixedClock { now }
+    }
+
+    /// A clock showing the start of ```date```.
+    pub fn on(date: NaiveDate) -> FixedClock {
+        FixedClock::new(date.and_hms_opt(0, 0, 0).unwrap())
+    }
+}
+
+impl Clock for FixedClock {
+    fn now(&self) -> NaiveDateTime {
+        self.now
+    }
+}
+
+/// Difference from ```date``` to today on ```clock```, e.g. an age. Negative
+/// for a date in the future.
+pub fn since_today<C: Clock + ?Sized>(date: &NaiveDate, clock: &C) -> Interval {
+    get_diff(date, &clock.today())
+}
+
+/// Difference from today on ```clock``` to ```date```, e.g. a countdown.
+/// Negative for a date in the past.
+pub fn until_today<C: Clock + ?Sized>(date: &NaiveDate, clock: &C) -> Interval {
+    get_diff(&clock.today(), date)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use crate::{since_today, until_today, Clock, FixedClock, Interval, SystemClock};
+
+    #[test]
+    fn clock() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let clock = FixedClock::new(date(2021, 3, 1).and_hms_opt(18, 30, 0).unwrap());
+        assert_eq!(clock.today(), date(2021, 3, 1));
+
+        assert_eq!(
+            since_today(&date(2020, 12, 25), &clock),
+            Interval::new(0, 2, 4, true).unwrap()
+        );
+        assert_eq!(
+            until_today(&date(2021, 12, 25), &clock),
+            Interval::new(0, 9, 24, true).unwrap()
+        );
+        assert_eq!(
+            until_today(&date(2020, 12, 25), &clock),
+            since_today(&date(2020, 12, 25), &clock).inverse()
+        );
+
+        // usable as a trait object
+        let clocks: [&dyn Clock; 2] = [&clock, &SystemClock];
+        assert_eq!(since_today(&date(2021, 3, 1), clocks[0]), Interval::zero());
+        assert!(since_today(&date(2021, 3, 1), clocks[1]).positive());
+    }
+}
@@ -0,0 +1,168 @@
+//! Dates in the formats people and spreadsheets write them, see
+//! ```parse_date```.
+//!
+//! Needs the ```parse``` feature.
+
+use chrono::NaiveDate;
+
+/// Which of day and month comes first in a numeric date like
+/// ```01/02/2020```, see ```parse_date```.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DateOrder {
+    /// US style, ```01/02/2020``` is January 2.
+    #[default]
+    MonthFirst,
+    /// European style, ```01/02/2020``` is February 1.
+    DayFirst,
+}
+
+/// Formats tried by ```parse_date```, year first, then numeric, then with a
+/// month name, the month first numeric ones given for ```DateOrder::MonthFirst```.
+const DATE_FORMATS: [&str; 22] = [
+    "%Y-%m-%d",
+    "%Y/%m/%d",
+    "%Y.%m.%d",
+    "%Y%m%d",
+    "%m/%d/%Y",
+    "%d/%m/%Y",
+    "%m-%d-%Y",
+    "%d-%m-%Y",
+    "%m.%d.%Y",
+    "%d.%m.%Y",
+    "%b %d, %Y",
+    "%b %d %Y",
+    "%B %d, %Y",
+    "%B %d %Y",
+    "%d %b %Y",
+    "%d %B %Y",
+    "%d %b, %Y",
+    "%d-%b-%Y",
+    "%a, %b %d, %Y",
+    "%a %b %d %Y",
+    "%a, %d %b %Y",
+    "%b. %d, %Y",
+];
+
+/// Parses a date written in one of the common formats, and gives the
+/// ```strftime``` pattern that matched, e.g. to read the rest of a column
+/// with ```get_diff_str```:
+///
+/// - year first: ```2020-02-01```, ```2020/02/01```, ```2020.02.01```,
+///   ```20200201```
+/// - numeric with the year last, separated by ```/```, ```-``` or ```.```:
+///   ```01/02/2020```, read with ```order``` when both day and month could
+///   be 12 or less, as the other way round otherwise
+/// - with an English month name or abbreviation in any case:
+///   ```Jan 2, 2020```, ```January 2 2020```, ```2 Jan 2020```,
+///   ```02-JAN-2020```, ```Thu, Jan 2, 2020```, ```Jan. 2, 2020```
+///
+/// Day and month may leave out the leading zero. ```None``` when no format
+/// matches.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "parse")]
+/// # {
+/// use chrono::NaiveDate;
+///
+/// use datediff::dates::{parse_date, DateOrder};
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// # assert_eq!(parse_date("01/02/2020", DateOrder::DayFirst), Some((date(2020, 2, 1), "%d/%m/%Y")));
+/// # assert_eq!(parse_date("01/02/2020", DateOrder::MonthFirst), Some((date(2020, 1, 2), "%m/%d/%Y")));
+/// # assert_eq!(parse_date("13/02/2020", DateOrder::MonthFirst), Some((date(2020, 2, 13), "%d/%m/%Y")));
+/// # assert_eq!(parse_date("Jan 2, 2020", DateOrder::DayFirst), Some((date(2020, 1, 2), "%b %d, %Y")));
+/// # assert_eq!(parse_date("2020.02.01", DateOrder::DayFirst), Some((date(2020, 2, 1), "%Y.%m.%d")));
+/// # }
+/// ```
+pub fn parse_date(s: &str, order: DateOrder) -> Option<(NaiveDate, &'static str)> {
+    let s = s.trim();
+    let mut formats: Vec<_> = DATE_FORMATS.to_vec();
+    if order == DateOrder::DayFirst {
+        //day first formats come right after their month first twin
+        for pair in formats[4..10].chunks_mut(2) {
+            pair.swap(0, 1);
+        }
+    }
+    formats.into_iter().find_map(|format| {
+        NaiveDate::parse_from_str(s, format)
+            .ok()
+            .map(|date| (date, format))
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use crate::dates::{parse_date, DateOrder};
+
+    #[test]
+    fn date_formats() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let jan_2 = date(2020, 1, 2);
+        let feb_1 = date(2020, 2, 1);
+        for &(text, order, expected, format) in &[
+            ("2020-02-01", DateOrder::MonthFirst, feb_1, "%Y-%m-%d"),
+            ("2020/2/1", DateOrder::MonthFirst, feb_1, "%Y/%m/%d"),
+            (" 2020.02.01 ", DateOrder::DayFirst, feb_1, "%Y.%m.%d"),
+            ("20200201", DateOrder::DayFirst, feb_1, "%Y%m%d"),
+            ("01/02/2020", DateOrder::MonthFirst, jan_2, "%m/%d/%Y"),
+            ("01/02/2020", DateOrder::DayFirst, feb_1, "%d/%m/%Y"),
+            ("1-2-2020", DateOrder::DayFirst, feb_1, "%d-%m-%Y"),
+            ("01.02.2020", DateOrder::DayFirst, feb_1, "%d.%m.%Y"),
+            ("02.01.2020", DateOrder::MonthFirst, feb_1, "%m.%d.%Y"),
+            (
+                "1/13/2020",
+                DateOrder::DayFirst,
+                date(2020, 1, 13),
+                "%m/%d/%Y",
+            ),
+            (
+                "13/1/2020",
+                DateOrder::MonthFirst,
+                date(2020, 1, 13),
+                "%d/%m/%Y",
+            ),
+            ("Jan 2, 2020", DateOrder::MonthFirst, jan_2, "%b %d, %Y"),
+            ("january 2 2020", DateOrder::DayFirst, jan_2, "%B %d %Y"),
+            ("January 2, 2020", DateOrder::DayFirst, jan_2, "%B %d, %Y"),
+            ("2 january 2020", DateOrder::DayFirst, jan_2, "%d %B %Y"),
+            ("2 Jan 2020", DateOrder::MonthFirst, jan_2, "%d %b %Y"),
+            ("02-JAN-2020", DateOrder::MonthFirst, jan_2, "%d-%b-%Y"),
+            (
+                "Thu, Jan 2, 2020",
+                DateOrder::MonthFirst,
+                jan_2,
+                "%a, %b %d, %Y",
+            ),
+            (
+                "Thu, 02 Jan 2020",
+                DateOrder::DayFirst,
+                jan_2,
+                "%a, %d %b %Y",
+            ),
+            ("Jan. 2, 2020", DateOrder::MonthFirst, jan_2, "%b. %d, %Y"),
+        ] {
+            assert_eq!(
+                parse_date(text, order),
+                Some((expected, format)),
+                "{} {:?}",
+                text,
+                order
+            );
+        }
+        for &text in &[
+            "",
+            "2020-02-30",
+            "13/13/2020",
+            "Fri, Jan 2, 2020",
+            "tomorrow",
+            "2020-02-01 12:00",
+        ] {
+            assert_eq!(parse_date(text, DateOrder::MonthFirst), None, "{}", text);
+        }
+    }
+}
@@ -0,0 +1,661 @@
+use std::fmt;
+
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+
+use crate::{get_diff, plural, Interval};
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+const NANOS_PER_DAY: u64 = 24 * 60 * 60 * NANOS_PER_SECOND;
+
+/// Holds the difference of two ```chrono::NaiveDateTime``` as years, months,
+/// days, hours, minutes, seconds and nanoseconds, see ```get_diff_datetime```.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateTimeInterval {
+    date: Interval,
+    hours: u32,
+    minutes: u32,
+    seconds: u32,
+    nanoseconds: u32,
+    positive: bool,
+}
+
+impl DateTimeInterval {
+    pub fn years(&self) -> u32 {
+        self.date.years()
+    }
+    pub fn months(&self) -> u32 {
+        self.date.months()
+    }
+    pub fn days(&self) -> u32 {
+        self.date.days()
+    }
+    pub fn hours(&self) -> u32 {
+        self.hours
+    }
+    pub fn minutes(&self) -> u32 {
+        self.minutes
+    }
+    pub fn seconds(&self) -> u32 {
+        self.seconds
+    }
+    /// Fraction of a second, in ```0..1_000_000_000```.
+    pub fn nanoseconds(&self) -> u32 {
+        self.nanoseconds
+    }
+    /// Fraction of a second in whole milliseconds, in ```0..1000```.
+    pub fn milliseconds(&self) -> u32 {
+        self.nanoseconds / 1_000_000
+    }
+    pub fn positive(&self) -> bool {
+        self.positive
+    }
+
+    /// Tells whether all components are zero.
+    pub fn is_zero(&self) -> bool {
+        self.date.is_zero()
+            && self.hours == 0
+            && self.minutes == 0
+            && self.seconds == 0
+            && self.nanoseconds == 0
+    }
+
+    /// Years, months and days of the interval with its sign, dropping the
+    /// time of day.
+    pub fn date_part(&self) -> Interval {
+        self.date.with_sign(self.positive)
+    }
+}
+
+impl fmt::Display for DateTimeInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({} year{} {} month{} {} day{} {} hour{} {} minute{} {}",
+            self.date.years(),
+            plural(i64::from(self.date.years())),
+            self.date.months(),
+            plural(i64::from(self.date.months())),
+            self.date.days(),
+            plural(i64::from(self.date.days())),
+            self.hours,
+            plural(i64::from(self.hours)),
+            self.minutes,
+            plural(i64::from(self.minutes)),
+            self.seconds,
+        )?;
+        if self.nanoseconds != 0 {
+            //only as many digits as needed, 1.5 rather than 1.500000000
+            let fraction = format!("{:09}", self.nanoseconds);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+        write!(
+            f,
+            " second{} {})",
+            //1.5 seconds but 1 second
+            if self.seconds == 1 && self.nanoseconds == 0 {
+                ""
+            } else {
+                "s"
+            },
+            if self.positive { "Ahead" } else { "Behind" }
+        )
+    }
+}
+
+/// Takes two ```chrono::NaiveDateTime``` and gives the difference as
+/// DateTimeInterval, broken down from the earlier one like ```get_diff```.
+/// When the later time of day is before the earlier one, a day is borrowed
+/// and counted as 24 hours, so 2020-01-15 22:00 to 2020-03-01 02:00 is
+/// 2020-01-15 to 2020-02-29 and 4 hours, i.e. 1 month 14 days 4 hours.
+///
+/// Fractions of a second are kept to the nanosecond. A leap second counts
+/// as the last nanosecond of the 59th second of its minute.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::get_diff_datetime;
+///
+/// let start = NaiveDate::from_ymd_opt(2020, 1, 15).unwrap().and_hms_opt(22, 0, 0).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2020, 3, 1).unwrap().and_hms_opt(2, 30, 15).unwrap();
+///
+/// println!("Duration is {}", get_diff_datetime(&start, &end));
+/// # let duration = get_diff_datetime(&start, &end);
+/// # assert_eq!((duration.years(), duration.months(), duration.days()), (0, 1, 14));
+/// # assert_eq!((duration.hours(), duration.minutes(), duration.seconds()), (4, 30, 15));
+/// # assert_eq!(duration.positive(), true);
+/// ```
+pub fn get_diff_datetime(start: &NaiveDateTime, end: &NaiveDateTime) -> DateTimeInterval {
+    let positive = start <= end;
+    let (earlier, later) = if positive {
+        (*start, *end)
+    } else {
+        (*end, *start)
+    };
+
+    let (earlier_nanos, later_nanos) = (nanos_of_day(&earlier), nanos_of_day(&later));
+    let mut later_date = later.date();
+    let nanos = if later_nanos < earlier_nanos {
+        //borrow a day, later is on a later date than earlier here
+        later_date = later_date.pred_opt().unwrap();
+        later_nanos + NANOS_PER_DAY - earlier_nanos
+    } else {
+        later_nanos - earlier_nanos
+    };
+
+    let date = get_diff(&earlier.date(), &later_date);
+    let seconds = (nanos / NANOS_PER_SECOND) as u32;
+    DateTimeInterval {
+        date,
+        hours: seconds / 3600,
+        minutes: seconds / 60 % 60,
+        seconds: seconds % 60,
+        nanoseconds: (nanos % NANOS_PER_SECOND) as u32,
+        positive: positive || (date.is_zero() && nanos == 0),
+    }
+}
+
+/// Nanoseconds since midnight, a leap second is folded into the second
+/// before it.
+fn nanos_of_day(datetime: &NaiveDateTime) -> u64 {
+    let nanos = u64::from(datetime.nanosecond()).min(NANOS_PER_SECOND - 1);
+    u64::from(datetime.num_seconds_from_midnight()) * NANOS_PER_SECOND + nanos
+}
+
+/// Age at ```as_of``` of someone born at ```dob```, down to the minute and
+/// beyond, broken down like ```get_diff_datetime```. A baby born at 23:50
+/// is 0 days 0 hours 20 minutes old at 00:10 the next day, not a day.
+/// ```None``` when ```as_of``` is before the birth.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::age_exact;
+///
+/// let born = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap().and_hms_opt(23, 50, 0).unwrap();
+/// let now = NaiveDate::from_ymd_opt(2021, 3, 4).unwrap().and_hms_opt(8, 5, 0).unwrap();
+///
+/// let age = age_exact(&born, &now).unwrap();
+/// println!("{} days {} hours {} minutes old", age.days(), age.hours(), age.minutes());
+/// # assert_eq!((age.days(), age.hours(), age.minutes()), (2, 8, 15));
+/// # assert_eq!(age_exact(&now, &born), None);
+/// ```
+pub fn age_exact(dob: &NaiveDateTime, as_of: &NaiveDateTime) -> Option<DateTimeInterval> {
+    if as_of < dob {
+        return None;
+    }
+    Some(get_diff_datetime(dob, as_of))
+}
+
+/// How ```get_time_diff``` reads an end time before the start time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MidnightPolicy {
+    /// Both times are on the same day, 22:00 to 06:00 is 16 hours behind.
+    #[default]
+    SameDay,
+    /// The end time is on the next day, 22:00 to 06:00 is 8 hours, e.g. for
+    /// night shifts. Equal times are still 0.
+    WrapAround,
+}
+
+/// Takes two ```chrono::NaiveTime``` and gives the difference as a
+/// DateTimeInterval without years, months or days, reading an end before the
+/// start as ```policy``` says.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveTime;
+///
+/// use datediff::{get_time_diff, MidnightPolicy};
+///
+/// let clock_in = NaiveTime::from_hms_opt(22, 0, 0).unwrap();
+/// let clock_out = NaiveTime::from_hms_opt(6, 15, 0).unwrap();
+///
+/// let shift = get_time_diff(&clock_in, &clock_out, MidnightPolicy::WrapAround);
+/// # assert_eq!((shift.hours(), shift.minutes(), shift.positive()), (8, 15, true));
+/// # let same_day = get_time_diff(&clock_in, &clock_out, MidnightPolicy::SameDay);
+/// # assert_eq!((same_day.hours(), same_day.minutes(), same_day.positive()), (15, 45, false));
+/// ```
+pub fn get_time_diff(
+    start: &NaiveTime,
+    end: &NaiveTime,
+    policy: MidnightPolicy,
+) -> DateTimeInterval {
+    let day = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+    let end_day = match policy {
+        MidnightPolicy::WrapAround if end < start => day.succ_opt().unwrap(),
+        _ => day,
+    };
+    get_diff_datetime(&day.and_time(*start), &end_day.and_time(*end))
+}
+
+/// Time of day ```get_diff_date_datetime``` and ```get_diff_datetime_date```
+/// assume for the side that only has a date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeOfDayAssumption {
+    /// 00:00 at the start of the day.
+    StartOfDay,
+    /// 12:00 on the day.
+    Noon,
+    /// The midnight ending the day, i.e. 00:00 on the next day, e.g. for a
+    /// deadline "due 2021-03-01" that is met by anything on that day. Panics
+    /// for ```NaiveDate::MAX```.
+    EndOfDay,
+}
+
+impl TimeOfDayAssumption {
+    /// Date and time of ```date``` under this assumption.
+    pub fn at(&self, date: &NaiveDate) -> NaiveDateTime {
+        match self {
+            TimeOfDayAssumption::StartOfDay => date.and_hms_opt(0, 0, 0).unwrap(),
+            TimeOfDayAssumption::Noon => date.and_hms_opt(12, 0, 0).unwrap(),
+            TimeOfDayAssumption::EndOfDay => date
+                .succ_opt()
+                .expect("date out of range")
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+        }
+    }
+}
+
+/// Takes a date and a date with time, and gives the difference like
+/// ```get_diff_datetime``` after giving the date the time of day
+/// ```assumption``` says.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::{get_diff_date_datetime, TimeOfDayAssumption};
+///
+/// let due = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap();
+/// let submitted = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap().and_hms_opt(18, 0, 0).unwrap();
+///
+/// let late = get_diff_date_datetime(&due, &submitted, TimeOfDayAssumption::EndOfDay);
+/// # assert_eq!((late.hours(), late.positive()), (6, false));
+/// # let late = get_diff_date_datetime(&due, &submitted, TimeOfDayAssumption::StartOfDay);
+/// # assert_eq!((late.hours(), late.positive()), (18, true));
+/// ```
+pub fn get_diff_date_datetime(
+    start: &NaiveDate,
+    end: &NaiveDateTime,
+    assumption: TimeOfDayAssumption,
+) -> DateTimeInterval {
+    get_diff_datetime(&assumption.at(start), end)
+}
+
+/// Same as ```get_diff_date_datetime``` with the date with time first.
+pub fn get_diff_datetime_date(
+    start: &NaiveDateTime,
+    end: &NaiveDate,
+    assumption: TimeOfDayAssumption,
+) -> DateTimeInterval {
+    get_diff_datetime(start, &assumption.at(end))
+}
+
+/// Time from ```now``` until the clock next shows ```target```, less than a
+/// day and zero when it shows it now. Like ```get_time_diff``` with
+/// ```MidnightPolicy::WrapAround```.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Duration, NaiveDate, NaiveTime};
+///
+/// use datediff::until_next_time;
+///
+/// let now = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap().and_hms_opt(23, 30, 0).unwrap();
+/// let backup = NaiveTime::from_hms_opt(2, 0, 0).unwrap();
+///
+/// # assert_eq!(until_next_time(now, backup), Duration::minutes(150));
+/// ```
+pub fn until_next_time(now: NaiveDateTime, target: NaiveTime) -> Duration {
+    let diff = get_time_diff(&now.time(), &target, MidnightPolicy::WrapAround);
+    Duration::hours(i64::from(diff.hours))
+        + Duration::minutes(i64::from(diff.minutes))
+        + Duration::seconds(i64::from(diff.seconds))
+        + Duration::nanoseconds(i64::from(diff.nanoseconds))
+}
+
+/// Time from ```now``` until the next ```weekday``` at ```target```, e.g.
+/// "next Tuesday 09:00", less than a week and zero when it's that time now.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{Duration, NaiveDate, NaiveTime, Weekday};
+///
+/// use datediff::until_next_weekday_time;
+///
+/// // a Monday
+/// let now = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap().and_hms_opt(10, 0, 0).unwrap();
+/// let standup = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+///
+/// # assert_eq!(until_next_weekday_time(now, Weekday::Tue, standup), Duration::hours(23));
+/// # assert_eq!(until_next_weekday_time(now, Weekday::Mon, standup), Duration::hours(7 * 24 - 1));
+/// ```
+pub fn until_next_weekday_time(
+    now: NaiveDateTime,
+    weekday: Weekday,
+    target: NaiveTime,
+) -> Duration {
+    let until_time = until_next_time(now, target);
+    //the day the clock next shows the target, then on to the weekday
+    let next = (now + until_time).weekday();
+    let days = (7 + weekday.num_days_from_monday() - next.num_days_from_monday()) % 7;
+    until_time + Duration::days(i64::from(days))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Weekday};
+
+    use crate::{
+        age_exact, get_diff, get_diff_date_datetime, get_diff_datetime, get_diff_datetime_date,
+        get_time_diff, until_next_time, until_next_weekday_time, EomPolicy, Interval,
+        MidnightPolicy, TimeOfDayAssumption,
+    };
+
+    #[test]
+    fn datetime() {
+        let at = |y, m, d, h, min, s| {
+            NaiveDate::from_ymd_opt(y, m, d)
+                .unwrap()
+                .and_hms_opt(h, min, s)
+                .unwrap()
+        };
+
+        let cases = [
+            (
+                at(2020, 1, 1, 0, 0, 0),
+                at(2020, 1, 1, 0, 0, 0),
+                (0, 0, 0, 0, 0, 0),
+            ),
+            (
+                at(2020, 1, 1, 10, 0, 0),
+                at(2020, 1, 2, 9, 59, 59),
+                (0, 0, 0, 23, 59, 59),
+            ),
+            (
+                at(2020, 1, 1, 10, 0, 0),
+                at(2020, 1, 2, 10, 0, 0),
+                (0, 0, 1, 0, 0, 0),
+            ),
+            (
+                at(2020, 1, 15, 22, 0, 0),
+                at(2020, 3, 1, 2, 0, 0),
+                (0, 1, 14, 4, 0, 0),
+            ),
+            (
+                at(2020, 1, 15, 1, 0, 0),
+                at(2020, 3, 1, 2, 0, 0),
+                (0, 1, 15, 1, 0, 0),
+            ),
+            (
+                at(2019, 12, 31, 23, 59, 59),
+                at(2020, 1, 1, 0, 0, 0),
+                (0, 0, 0, 0, 0, 1),
+            ),
+            (
+                at(2020, 2, 29, 12, 0, 0),
+                at(2021, 3, 1, 11, 0, 0),
+                (0, 11, 30, 23, 0, 0),
+            ),
+            (
+                at(1999, 5, 17, 8, 30, 0),
+                at(2021, 11, 3, 17, 45, 30),
+                (22, 5, 17, 9, 15, 30),
+            ),
+        ];
+        for &(start, end, (y, m, d, h, min, s)) in &cases {
+            for &(start, end, positive) in &[(start, end, true), (end, start, start == end)] {
+                let diff = get_diff_datetime(&start, &end);
+                assert_eq!(
+                    (diff.years(), diff.months(), diff.days()),
+                    (y, m, d),
+                    "{} {}",
+                    start,
+                    end
+                );
+                assert_eq!(
+                    (diff.hours(), diff.minutes(), diff.seconds()),
+                    (h, min, s),
+                    "{} {}",
+                    start,
+                    end
+                );
+                assert_eq!(diff.positive(), positive, "{} {}", start, end);
+            }
+        }
+
+        // the date part plus the time part leads from the earlier to the later
+        let start = at(2020, 1, 30, 18, 20, 5);
+        for hours in (0..24 * 800).step_by(7) {
+            let end = start
+                + Duration::hours(hours)
+                + Duration::seconds(hours * 13 % 3600)
+                + Duration::nanoseconds(hours * 7_919_131 % 1_000_000_000);
+            let diff = get_diff_datetime(&start, &end);
+            let date = diff.date_part().apply_to(start.date(), EomPolicy::Clamp);
+            let time = Duration::hours(i64::from(diff.hours()))
+                + Duration::minutes(i64::from(diff.minutes()))
+                + Duration::seconds(i64::from(diff.seconds()))
+                + Duration::nanoseconds(i64::from(diff.nanoseconds()));
+            assert_eq!(date.and_time(start.time()) + time, end, "{}", end);
+        }
+
+        let diff = get_diff_datetime(&at(2021, 1, 1, 12, 0, 0), &at(2020, 1, 1, 13, 0, 0));
+        assert_eq!(
+            diff.to_string(),
+            "(0 years 11 months 30 days 23 hours 0 minutes 0 seconds Behind)"
+        );
+        assert_eq!(
+            diff.date_part(),
+            get_diff(
+                &NaiveDate::from_ymd_opt(2021, 1, 1).unwrap(),
+                &NaiveDate::from_ymd_opt(2020, 1, 2).unwrap()
+            )
+        );
+        assert_eq!(
+            get_diff_datetime(&at(2021, 1, 1, 12, 0, 0), &at(2021, 1, 1, 11, 0, 0)).date_part(),
+            Interval::zero()
+        );
+    }
+
+    #[test]
+    fn subsecond() {
+        let at = |d, h, min, s, nano| {
+            NaiveDate::from_ymd_opt(2021, 6, d)
+                .unwrap()
+                .and_hms_nano_opt(h, min, s, nano)
+                .unwrap()
+        };
+
+        let diff = get_diff_datetime(&at(1, 12, 0, 0, 750_000_000), &at(2, 12, 0, 1, 250_000_000));
+        assert_eq!((diff.days(), diff.hours(), diff.seconds()), (1, 0, 0));
+        assert_eq!(
+            (diff.nanoseconds(), diff.milliseconds()),
+            (500_000_000, 500)
+        );
+        assert_eq!(
+            diff.to_string(),
+            "(0 years 0 months 1 day 0 hours 0 minutes 0.5 seconds Ahead)"
+        );
+
+        // borrowing a day for the fraction alone
+        let diff = get_diff_datetime(&at(1, 12, 0, 0, 1), &at(2, 12, 0, 0, 0));
+        assert_eq!((diff.days(), diff.hours(), diff.minutes()), (0, 23, 59));
+        assert_eq!((diff.seconds(), diff.nanoseconds()), (59, 999_999_999));
+        assert!(diff.positive());
+
+        let diff = get_diff_datetime(&at(3, 0, 0, 0, 1), &at(3, 0, 0, 0, 0));
+        assert_eq!(diff.nanoseconds(), 1);
+        assert!(!diff.positive());
+        assert!(!diff.is_zero());
+        assert_eq!(
+            diff.to_string(),
+            "(0 years 0 months 0 days 0 hours 0 minutes 0.000000001 seconds Behind)"
+        );
+
+        // a leap second is the end of the second before it
+        let leap = at(30, 23, 59, 59, 1_500_000_000);
+        let diff = get_diff_datetime(&at(30, 23, 59, 59, 0), &leap);
+        assert_eq!((diff.seconds(), diff.nanoseconds()), (0, 999_999_999));
+        let diff = get_diff_datetime(&leap, &at(30, 23, 59, 59, 999_999_999));
+        assert!(diff.is_zero());
+    }
+
+    #[test]
+    fn time_diff() {
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+
+        for &(start, end, same_day, wrap_around) in &[
+            (time(9, 0), time(17, 30), (8, 30, true), (8, 30, true)),
+            (time(22, 0), time(6, 0), (16, 0, false), (8, 0, true)),
+            (time(6, 0), time(6, 0), (0, 0, true), (0, 0, true)),
+            (time(0, 1), time(0, 0), (0, 1, false), (23, 59, true)),
+            (time(0, 0), time(23, 59), (23, 59, true), (23, 59, true)),
+        ] {
+            for &(policy, expected) in &[
+                (MidnightPolicy::SameDay, same_day),
+                (MidnightPolicy::WrapAround, wrap_around),
+            ] {
+                let diff = get_time_diff(&start, &end, policy);
+                assert_eq!(
+                    (diff.hours(), diff.minutes(), diff.positive()),
+                    expected,
+                    "{} {} {:?}",
+                    start,
+                    end,
+                    policy
+                );
+                assert!(diff.date_part().is_zero());
+            }
+        }
+    }
+
+    #[test]
+    fn mixed() {
+        let date = NaiveDate::from_ymd_opt(2021, 2, 28).unwrap();
+        let event = NaiveDate::from_ymd_opt(2021, 3, 2)
+            .unwrap()
+            .and_hms_opt(9, 0, 0)
+            .unwrap();
+
+        for &(assumption, (days, hours)) in &[
+            (TimeOfDayAssumption::StartOfDay, (2, 9)),
+            (TimeOfDayAssumption::Noon, (1, 21)),
+            (TimeOfDayAssumption::EndOfDay, (1, 9)),
+        ] {
+            let diff = get_diff_date_datetime(&date, &event, assumption);
+            assert_eq!(
+                (diff.days(), diff.hours()),
+                (days, hours),
+                "{:?}",
+                assumption
+            );
+            assert!(diff.positive());
+            let diff = get_diff_datetime_date(&event, &date, assumption);
+            assert_eq!(
+                (diff.days(), diff.hours()),
+                (days, hours),
+                "{:?}",
+                assumption
+            );
+            assert!(!diff.positive());
+        }
+        assert_eq!(
+            TimeOfDayAssumption::EndOfDay.at(&date),
+            NaiveDate::from_ymd_opt(2021, 3, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn until_next() {
+        let time = |h, m| NaiveTime::from_hms_opt(h, m, 0).unwrap();
+        // 2021-03-01 is a Monday
+        let now = NaiveDate::from_ymd_opt(2021, 3, 1)
+            .unwrap()
+            .and_hms_milli_opt(10, 0, 0, 250)
+            .unwrap();
+
+        assert_eq!(
+            until_next_time(now, time(10, 30)),
+            Duration::minutes(30) - Duration::milliseconds(250)
+        );
+        assert_eq!(
+            until_next_time(now, time(10, 0)),
+            Duration::days(1) - Duration::milliseconds(250)
+        );
+        assert_eq!(until_next_time(now, now.time()), Duration::zero());
+
+        for &(weekday, target, hours) in &[
+            (Weekday::Mon, time(11, 0), 1),
+            (Weekday::Mon, time(9, 0), 7 * 24 - 1),
+            (Weekday::Tue, time(9, 0), 23),
+            (Weekday::Sun, time(10, 0), 6 * 24),
+            (Weekday::Sat, time(23, 0), 5 * 24 + 13),
+        ] {
+            let until = until_next_weekday_time(now, weekday, target);
+            assert_eq!(
+                until,
+                Duration::hours(hours) - Duration::milliseconds(250),
+                "{} {}",
+                weekday,
+                target
+            );
+            assert_eq!((now + until).weekday(), weekday);
+            assert_eq!((now + until).time(), target);
+        }
+        assert_eq!(
+            until_next_weekday_time(now, Weekday::Mon, now.time()),
+            Duration::zero()
+        );
+    }
+
+    #[test]
+    fn age() {
+        let at = |y, m, d, h, min| {
+            NaiveDate::from_ymd_opt(y, m, d)
+                .unwrap()
+                .and_hms_opt(h, min, 0)
+                .unwrap()
+        };
+        let born = at(2020, 2, 29, 23, 50);
+
+        for &(as_of, expected) in &[
+            (at(2020, 2, 29, 23, 50), (0, 0, 0, 0, 0)),
+            (at(2020, 3, 1, 0, 10), (0, 0, 0, 0, 20)),
+            (at(2020, 3, 1, 23, 49), (0, 0, 0, 23, 59)),
+            (at(2020, 3, 29, 23, 50), (0, 1, 0, 0, 0)),
+            (at(2021, 2, 28, 23, 50), (0, 11, 30, 0, 0)),
+            (at(2021, 3, 1, 6, 0), (0, 11, 30, 6, 10)),
+            (at(2021, 3, 1, 23, 50), (1, 0, 1, 0, 0)),
+        ] {
+            let age = age_exact(&born, &as_of).unwrap();
+            assert_eq!(
+                (
+                    age.years(),
+                    age.months(),
+                    age.days(),
+                    age.hours(),
+                    age.minutes()
+                ),
+                expected,
+                "{}",
+                as_of
+            );
+            assert!(age.positive());
+        }
+        assert_eq!(age_exact(&born, &at(2020, 2, 29, 23, 49)), None);
+    }
+}
@@ -0,0 +1,982 @@
+use std::{borrow::Cow, error, fmt};
+
+use crate::{Interval, WeeksDays};
+
+/// Widest padding ```Interval::format``` accepts for a number.
+const MAX_FORMAT_WIDTH: usize = 64;
+
+/// "s" to append to an English unit name for ```count``` of it.
+pub(crate) fn plural(count: i64) -> &'static str {
+    if count.abs() == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
+
+/// Formatting mode for ```Interval::to_string_styled```, shown here for
+/// -2 years 3 months 1 day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Style {
+    /// Full unit names, ```-2 years, 3 months, 1 day```.
+    Long,
+    /// Abbreviated unit names, ```-2 yrs, 3 mos, 1 day```.
+    Short,
+    /// Single letter units, ```-2y 3m 1d```.
+    Narrow,
+    /// An ISO 8601 duration, ```-P2Y3M1D```, see ```Interval::to_iso8601```.
+    Iso8601,
+    /// Dense, ```-2y3m1d```, see ```Interval::to_compact_string```.
+    Compact,
+}
+
+/// How ```Interval::display_with``` shows whether an interval is negative.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum SignStyle {
+    /// In parentheses followed by "Ahead" or "Behind", like
+    /// ```(1 year 2 months Behind)```, the ```Display``` of ```Interval```.
+    #[default]
+    AheadBehind,
+    /// A ```-``` in front of a negative interval, nothing for a positive one.
+    LeadingMinus,
+    /// The magnitude followed by a word for each direction, like
+    /// ```SignStyle::suffix("from now", "ago")``` for ```3 months ago```.
+    Suffix {
+        ahead: Cow<'static, str>,
+        behind: Cow<'static, str>,
+    },
+    /// The magnitude only.
+    None,
+}
+
+impl SignStyle {
+    /// ```SignStyle::Suffix``` with the words for each direction, either
+    /// literals or ```String```s, e.g. translated at runtime.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::{DisplayOptions, Interval, SignStyle};
+    ///
+    /// let ago = String::from("ago");
+    /// let options = DisplayOptions::new()
+    ///     .with_hide_zero(true)
+    ///     .with_sign_style(SignStyle::suffix("from now", ago));
+    ///
+    /// # assert_eq!(Interval::of_days(3).inverse().display_with(&options).to_string(), "3 days ago");
+    /// # assert_eq!(Interval::of_days(3).display_with(&options).to_string(), "3 days from now");
+    /// ```
+    pub fn suffix(
+        ahead: impl Into<Cow<'static, str>>,
+        behind: impl Into<Cow<'static, str>>,
+    ) -> SignStyle {
+        SignStyle::Suffix {
+            ahead: ahead.into(),
+            behind: behind.into(),
+        }
+    }
+}
+
+/// Settings for ```Interval::display_with```. The defaults give the
+/// ```Display``` of ```Interval```.
+///
+/// # Example
+///
+/// ```
+/// use datediff::{DisplayOptions, Interval, SignStyle};
+///
+/// let options = DisplayOptions::new()
+///     .with_hide_zero(true)
+///     .with_sign_style(SignStyle::LeadingMinus);
+/// let interval = Interval::new(1, 0, 3, false).unwrap();
+///
+/// # assert_eq!(interval.display_with(&options).to_string(), "-1 year 3 days");
+/// # assert_eq!(interval.display_with(&options.clone().with_abbreviate(true)).to_string(), "-1y 3d");
+/// # assert_eq!(interval.display_with(&options.with_separator(", ")).to_string(), "-1 year, 3 days");
+/// # assert_eq!(interval.to_string(), "(1 year 0 months 3 days Behind)");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DisplayOptions {
+    hide_zero: bool,
+    abbreviate: bool,
+    separator: Cow<'static, str>,
+    sign_style: SignStyle,
+}
+
+impl Default for DisplayOptions {
+    fn default() -> DisplayOptions {
+        DisplayOptions {
+            hide_zero: false,
+            abbreviate: false,
+            separator: Cow::Borrowed(" "),
+            sign_style: SignStyle::AheadBehind,
+        }
+    }
+}
+
+impl DisplayOptions {
+    pub fn new() -> DisplayOptions {
+        DisplayOptions::default()
+    }
+
+    /// Leaves out zero components, a zero interval still shows ```0 days```.
+    pub fn with_hide_zero(mut self, hide_zero: bool) -> Self {
+        self.hide_zero = hide_zero;
+        self
+    }
+    /// Writes units as ```y```, ```m``` and ```d``` right after the number,
+    /// like ```2y 3m```, instead of ```2 years 3 months```.
+    pub fn with_abbreviate(mut self, abbreviate: bool) -> Self {
+        self.abbreviate = abbreviate;
+        self
+    }
+    /// Text between components, a space by default. A ```", "``` gives
+    /// ```2 years, 3 months```. Takes a ```String``` as well, e.g. one read
+    /// from a config file.
+    pub fn with_separator(mut self, separator: impl Into<Cow<'static, str>>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+    /// How the sign is shown, see ```SignStyle```.
+    pub fn with_sign_style(mut self, sign_style: SignStyle) -> Self {
+        self.sign_style = sign_style;
+        self
+    }
+
+    pub fn hide_zero(&self) -> bool {
+        self.hide_zero
+    }
+    pub fn abbreviate(&self) -> bool {
+        self.abbreviate
+    }
+    pub fn separator(&self) -> &str {
+        &self.separator
+    }
+    pub fn sign_style(&self) -> &SignStyle {
+        &self.sign_style
+    }
+}
+
+/// Formats an ```Interval``` with ```DisplayOptions```, see
+/// ```Interval::display_with```.
+#[derive(Debug, Clone, Copy)]
+pub struct IntervalDisplay<'a> {
+    interval: &'a Interval,
+    options: &'a DisplayOptions,
+}
+
+impl IntervalDisplay<'_> {
+    /// Each component shown, with its unit.
+    fn parts(&self) -> Vec<String> {
+        let interval = self.interval;
+        let options = self.options;
+        let unit = |value: u32, name: &str| {
+            if options.abbreviate {
+                format!("{}{}", value, &name[..1])
+            } else {
+                format!("{} {}{}", value, name, plural(i64::from(value)))
+            }
+        };
+        let mut parts: Vec<_> = [
+            (interval.years, "year"),
+            (interval.months, "month"),
+            (interval.days, "day"),
+        ]
+        .iter()
+        .filter(|&&(value, _)| !options.hide_zero || value != 0)
+        .map(|&(value, name)| unit(value, name))
+        .collect();
+        if parts.is_empty() {
+            parts.push(unit(0, "day"));
+        }
+        parts
+    }
+}
+
+impl fmt::Display for IntervalDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let interval = self.interval;
+        let options = self.options;
+        let parts = self.parts();
+        let text = parts.join(&options.separator);
+        //padded as a whole, so the width and alignment of {:>30} apply
+        let text = match &options.sign_style {
+            SignStyle::AheadBehind => format!(
+                "({} {})",
+                text,
+                if interval.positive { "Ahead" } else { "Behind" }
+            ),
+            SignStyle::LeadingMinus if !interval.positive => format!("-{}", text),
+            SignStyle::Suffix { ahead, behind } => {
+                format!(
+                    "{} {}",
+                    text,
+                    if interval.positive { ahead } else { behind }
+                )
+            }
+            SignStyle::LeadingMinus | SignStyle::None => text,
+        };
+        pad(f, &text)
+    }
+}
+
+/// Same as ```Formatter::pad```, but ignores the precision, which would cut
+/// the text short.
+fn pad(f: &mut fmt::Formatter<'_>, text: &str) -> fmt::Result {
+    let fill = f.width().unwrap_or(0).saturating_sub(text.chars().count());
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Right) => (fill, 0),
+        Some(fmt::Alignment::Center) => (fill / 2, fill - fill / 2),
+        Some(fmt::Alignment::Left) | None => (0, fill),
+    };
+    let padding = |count: usize| f.fill().to_string().repeat(count);
+    let (before, after) = (padding(before), padding(after));
+    write!(f, "{}{}{}", before, text, after)
+}
+
+impl Interval {
+    /// Formats the interval with ```options``` instead of the fixed
+    /// ```Display```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::{DisplayOptions, Interval, SignStyle};
+    ///
+    /// let options = DisplayOptions::new().with_hide_zero(true).with_sign_style(SignStyle::None);
+    ///
+    /// println!("{} left", Interval::of_months(1).display_with(&options));
+    /// # assert_eq!(Interval::of_months(1).display_with(&options).to_string(), "1 month");
+    /// # assert_eq!(Interval::zero().display_with(&options).to_string(), "0 days");
+    /// ```
+    pub fn display_with<'a>(&'a self, options: &'a DisplayOptions) -> IntervalDisplay<'a> {
+        IntervalDisplay {
+            interval: self,
+            options,
+        }
+    }
+
+    /// Formats the interval densely as ```2y3m4d```, leaving out zero
+    /// components, with a leading ```-``` when negative and ```0d``` when
+    /// zero. Same as ```{:#}```, and parsed back by ```str::parse```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::Interval;
+    ///
+    /// let interval = Interval::new(2, 3, 4, false).unwrap();
+    ///
+    /// println!("backup-{}.tar", interval.to_compact_string());
+    /// # assert_eq!(interval.to_compact_string(), "-2y3m4d");
+    /// # assert_eq!(interval.to_compact_string().parse::<Interval>(), Ok(interval));
+    /// # assert_eq!(Interval::of_months(6).to_compact_string(), "6m");
+    /// ```
+    pub fn to_compact_string(&self) -> String {
+        format!("{:#}", self)
+    }
+
+    /// Formats the non-zero components as a list for a sentence, e.g. "2
+    /// years, 3 months and 4 days" with ```conjunction``` "and" before the
+    /// last one, and "2 years, 3 months, and 4 days" with
+    /// ```serial_comma```. The sign is left out, a zero interval is "0 days".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::Interval;
+    ///
+    /// let interval = Interval::new(2, 3, 4, true).unwrap();
+    ///
+    /// println!("Delivered in {}.", interval.to_sentence("and", false));
+    /// # assert_eq!(interval.to_sentence("and", false), "2 years, 3 months and 4 days");
+    /// # assert_eq!(interval.to_sentence("and", true), "2 years, 3 months, and 4 days");
+    /// # assert_eq!(Interval::new(1, 0, 1, true).unwrap().to_sentence("und", true), "1 year und 1 day");
+    /// ```
+    pub fn to_sentence(&self, conjunction: &str, serial_comma: bool) -> String {
+        let mut parts = self
+            .display_with(&DisplayOptions::new().with_hide_zero(true))
+            .parts();
+        match parts.len() {
+            1 => parts.remove(0),
+            2 => format!("{} {} {}", parts[0], conjunction, parts[1]),
+            _ => {
+                let last = parts.pop().unwrap();
+                let comma = if serial_comma { "," } else { "" };
+                format!("{}{} {} {}", parts.join(", "), comma, conjunction, last)
+            }
+        }
+    }
+
+    /// Formats the interval as logfmt key value pairs,
+    /// ```years=2 months=3 days=4 sign=+```, to append to a log line. The
+    /// sign is ```-``` when negative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::Interval;
+    ///
+    /// let interval = Interval::new(2, 3, 4, false).unwrap();
+    ///
+    /// println!("level=info msg=\"contract renewed\" {}", interval.to_logfmt());
+    /// # assert_eq!(interval.to_logfmt(), "years=2 months=3 days=4 sign=-");
+    /// ```
+    pub fn to_logfmt(&self) -> String {
+        format!(
+            "years={} months={} days={} sign={}",
+            self.years,
+            self.months,
+            self.days,
+            if self.positive { '+' } else { '-' }
+        )
+    }
+
+    /// Formats the interval as a JSON object on a single line for JSON
+    /// lines logs, ```{"years":2,"months":3,"days":4,"sign":"+"}```, with
+    /// the same fields as ```Interval::to_logfmt```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::Interval;
+    ///
+    /// # assert_eq!(Interval::of_days(4).to_json_line(), r#"{"years":0,"months":0,"days":4,"sign":"+"}"#);
+    /// ```
+    pub fn to_json_line(&self) -> String {
+        format!(
+            r#"{{"years":{},"months":{},"days":{},"sign":"{}"}}"#,
+            self.years,
+            self.months,
+            self.days,
+            if self.positive { '+' } else { '-' }
+        )
+    }
+
+    /// Formats the interval in ```style```. Zero components are left out
+    /// except in a zero interval, and a negative one starts with ```-```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::{Interval, Style};
+    ///
+    /// let interval = Interval::new(1, 2, 0, true).unwrap();
+    ///
+    /// # assert_eq!(interval.to_string_styled(Style::Long), "1 year, 2 months");
+    /// # assert_eq!(interval.to_string_styled(Style::Short), "1 yr, 2 mos");
+    /// # assert_eq!(interval.to_string_styled(Style::Narrow), "1y 2m");
+    /// # assert_eq!(interval.to_string_styled(Style::Iso8601), "P1Y2M");
+    /// # assert_eq!(interval.to_string_styled(Style::Compact), "1y2m");
+    /// ```
+    pub fn to_string_styled(&self, style: Style) -> String {
+        let options = DisplayOptions::new()
+            .with_hide_zero(true)
+            .with_sign_style(SignStyle::LeadingMinus);
+        match style {
+            Style::Long => self.display_with(&options.with_separator(", ")).to_string(),
+            Style::Short => {
+                let mut parts: Vec<_> = [
+                    (self.years, "yr", "yrs"),
+                    (self.months, "mo", "mos"),
+                    (self.days, "day", "days"),
+                ]
+                .iter()
+                .filter(|&&(value, _, _)| value != 0)
+                .map(|&(value, one, many)| {
+                    format!("{} {}", value, if value == 1 { one } else { many })
+                })
+                .collect();
+                if parts.is_empty() {
+                    parts.push("0 days".to_string());
+                }
+                let sign = if self.positive { "" } else { "-" };
+                format!("{}{}", sign, parts.join(", "))
+            }
+            Style::Narrow => self
+                .display_with(&options.with_abbreviate(true))
+                .to_string(),
+            Style::Iso8601 => self.to_iso8601(),
+            Style::Compact => self.to_compact_string(),
+        }
+    }
+}
+
+/// Error returned when a pattern for ```Interval::format``` is invalid, the
+/// offending part of the pattern is included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatError(String);
+
+impl FormatError {
+    /// The part of the pattern that couldn't be used.
+    pub fn text(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid format pattern: {:?}", self.0)
+    }
+}
+
+impl error::Error for FormatError {}
+
+/// Text of an optional section, see ```Interval::format```.
+#[derive(Default)]
+struct Section {
+    text: String,
+    values: usize,
+    non_zero: bool,
+}
+
+impl Interval {
+    /// Formats the interval like ```strftime``` formats dates, e.g.
+    /// ```"%y years, %m months and %d days"```. Specifiers are:
+    ///
+    /// - ```%y```, ```%m```, ```%d```: years, months and days
+    /// - ```%M```: total months, years included
+    /// - ```%s```: ```-``` when negative, nothing otherwise
+    /// - ```%+```: ```-``` when negative, ```+``` otherwise
+    /// - ```%%```: a literal ```%```
+    /// - ```%[``` ... ```%]```: an optional section, left out when every
+    ///   number in it is zero
+    ///
+    /// A width between the ```%``` and a number pads it with spaces, a
+    /// width starting with ```0``` pads it with zeros, so ```%02d``` gives
+    /// ```07```. Widths above 64 are an error. Without ```%s``` or ```%+```
+    /// the sign isn't shown.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::Interval;
+    ///
+    /// let interval = Interval::new(2, 0, 7, false).unwrap();
+    ///
+    /// println!("{}", interval.format("%s%[%y years, %]%[%m months, %]%d days").unwrap());
+    /// # assert_eq!(interval.format("%y years, %m months and %d days").unwrap(), "2 years, 0 months and 7 days");
+    /// # assert_eq!(interval.format("%s%[%y years, %]%[%m months, %]%d days").unwrap(), "-2 years, 7 days");
+    /// # assert_eq!(interval.format("%+%04y-%02m-%02d").unwrap(), "-0002-00-07");
+    /// # assert!(interval.format("%q").is_err());
+    /// ```
+    pub fn format(&self, pattern: &str) -> Result<String, FormatError> {
+        let mut out = String::new();
+        let mut section: Option<Section> = None;
+        let mut chars = pattern.char_indices().peekable();
+        while let Some((start, c)) = chars.next() {
+            let text = match &mut section {
+                Some(section) => &mut section.text,
+                None => &mut out,
+            };
+            if c != '%' {
+                text.push(c);
+                continue;
+            }
+            let zero = matches!(chars.peek(), Some(&(_, '0')));
+            let mut width = Some(0usize);
+            while let Some(&(_, digit)) = chars.peek() {
+                match digit.to_digit(10) {
+                    Some(digit) => {
+                        width = width
+                            .and_then(|width| width.checked_mul(10))
+                            .and_then(|width| width.checked_add(digit as usize))
+                    }
+                    None => break,
+                }
+                chars.next();
+            }
+            let (end, specifier) = match chars.next() {
+                Some((index, specifier)) => (index + specifier.len_utf8(), specifier),
+                None => return Err(FormatError(pattern[start..].to_string())),
+            };
+            let width = match width {
+                Some(width) if width <= MAX_FORMAT_WIDTH => width,
+                _ => return Err(FormatError(pattern[start..end].to_string())),
+            };
+            let value = match specifier {
+                'y' => i64::from(self.years),
+                'm' => i64::from(self.months),
+                'd' => i64::from(self.days),
+                'M' => self.total_months().abs(),
+                _ if width != 0 => return Err(FormatError(pattern[start..end].to_string())),
+                '%' => {
+                    text.push('%');
+                    continue;
+                }
+                's' => {
+                    if !self.positive {
+                        text.push('-');
+                    }
+                    continue;
+                }
+                '+' => {
+                    text.push(if self.positive { '+' } else { '-' });
+                    continue;
+                }
+                '[' if section.is_none() => {
+                    section = Some(Section::default());
+                    continue;
+                }
+                ']' if section.is_some() => {
+                    let section = section.take().unwrap();
+                    if section.non_zero || section.values == 0 {
+                        out.push_str(&section.text);
+                    }
+                    continue;
+                }
+                _ => return Err(FormatError(pattern[start..end].to_string())),
+            };
+            if zero {
+                text.push_str(&format!("{:0width$}", value, width = width));
+            } else {
+                text.push_str(&format!("{:width$}", value, width = width));
+            }
+            if let Some(section) = &mut section {
+                section.values += 1;
+                section.non_zero |= value != 0;
+            }
+        }
+        match section {
+            Some(_) => Err(FormatError("%[".to_string())),
+            None => Ok(out),
+        }
+    }
+}
+
+impl WeeksDays {
+    /// Formats weeks and days as "29 weeks, 3 days", the notation of
+    /// gestational age and sprints, with a leading ```-``` when negative.
+    /// Both are always shown. Use ```Interval::to_weeks_days``` to get them
+    /// for an interval from its start.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::{get_diff_weeks, Interval};
+    ///
+    /// let last_period = NaiveDate::from_ymd_opt(2021, 1, 4).unwrap();
+    /// let today = NaiveDate::from_ymd_opt(2021, 7, 29).unwrap();
+    ///
+    /// println!("{}", get_diff_weeks(&last_period, &today).to_long_string());
+    /// # assert_eq!(get_diff_weeks(&last_period, &today).to_long_string(), "29 weeks, 3 days");
+    /// # assert_eq!(Interval::of_months(1).to_weeks_days(last_period).to_long_string(), "4 weeks, 3 days");
+    /// ```
+    pub fn to_long_string(&self) -> String {
+        format!(
+            "{}{} week{}, {} day{}",
+            if self.positive() { "" } else { "-" },
+            self.weeks(),
+            plural(i64::from(self.weeks())),
+            self.days(),
+            plural(i64::from(self.days()))
+        )
+    }
+}
+
+/// Writes ```(2 years 3 months 0 days Ahead)```, or the compact ```2y3m```
+/// with the alternate flag ```{:#}```, ```-2y3m``` when negative. Width,
+/// fill and alignment pad the whole text, so ```{:<32}``` lines up a table.
+/// A precision is ignored.
+///
+/// # Example
+///
+/// ```
+/// use datediff::Interval;
+///
+/// let interval = Interval::new(2, 3, 0, false).unwrap();
+///
+/// # assert_eq!(format!("{}", interval), "(2 years 3 months 0 days Behind)");
+/// # assert_eq!(format!("{:#}", interval), "-2y3m");
+/// # assert_eq!(format!("[{:>8}]", format!("{:#}", interval)), "[   -2y3m]");
+/// # assert_eq!(format!("[{:>#8}]", interval), "[   -2y3m]");
+/// # assert_eq!(format!("{:#.0}", interval), "-2y3m");
+/// ```
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let options = if f.alternate() {
+            DisplayOptions::new()
+                .with_hide_zero(true)
+                .with_abbreviate(true)
+                .with_separator("")
+                .with_sign_style(SignStyle::LeadingMinus)
+        } else {
+            DisplayOptions::default()
+        };
+        self.display_with(&options).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use crate::{get_diff_weeks, DisplayOptions, Interval, SignStyle, Style};
+
+    #[test]
+    fn display() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+        for &(i, expected) in &[
+            (interval(1, 1, 1, true), "(1 year 1 month 1 day Ahead)"),
+            (
+                interval(2, 0, 30, false),
+                "(2 years 0 months 30 days Behind)",
+            ),
+            (Interval::zero(), "(0 years 0 months 0 days Ahead)"),
+        ] {
+            assert_eq!(i.to_string(), expected);
+        }
+
+        let hide_zero = DisplayOptions::new().with_hide_zero(true);
+        for (i, sign_style, expected) in [
+            (
+                interval(2, 0, 30, false),
+                SignStyle::AheadBehind,
+                "(2 years 30 days Behind)",
+            ),
+            (
+                interval(2, 0, 30, false),
+                SignStyle::LeadingMinus,
+                "-2 years 30 days",
+            ),
+            (
+                interval(2, 0, 30, false),
+                SignStyle::None,
+                "2 years 30 days",
+            ),
+            (interval(0, 1, 0, true), SignStyle::LeadingMinus, "1 month"),
+            (Interval::zero(), SignStyle::LeadingMinus, "0 days"),
+        ] {
+            assert_eq!(
+                i.display_with(&hide_zero.clone().with_sign_style(sign_style.clone()))
+                    .to_string(),
+                expected,
+                "{:?} {:?}",
+                i,
+                sign_style
+            );
+        }
+        assert_eq!(
+            interval(0, 0, 1, false)
+                .display_with(&DisplayOptions::new().with_sign_style(SignStyle::LeadingMinus))
+                .to_string(),
+            "-0 years 0 months 1 day"
+        );
+    }
+
+    #[test]
+    fn display_options() {
+        let interval = Interval::new(2, 3, 0, true).unwrap();
+        let compact = DisplayOptions::new()
+            .with_hide_zero(true)
+            .with_abbreviate(true)
+            .with_sign_style(SignStyle::LeadingMinus);
+        let sentence = DisplayOptions::new()
+            .with_hide_zero(true)
+            .with_separator(", ")
+            .with_sign_style(SignStyle::None);
+        for (i, options, expected) in vec![
+            (interval, compact.clone(), "2y 3m"),
+            (interval.inverse(), compact.clone(), "-2y 3m"),
+            (interval, compact.clone().with_separator(""), "2y3m"),
+            (interval, compact.clone().with_hide_zero(false), "2y 3m 0d"),
+            (Interval::zero(), compact, "0d"),
+            (interval, sentence.clone(), "2 years, 3 months"),
+            (Interval::of_days(1), sentence, "1 day"),
+            (
+                interval,
+                DisplayOptions::new()
+                    .with_hide_zero(true)
+                    .with_separator(" | ".to_string()),
+                "(2 years | 3 months Ahead)",
+            ),
+            (
+                interval.inverse(),
+                DisplayOptions::new()
+                    .with_abbreviate(true)
+                    .with_separator(", "),
+                "(2y, 3m, 0d Behind)",
+            ),
+        ] {
+            assert_eq!(
+                i.display_with(&options).to_string(),
+                expected,
+                "{:?}",
+                options
+            );
+        }
+    }
+
+    #[test]
+    fn formatter_flags() {
+        let interval = Interval::new(1, 0, 12, true).unwrap();
+        for (text, expected) in &[
+            (format!("{:#}", interval), "1y12d"),
+            (format!("{:#}", interval.inverse()), "-1y12d"),
+            (format!("{:#}", Interval::zero()), "0d"),
+            (format!("{:>8}|", format!("{:#}", interval)), "   1y12d|"),
+            (format!("{:<#8}|", interval), "1y12d   |"),
+            (format!("{:^#9}|", interval), "  1y12d  |"),
+            (format!("{:->#8}|", interval), "---1y12d|"),
+            (format!("{:#.2}|", interval), "1y12d|"),
+            (format!("{:*^#9.0}|", interval), "**1y12d**|"),
+            (
+                format!("{:4}|", interval),
+                "(1 year 0 months 12 days Ahead)|",
+            ),
+            (
+                format!("{:>34}|", interval),
+                "   (1 year 0 months 12 days Ahead)|",
+            ),
+            (
+                format!(
+                    "{:.<12}|",
+                    interval.display_with(
+                        &DisplayOptions::new()
+                            .with_abbreviate(true)
+                            .with_hide_zero(true)
+                    )
+                ),
+                "(1y 12d Ahead)|",
+            ),
+            (
+                format!(
+                    "{:.<8}|",
+                    interval.display_with(
+                        &DisplayOptions::new()
+                            .with_abbreviate(true)
+                            .with_sign_style(SignStyle::None)
+                    )
+                ),
+                "1y 0m 12d|",
+            ),
+            (
+                format!(
+                    "{:.<8}|",
+                    Interval::of_days(3).display_with(
+                        &DisplayOptions::new()
+                            .with_hide_zero(true)
+                            .with_sign_style(SignStyle::None)
+                    )
+                ),
+                "3 days..|",
+            ),
+        ] {
+            assert_eq!(text, *expected);
+        }
+    }
+
+    #[test]
+    fn compact() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+        for &(i, expected) in &[
+            (interval(2, 3, 4, true), "2y3m4d"),
+            (interval(2, 3, 4, false), "-2y3m4d"),
+            (interval(0, 0, 30, true), "30d"),
+            (interval(10, 0, 1, false), "-10y1d"),
+            (Interval::zero(), "0d"),
+        ] {
+            assert_eq!(i.to_compact_string(), expected);
+            assert_eq!(expected.parse::<Interval>(), Ok(i));
+        }
+    }
+
+    #[test]
+    fn styled() {
+        let a = Interval::new(2, 3, 1, false).unwrap();
+        let b = Interval::new(1, 0, 12, true).unwrap();
+        for &(style, a_text, b_text, zero) in &[
+            (
+                Style::Long,
+                "-2 years, 3 months, 1 day",
+                "1 year, 12 days",
+                "0 days",
+            ),
+            (
+                Style::Short,
+                "-2 yrs, 3 mos, 1 day",
+                "1 yr, 12 days",
+                "0 days",
+            ),
+            (Style::Narrow, "-2y 3m 1d", "1y 12d", "0d"),
+            (Style::Iso8601, "-P2Y3M1D", "P1Y12D", "P0D"),
+            (Style::Compact, "-2y3m1d", "1y12d", "0d"),
+        ] {
+            assert_eq!(a.to_string_styled(style), a_text, "{:?}", style);
+            assert_eq!(b.to_string_styled(style), b_text, "{:?}", style);
+            assert_eq!(
+                Interval::zero().to_string_styled(style),
+                zero,
+                "{:?}",
+                style
+            );
+        }
+    }
+
+    #[test]
+    fn sentence() {
+        let interval = |y, m, d| Interval::new(y, m, d, true).unwrap();
+        for &(i, expected, serial) in &[
+            (
+                interval(2, 3, 4),
+                "2 years, 3 months and 4 days",
+                "2 years, 3 months, and 4 days",
+            ),
+            (
+                interval(2, 0, 4),
+                "2 years and 4 days",
+                "2 years and 4 days",
+            ),
+            (interval(0, 1, 1), "1 month and 1 day", "1 month and 1 day"),
+            (interval(1, 0, 0), "1 year", "1 year"),
+            (Interval::zero(), "0 days", "0 days"),
+        ] {
+            assert_eq!(i.to_sentence("and", false), expected);
+            assert_eq!(i.to_sentence("and", true), serial);
+            assert_eq!(i.inverse().to_sentence("and", false), expected);
+        }
+        assert_eq!(
+            interval(2, 3, 4).to_sentence("&", false),
+            "2 years, 3 months & 4 days"
+        );
+    }
+
+    #[test]
+    fn weeks_days() {
+        let date = |m, d| NaiveDate::from_ymd_opt(2021, m, d).unwrap();
+        for &(start, end, expected) in &[
+            (date(1, 4), date(7, 29), "29 weeks, 3 days"),
+            (date(1, 4), date(1, 12), "1 week, 1 day"),
+            (date(1, 4), date(1, 4), "0 weeks, 0 days"),
+            (date(1, 18), date(1, 4), "-2 weeks, 0 days"),
+        ] {
+            assert_eq!(
+                get_diff_weeks(&start, &end).to_long_string(),
+                expected,
+                "{} {}",
+                start,
+                end
+            );
+        }
+        assert_eq!(
+            Interval::of_months(1)
+                .to_weeks_days(date(2, 1))
+                .to_long_string(),
+            "4 weeks, 0 days"
+        );
+    }
+
+    #[test]
+    fn suffix() {
+        let relative = DisplayOptions::new()
+            .with_hide_zero(true)
+            .with_sign_style(SignStyle::suffix("from now", "ago"));
+        let interval = Interval::new(0, 3, 1, true).unwrap();
+        assert_eq!(
+            interval.display_with(&relative).to_string(),
+            "3 months 1 day from now"
+        );
+        assert_eq!(
+            interval.inverse().display_with(&relative).to_string(),
+            "3 months 1 day ago"
+        );
+        assert_eq!(
+            interval
+                .inverse()
+                .display_with(&relative.clone().with_abbreviate(true).with_separator(""))
+                .to_string(),
+            "3m1d ago"
+        );
+
+        let (ahead, behind) = (String::from("hence"), String::from("since"));
+        let translated = DisplayOptions::new()
+            .with_sign_style(SignStyle::suffix(ahead, behind))
+            .with_separator(String::from(", "));
+        assert_eq!(
+            interval.inverse().display_with(&translated).to_string(),
+            "0 years, 3 months, 1 day since"
+        );
+        assert_eq!(
+            translated.sign_style(),
+            &SignStyle::Suffix {
+                ahead: "hence".into(),
+                behind: "since".into()
+            }
+        );
+    }
+
+    #[test]
+    fn logfmt() {
+        let interval = Interval::new(2, 3, 4, true).unwrap();
+        assert_eq!(interval.to_logfmt(), "years=2 months=3 days=4 sign=+");
+        assert_eq!(
+            interval.inverse().to_logfmt(),
+            "years=2 months=3 days=4 sign=-"
+        );
+        assert_eq!(
+            Interval::zero().to_logfmt(),
+            "years=0 months=0 days=0 sign=+"
+        );
+        assert_eq!(
+            interval.inverse().to_json_line(),
+            r#"{"years":2,"months":3,"days":4,"sign":"-"}"#
+        );
+    }
+
+    #[test]
+    fn format() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+        let a = interval(2, 3, 4, true);
+        for &(pattern, expected) in &[
+            (
+                "%y years, %m months and %d days",
+                "2 years, 3 months and 4 days",
+            ),
+            ("%yy%mm%dd", "2y3m4d"),
+            ("%M months", "27 months"),
+            ("%03y|%3m|%1d|%0d", "002|  3|4|4"),
+            (
+                "%064d",
+                "0000000000000000000000000000000000000000000000000000000000000004",
+            ),
+            ("%+%y %s", "+2 "),
+            ("100%% of %d", "100% of 4"),
+            ("%[%y years %]%[a%]%[no%]", "2 years ano"),
+            ("", ""),
+        ] {
+            assert_eq!(a.format(pattern).unwrap(), expected, "{}", pattern);
+        }
+
+        let b = interval(0, 1, 0, false);
+        for &(pattern, expected) in &[
+            ("%s%[%y years %]%[%m month %]%[%d days%]", "-1 month "),
+            ("%[%y/%d%]%[%m/%y%]", "1/0"),
+            ("%+%M", "-1"),
+        ] {
+            assert_eq!(b.format(pattern).unwrap(), expected, "{}", pattern);
+        }
+
+        for &(pattern, text) in &[
+            ("%q", "%q"),
+            ("%y %", "%"),
+            ("%2", "%2"),
+            ("%2s", "%2s"),
+            ("%[%[%]%]", "%["),
+            ("%]", "%]"),
+            ("%[%y", "%["),
+            ("%é", "%é"),
+            ("%65d", "%65d"),
+            ("%999999999d", "%999999999d"),
+            ("%99999999999999999999999d", "%99999999999999999999999d"),
+        ] {
+            assert_eq!(a.format(pattern).unwrap_err().text(), text, "{}", pattern);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use chrono::NaiveDate;
+
+use crate::{get_diff, Interval};
+
+/// Fluent access to ```get_diff``` on dates.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::DateDiffExt;
+///
+/// let independence = NaiveDate::from_ymd_opt(1947, 8, 15).unwrap();
+/// let republic = NaiveDate::from_ymd_opt(1950, 1, 26).unwrap();
+///
+/// println!("Republic came {} later", republic.since(&independence));
+/// # use datediff::Interval;
+/// # assert_eq!(republic.since(&independence), Interval::new(2, 5, 11, true).unwrap());
+/// # assert_eq!(independence.until(&republic), Interval::new(2, 5, 11, true).unwrap());
+/// # assert_eq!(republic.diff(&independence), Interval::new(2, 5, 11, false).unwrap());
+/// ```
+pub trait DateDiffExt {
+    /// Difference from this date to ```other```, same as ```get_diff(self, other)```.
+    fn diff(&self, other: &Self) -> Interval;
+
+    /// Time elapsed from ```earlier``` to this date, positive if ```earlier```
+    /// is actually earlier.
+    fn since(&self, earlier: &Self) -> Interval;
+
+    /// Time left from this date to ```later```, positive if ```later```
+    /// is actually later.
+    fn until(&self, later: &Self) -> Interval;
+}
+
+impl DateDiffExt for NaiveDate {
+    fn diff(&self, other: &NaiveDate) -> Interval {
+        get_diff(self, other)
+    }
+
+    fn since(&self, earlier: &NaiveDate) -> Interval {
+        get_diff(earlier, self)
+    }
+
+    fn until(&self, later: &NaiveDate) -> Interval {
+        get_diff(self, later)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use crate::{get_diff, DateDiffExt};
+
+    #[test]
+    fn ext() {
+        let a = NaiveDate::from_ymd_opt(2013, 2, 5).unwrap();
+        let b = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+
+        assert_eq!(a.diff(&b), get_diff(&a, &b));
+        assert_eq!(b.diff(&a), get_diff(&b, &a));
+        assert_eq!(b.since(&a), get_diff(&a, &b));
+        assert!(!a.since(&b).positive());
+        assert_eq!(a.until(&b), get_diff(&a, &b));
+        assert!(!b.until(&a).positive());
+    }
+}
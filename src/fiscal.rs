@@ -0,0 +1,158 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::days_in_month;
+
+/// Fiscal year starting on ```start_month```/```start_day``` every year, e.g.
+/// April 1 in the UK and India, October 1 for the US federal government.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::FiscalCalendar;
+///
+/// let april = FiscalCalendar::new(4, 1).unwrap();
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// println!("Fiscal years crossed: {}", april.fiscal_years_between(&date(2021, 3, 31), &date(2021, 4, 1)));
+/// # assert_eq!(april.fiscal_years_between(&date(2021, 3, 31), &date(2021, 4, 1)), 1);
+/// # assert_eq!(april.fiscal_quarters_between(&date(2021, 4, 1), &date(2022, 1, 1)), 3);
+/// # assert_eq!(april.fiscal_year_of(&date(2021, 3, 31)), 2020);
+/// # assert_eq!(april.fiscal_quarter_of(&date(2021, 3, 31)), 4);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FiscalCalendar {
+    start_month: u32,
+    start_day: u32,
+}
+
+impl FiscalCalendar {
+    /// Calendar year, starting on Jan 1.
+    pub const CALENDAR: FiscalCalendar = FiscalCalendar {
+        start_month: 1,
+        start_day: 1,
+    };
+
+    /// Gives ```None``` when the start isn't a day of every year, so Feb 29
+    /// isn't allowed.
+    pub fn new(start_month: u32, start_day: u32) -> Option<FiscalCalendar> {
+        if !(1..=12).contains(&start_month)
+            || start_day == 0
+            || start_day > days_in_month(2021, start_month)
+        {
+            return None;
+        }
+        Some(FiscalCalendar {
+            start_month,
+            start_day,
+        })
+    }
+
+    pub fn start_month(&self) -> u32 {
+        self.start_month
+    }
+    pub fn start_day(&self) -> u32 {
+        self.start_day
+    }
+
+    /// Months since the start of the common era, shifted so that fiscal
+    /// years start at multiples of 12.
+    fn period_index(&self, date: &NaiveDate) -> i64 {
+        let months = i64::from(date.year()) * 12 + i64::from(date.month0())
+            - i64::from(self.start_month - 1);
+        if date.day() < self.start_day {
+            months - 1
+        } else {
+            months
+        }
+    }
+
+    /// Fiscal year ```date``` falls in, named after the calendar year it
+    /// starts in. Add 1 for conventions naming it after the year it ends in,
+    /// like the US federal fiscal year.
+    pub fn fiscal_year_of(&self, date: &NaiveDate) -> i32 {
+        self.period_index(date).div_euclid(12) as i32
+    }
+
+    /// Fiscal quarter ```date``` falls in, from 1 to 4.
+    pub fn fiscal_quarter_of(&self, date: &NaiveDate) -> u32 {
+        (self.period_index(date).rem_euclid(12) / 3 + 1) as u32
+    }
+
+    /// Counts the fiscal year starts crossed between ```start``` and ```end```,
+    /// like ```diff_in``` does for calendar years. Negative when ```end``` is
+    /// before ```start```.
+    pub fn fiscal_years_between(&self, start: &NaiveDate, end: &NaiveDate) -> i64 {
+        self.period_index(end).div_euclid(12) - self.period_index(start).div_euclid(12)
+    }
+
+    /// Counts the fiscal quarter starts crossed between ```start``` and
+    /// ```end```. Negative when ```end``` is before ```start```.
+    pub fn fiscal_quarters_between(&self, start: &NaiveDate, end: &NaiveDate) -> i64 {
+        self.period_index(end).div_euclid(3) - self.period_index(start).div_euclid(3)
+    }
+}
+
+impl Default for FiscalCalendar {
+    fn default() -> FiscalCalendar {
+        FiscalCalendar::CALENDAR
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDate};
+
+    use crate::{diff_in, FiscalCalendar, Unit};
+
+    #[test]
+    fn fiscal() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(FiscalCalendar::new(2, 29), None);
+        assert_eq!(FiscalCalendar::new(13, 1), None);
+        assert_eq!(FiscalCalendar::new(4, 0), None);
+
+        // the calendar year behaves like diff_in
+        let calendar = FiscalCalendar::default();
+        let start = date(2019, 12, 20);
+        for offset in (0..800).step_by(5) {
+            let end = start + Duration::days(offset);
+            assert_eq!(
+                calendar.fiscal_years_between(&start, &end),
+                diff_in(&start, &end, Unit::Year)
+            );
+            assert_eq!(
+                calendar.fiscal_quarters_between(&end, &start),
+                diff_in(&end, &start, Unit::Quarter)
+            );
+        }
+
+        let october = FiscalCalendar::new(10, 1).unwrap();
+        assert_eq!(october.fiscal_year_of(&date(2023, 10, 1)), 2023);
+        assert_eq!(october.fiscal_year_of(&date(2023, 9, 30)), 2022);
+        assert_eq!(october.fiscal_quarter_of(&date(2023, 12, 31)), 1);
+        assert_eq!(october.fiscal_quarter_of(&date(2024, 1, 1)), 2);
+        assert_eq!(october.fiscal_quarter_of(&date(2024, 9, 30)), 4);
+        assert_eq!(
+            october.fiscal_years_between(&date(2023, 1, 1), &date(2024, 12, 31)),
+            2
+        );
+        assert_eq!(
+            october.fiscal_quarters_between(&date(2023, 12, 31), &date(2023, 10, 1)),
+            0
+        );
+
+        // starting in the middle of a month
+        let march21 = FiscalCalendar::new(3, 21).unwrap();
+        assert_eq!(march21.fiscal_year_of(&date(2021, 3, 20)), 2020);
+        assert_eq!(march21.fiscal_year_of(&date(2021, 3, 21)), 2021);
+        assert_eq!(march21.fiscal_quarter_of(&date(2021, 6, 20)), 1);
+        assert_eq!(march21.fiscal_quarter_of(&date(2021, 6, 21)), 2);
+        assert_eq!(
+            march21.fiscal_quarters_between(&date(2021, 6, 21), &date(2021, 3, 20)),
+            -2
+        );
+    }
+}
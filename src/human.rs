@@ -0,0 +1,445 @@
+use crate::{plural, DateTimeInterval, DisplayOptions, Interval, MonthLengthAssumption, SignStyle};
+
+/// Joins ```(value, unit)``` pairs in the ```humantime``` duration grammar,
+/// skipping zeros. ```0s``` when all are zero, like ```humantime``` itself.
+fn humantime_parts(parts: &[(u32, &str, &str)]) -> String {
+    let words: Vec<_> = parts
+        .iter()
+        .filter(|&&(value, _, _)| value != 0)
+        .map(|&(value, one, many)| format!("{}{}", value, if value == 1 { one } else { many }))
+        .collect();
+    if words.is_empty() {
+        return "0s".to_string();
+    }
+    words.join(" ")
+}
+
+impl Interval {
+    /// Formats the interval in the duration grammar of the ```humantime```
+    /// crate, e.g. ```2years 3months 4days```, so it can be given to CLIs and
+    /// config parsers that read humantime strings. ```None``` when negative,
+    /// which humantime can't express.
+    ///
+    /// humantime reads a month as 30.44 days and a year as 365.25 days, so
+    /// the parsed ```std::time::Duration``` only approximates the calendar
+    /// interval.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::Interval;
+    ///
+    /// let interval = Interval::new(2, 3, 1, true).unwrap();
+    ///
+    /// println!("--retention {}", interval.to_humantime().unwrap());
+    /// # assert_eq!(interval.to_humantime().unwrap(), "2years 3months 1day");
+    /// # assert_eq!(Interval::zero().to_humantime().unwrap(), "0s");
+    /// # assert_eq!(interval.inverse().to_humantime(), None);
+    /// ```
+    pub fn to_humantime(&self) -> Option<String> {
+        if !self.positive {
+            return None;
+        }
+        Some(humantime_parts(&[
+            (self.years, "year", "years"),
+            (self.months, "month", "months"),
+            (self.days, "day", "days"),
+        ]))
+    }
+}
+
+impl DateTimeInterval {
+    /// Same as ```Interval::to_humantime``` including the time of day, e.g.
+    /// ```1day 2h 30m 15s 250ms```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::get_diff_datetime;
+    ///
+    /// let date = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+    /// let start = date.and_hms_opt(8, 0, 0).unwrap();
+    /// let end = date.and_hms_milli_opt(9, 30, 15, 250).unwrap();
+    ///
+    /// # assert_eq!(get_diff_datetime(&start, &end).to_humantime().unwrap(), "1h 30m 15s 250ms");
+    /// ```
+    pub fn to_humantime(&self) -> Option<String> {
+        if !self.positive() {
+            return None;
+        }
+        let nanos = self.nanoseconds();
+        Some(humantime_parts(&[
+            (self.years(), "year", "years"),
+            (self.months(), "month", "months"),
+            (self.days(), "day", "days"),
+            (self.hours(), "h", "h"),
+            (self.minutes(), "m", "m"),
+            (self.seconds(), "s", "s"),
+            (nanos / 1_000_000, "ms", "ms"),
+            (nanos / 1_000 % 1_000, "us", "us"),
+            (nanos % 1_000, "ns", "ns"),
+        ]))
+    }
+}
+
+/// Largest unit ```Interval::humanize``` may use, so with
+/// ```Granularity::Months``` 2 years is "in 24 months".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Granularity {
+    Days,
+    Weeks,
+    Months,
+    #[default]
+    Years,
+}
+
+/// Where ```Interval::humanize_with``` switches units.
+///
+/// # Example
+///
+/// ```
+/// use datediff::{Granularity, HumanizeThresholds, Interval};
+///
+/// let thresholds = HumanizeThresholds::new().with_weeks(14).with_years(18);
+///
+/// # assert_eq!(Interval::of_days(10).humanize_with(Granularity::Years, &thresholds), "in 10 days");
+/// # assert_eq!(Interval::of_months(15).humanize_with(Granularity::Years, &thresholds), "in 15 months");
+/// # assert_eq!(Interval::of_months(15).humanize(Granularity::Years), "in 1 year");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct HumanizeThresholds {
+    just_now: u32,
+    weeks: u32,
+    years: u32,
+}
+
+impl Default for HumanizeThresholds {
+    fn default() -> HumanizeThresholds {
+        HumanizeThresholds {
+            just_now: 1,
+            weeks: 7,
+            years: 12,
+        }
+    }
+}
+
+impl HumanizeThresholds {
+    pub fn new() -> HumanizeThresholds {
+        HumanizeThresholds::default()
+    }
+
+    /// Intervals without months shorter than this many days are "just now",
+    /// 1 by default so only zero is.
+    pub fn with_just_now(mut self, days: u32) -> Self {
+        self.just_now = days;
+        self
+    }
+    /// Days from which weeks are used, 7 by default. Less than 7 is taken
+    /// as 7, which would give 0 weeks.
+    pub fn with_weeks(mut self, days: u32) -> Self {
+        self.weeks = days;
+        self
+    }
+    /// Months from which years are used, 12 by default. Less than 12 is
+    /// taken as 12.
+    pub fn with_years(mut self, months: u32) -> Self {
+        self.years = months;
+        self
+    }
+
+    pub fn just_now(&self) -> u32 {
+        self.just_now
+    }
+    pub fn weeks(&self) -> u32 {
+        self.weeks
+    }
+    pub fn years(&self) -> u32 {
+        self.years
+    }
+}
+
+impl Interval {
+    /// Describes the interval relative to now, e.g. "in 2 years" when
+    /// positive, "3 months ago" when negative and "just now" when zero, so
+    /// it's meant for ```get_diff(&today, &date)```. Only the largest unit
+    /// up to ```granularity``` is given, what's below it is dropped. Days are
+    /// counted from months with ```MonthLengthAssumption::AverageYear``` when
+    /// ```granularity``` is below months, and months from days the same way
+    /// otherwise, so 400 days is "in 1 year".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::{get_diff, Granularity, Interval};
+    ///
+    /// let today = NaiveDate::from_ymd_opt(2021, 3, 1).unwrap();
+    /// let posted = NaiveDate::from_ymd_opt(2020, 11, 20).unwrap();
+    ///
+    /// println!("posted {}", get_diff(&today, &posted).humanize(Granularity::Years));
+    /// # assert_eq!(get_diff(&today, &posted).humanize(Granularity::Years), "3 months ago");
+    /// # assert_eq!(get_diff(&today, &posted).humanize(Granularity::Weeks), "14 weeks ago");
+    /// # assert_eq!(Interval::of_years(2).humanize(Granularity::Years), "in 2 years");
+    /// # assert_eq!(Interval::of_days(400).humanize(Granularity::Years), "in 1 year");
+    /// # assert_eq!(Interval::zero().humanize(Granularity::Years), "just now");
+    /// ```
+    pub fn humanize(&self, granularity: Granularity) -> String {
+        self.humanize_with(granularity, &HumanizeThresholds::default())
+    }
+
+    /// Describes the length of the interval with its two most significant
+    /// non-zero components, e.g. "2 years, 3 months" for 2 years 3 months
+    /// 17 days or "1 month, 4 days", as countdowns and account ages show
+    /// it. The rest is truncated like in ```Interval::top_units``` and the
+    /// sign is left out, a zero interval is "0 days".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::{get_diff, Interval};
+    ///
+    /// let joined = NaiveDate::from_ymd_opt(2018, 11, 3).unwrap();
+    /// let today = NaiveDate::from_ymd_opt(2021, 2, 20).unwrap();
+    ///
+    /// println!("Member for {}", get_diff(&joined, &today).humanize_brief());
+    /// # assert_eq!(get_diff(&joined, &today).humanize_brief(), "2 years, 3 months");
+    /// # assert_eq!(Interval::new(0, 1, 4, false).unwrap().humanize_brief(), "1 month, 4 days");
+    /// ```
+    pub fn humanize_brief(&self) -> String {
+        let options = DisplayOptions::new()
+            .with_hide_zero(true)
+            .with_separator(", ")
+            .with_sign_style(SignStyle::None);
+        self.top_units(2).display_with(&options).to_string()
+    }
+
+    /// Rounds the length of the interval to a single unit and says how close
+    /// it is, e.g. "about 2 years" up to 2 years 3 months, "over 2 years"
+    /// up to 2 years 9 months and "almost 3 years" from then on. Months are
+    /// qualified by the days left the same way, with "about" under a week
+    /// and "almost" from day 23, and intervals under a month are whole days.
+    /// A whole number of units has no qualifier and the sign is left out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::Interval;
+    ///
+    /// let interval = Interval::new(2, 10, 3, false).unwrap();
+    ///
+    /// println!("Last login {} ago", interval.humanize_approx());
+    /// # assert_eq!(interval.humanize_approx(), "almost 3 years");
+    /// # assert_eq!(Interval::new(0, 4, 9, true).unwrap().humanize_approx(), "over 4 months");
+    /// # assert_eq!(Interval::of_years(1).humanize_approx(), "1 year");
+    /// ```
+    pub fn humanize_approx(&self) -> String {
+        let months = self.total_months().unsigned_abs();
+        let (qualifier, value, unit) = if months >= 12 {
+            match months % 12 {
+                0 if self.days == 0 => ("", months / 12, "year"),
+                0..=2 => ("about ", months / 12, "year"),
+                3..=8 => ("over ", months / 12, "year"),
+                _ => ("almost ", months / 12 + 1, "year"),
+            }
+        } else if months > 0 {
+            match self.days {
+                0 => ("", months, "month"),
+                1..=6 => ("about ", months, "month"),
+                7..=22 => ("over ", months, "month"),
+                _ if months == 11 => ("almost ", 1, "year"),
+                _ => ("almost ", months + 1, "month"),
+            }
+        } else {
+            ("", u64::from(self.days), "day")
+        };
+        format!("{}{} {}{}", qualifier, value, unit, plural(value as i64))
+    }
+
+    /// Same as ```Interval::humanize``` with other thresholds.
+    pub fn humanize_with(
+        &self,
+        granularity: Granularity,
+        thresholds: &HumanizeThresholds,
+    ) -> String {
+        let mut months = self.total_months().unsigned_abs();
+        if granularity >= Granularity::Months {
+            //as many months of 365.25 / 12 days as fit
+            months += u64::from(self.days) * 48 / 1461;
+        }
+        let days = if granularity >= Granularity::Months {
+            u64::from(self.days)
+        } else {
+            self.to_duration_approx(MonthLengthAssumption::AverageYear)
+                .num_days()
+                .unsigned_abs()
+        };
+        let (value, unit) = if granularity >= Granularity::Years
+            && months >= u64::from(thresholds.years.max(12))
+        {
+            (months / 12, "year")
+        } else if granularity >= Granularity::Months && months > 0 {
+            (months, "month")
+        } else if days < u64::from(thresholds.just_now) {
+            return "just now".to_string();
+        } else if granularity >= Granularity::Weeks && days >= u64::from(thresholds.weeks.max(7)) {
+            (days / 7, "week")
+        } else {
+            (days, "day")
+        };
+        let amount = format!("{} {}{}", value, unit, plural(value as i64));
+        if self.positive {
+            format!("in {}", amount)
+        } else {
+            format!("{} ago", amount)
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use crate::{get_diff_datetime, Granularity, HumanizeThresholds, Interval};
+
+    #[test]
+    fn humantime() {
+        let interval = |y, m, d| Interval::new(y, m, d, true).unwrap();
+        for &(i, expected) in &[
+            (interval(2, 3, 4), "2years 3months 4days"),
+            (interval(1, 1, 1), "1year 1month 1day"),
+            (interval(0, 6, 0), "6months"),
+            (interval(10, 0, 30), "10years 30days"),
+            (Interval::zero(), "0s"),
+        ] {
+            assert_eq!(i.to_humantime().unwrap(), expected, "{}", i);
+        }
+        assert_eq!(Interval::new(0, 0, 1, false).unwrap().to_humantime(), None);
+
+        let date = |d| NaiveDate::from_ymd_opt(2021, 1, d).unwrap();
+        let start = date(1).and_hms_opt(0, 0, 0).unwrap();
+        for &(end, expected) in &[
+            (
+                date(2).and_hms_nano_opt(1, 2, 3, 4_005_006).unwrap(),
+                "1day 1h 2m 3s 4ms 5us 6ns",
+            ),
+            (date(1).and_hms_opt(0, 0, 0).unwrap(), "0s"),
+            (date(1).and_hms_micro_opt(0, 0, 0, 1).unwrap(), "1us"),
+        ] {
+            assert_eq!(
+                get_diff_datetime(&start, &end).to_humantime().unwrap(),
+                expected,
+                "{}",
+                end
+            );
+            assert_eq!(
+                get_diff_datetime(&end, &start).to_humantime().is_some(),
+                end == start
+            );
+        }
+    }
+
+    #[test]
+    fn humanize_brief() {
+        let interval = |y, m, d| Interval::new(y, m, d, true).unwrap();
+        for &(i, expected) in &[
+            (interval(2, 3, 17), "2 years, 3 months"),
+            (interval(2, 0, 17), "2 years, 17 days"),
+            (interval(1, 1, 1), "1 year, 1 month"),
+            (interval(0, 11, 30), "11 months, 30 days"),
+            (interval(0, 0, 1), "1 day"),
+            (interval(5, 0, 0).inverse(), "5 years"),
+            (Interval::zero(), "0 days"),
+        ] {
+            assert_eq!(i.humanize_brief(), expected, "{}", i);
+        }
+    }
+
+    #[test]
+    fn humanize_approx() {
+        let interval = |y, m, d| Interval::new(y, m, d, true).unwrap();
+        for &(i, expected) in &[
+            (interval(2, 0, 0), "2 years"),
+            (interval(2, 0, 1), "about 2 years"),
+            (interval(2, 2, 30), "about 2 years"),
+            (interval(2, 3, 0), "over 2 years"),
+            (interval(2, 8, 30), "over 2 years"),
+            (interval(2, 9, 0), "almost 3 years"),
+            (interval(0, 11, 0), "11 months"),
+            (interval(0, 11, 23), "almost 1 year"),
+            (interval(0, 1, 6), "about 1 month"),
+            (interval(0, 1, 7), "over 1 month"),
+            (interval(0, 1, 23), "almost 2 months"),
+            (interval(0, 0, 30), "30 days"),
+            (interval(0, 0, 1).inverse(), "1 day"),
+            (Interval::zero(), "0 days"),
+        ] {
+            assert_eq!(i.humanize_approx(), expected, "{}", i);
+        }
+    }
+
+    #[test]
+    fn humanize() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+        for &(i, granularity, expected) in &[
+            (interval(2, 11, 20, true), Granularity::Years, "in 2 years"),
+            (
+                interval(2, 11, 20, false),
+                Granularity::Months,
+                "35 months ago",
+            ),
+            (interval(0, 1, 0, true), Granularity::Years, "in 1 month"),
+            (interval(0, 1, 0, true), Granularity::Weeks, "in 4 weeks"),
+            (interval(0, 1, 0, true), Granularity::Days, "in 30 days"),
+            (interval(0, 0, 13, false), Granularity::Years, "1 week ago"),
+            (interval(0, 0, 6, false), Granularity::Years, "6 days ago"),
+            (interval(0, 0, 1, true), Granularity::Years, "in 1 day"),
+            (interval(1, 0, 0, false), Granularity::Days, "365 days ago"),
+            (Interval::zero(), Granularity::Days, "just now"),
+            (Interval::of_days(400), Granularity::Years, "in 1 year"),
+            (Interval::of_days(400), Granularity::Months, "in 13 months"),
+            (Interval::of_days(400), Granularity::Weeks, "in 57 weeks"),
+            (Interval::of_days(30), Granularity::Years, "in 4 weeks"),
+            (Interval::of_days(31), Granularity::Years, "in 1 month"),
+            (
+                interval(0, 11, 30, false),
+                Granularity::Years,
+                "11 months ago",
+            ),
+            (
+                Interval::of_days(800).inverse(),
+                Granularity::Years,
+                "2 years ago",
+            ),
+        ] {
+            assert_eq!(i.humanize(granularity), expected, "{} {:?}", i, granularity);
+        }
+
+        let thresholds = HumanizeThresholds::new()
+            .with_just_now(2)
+            .with_weeks(14)
+            .with_years(18);
+        for &(i, expected) in &[
+            (interval(0, 0, 1, false), "just now"),
+            (interval(0, 0, 2, false), "2 days ago"),
+            (interval(0, 0, 13, true), "in 13 days"),
+            (interval(0, 0, 14, true), "in 2 weeks"),
+            (interval(1, 5, 0, true), "in 17 months"),
+            (interval(1, 6, 0, true), "in 1 year"),
+        ] {
+            assert_eq!(
+                i.humanize_with(Granularity::Years, &thresholds),
+                expected,
+                "{}",
+                i
+            );
+        }
+    }
+}
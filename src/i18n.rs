@@ -0,0 +1,331 @@
+use std::{error, fmt};
+
+use icu_locale_core::{extensions::unicode::key, Locale};
+use icu_plurals::{PluralCategory, PluralRules};
+
+use crate::Interval;
+
+/// Error returned when ```Interval::format_locale``` has no messages for a
+/// locale, or it isn't a valid locale identifier.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedLocaleError(String);
+
+impl UnsupportedLocaleError {
+    /// The locale that was given.
+    pub fn locale(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for UnsupportedLocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsupported locale: {:?}", self.0)
+    }
+}
+
+impl error::Error for UnsupportedLocaleError {}
+
+/// Word for a unit in each CLDR plural category a language uses, the
+/// categories it doesn't use are never asked for. Zero, only used by
+/// Arabic and Latvian among others, takes the word for other. With
+/// ```dual``` the word for two already means two of the unit, like Arabic
+/// سنتان, and is written without the number.
+struct Forms {
+    one: &'static str,
+    two: &'static str,
+    few: &'static str,
+    many: &'static str,
+    other: &'static str,
+    dual: bool,
+}
+
+impl Forms {
+    /// Same word for every category but one, the language only has one and
+    /// other.
+    const fn simple(one: &'static str, other: &'static str) -> Forms {
+        Forms {
+            one,
+            two: other,
+            few: other,
+            many: other,
+            other,
+            dual: false,
+        }
+    }
+
+    /// Words for a language with one, few, many and other.
+    const fn slavic(
+        one: &'static str,
+        few: &'static str,
+        many: &'static str,
+        other: &'static str,
+    ) -> Forms {
+        Forms {
+            one,
+            two: other,
+            few,
+            many,
+            other,
+            dual: false,
+        }
+    }
+
+    /// Sets a dual noun for two, see ```Forms```.
+    const fn with_dual(self, two: &'static str) -> Forms {
+        Forms {
+            two,
+            dual: true,
+            ..self
+        }
+    }
+
+    fn get(&self, category: PluralCategory) -> &'static str {
+        match category {
+            PluralCategory::One => self.one,
+            PluralCategory::Two => self.two,
+            PluralCategory::Few => self.few,
+            PluralCategory::Many => self.many,
+            _ => self.other,
+        }
+    }
+
+    /// ```number```, already in the locale's digits, with its word.
+    fn format(&self, number: &str, category: PluralCategory) -> String {
+        match category {
+            PluralCategory::Two if self.dual => self.two.to_string(),
+            _ => format!("{} {}", number, self.get(category)),
+        }
+    }
+}
+
+/// Years, months and days in ```language```, an ISO 639 code.
+fn messages(language: &str) -> Option<[Forms; 3]> {
+    Some(match language {
+        "en" => [
+            Forms::simple("year", "years"),
+            Forms::simple("month", "months"),
+            Forms::simple("day", "days"),
+        ],
+        "de" => [
+            Forms::simple("Jahr", "Jahre"),
+            Forms::simple("Monat", "Monate"),
+            Forms::simple("Tag", "Tage"),
+        ],
+        "fr" => [
+            Forms::simple("an", "ans"),
+            Forms::simple("mois", "mois"),
+            Forms::simple("jour", "jours"),
+        ],
+        "es" => [
+            Forms::simple("año", "años"),
+            Forms::simple("mes", "meses"),
+            Forms::simple("día", "días"),
+        ],
+        "hi" => [
+            Forms::simple("वर्ष", "वर्ष"),
+            Forms::simple("महीना", "महीने"),
+            Forms::simple("दिन", "दिन"),
+        ],
+        "fa" => [
+            Forms::simple("سال", "سال"),
+            Forms::simple("ماه", "ماه"),
+            Forms::simple("روز", "روز"),
+        ],
+        "pl" => [
+            Forms::slavic("rok", "lata", "lat", "roku"),
+            Forms::slavic("miesiąc", "miesiące", "miesięcy", "miesiąca"),
+            Forms::slavic("dzień", "dni", "dni", "dnia"),
+        ],
+        "ru" => [
+            Forms::slavic("год", "года", "лет", "года"),
+            Forms::slavic("месяц", "месяца", "месяцев", "месяца"),
+            Forms::slavic("день", "дня", "дней", "дня"),
+        ],
+        "cs" => [
+            Forms::slavic("rok", "roky", "roku", "let"),
+            Forms::slavic("měsíc", "měsíce", "měsíce", "měsíců"),
+            Forms::slavic("den", "dny", "dne", "dní"),
+        ],
+        "ar" => [
+            Forms::slavic("سنة", "سنوات", "سنة", "سنة").with_dual("سنتان"),
+            Forms::slavic("شهر", "أشهر", "شهرًا", "شهر").with_dual("شهران"),
+            Forms::slavic("يوم", "أيام", "يومًا", "يوم").with_dual("يومان"),
+        ],
+        "he" => [
+            Forms::slavic("שנה", "שנים", "שנים", "שנים").with_dual("שנתיים"),
+            Forms::slavic("חודש", "חודשים", "חודשים", "חודשים").with_dual("חודשיים"),
+            Forms::slavic("יום", "ימים", "ימים", "ימים").with_dual("יומיים"),
+        ],
+        _ => return None,
+    })
+}
+
+/// Languages written right to left among those with messages.
+const RTL_LANGUAGES: [&str; 3] = ["ar", "fa", "he"];
+
+/// Zero digit of a CLDR numbering system, the other nine follow it.
+fn zero_digit(numbering_system: &str) -> Option<char> {
+    Some(match numbering_system {
+        "latn" => '0',
+        "arab" => '\u{0660}',
+        "arabext" => '\u{06F0}',
+        "deva" => '\u{0966}',
+        "beng" => '\u{09E6}',
+        "thai" => '\u{0E50}',
+        _ => return None,
+    })
+}
+
+/// Writes ```value``` with the digits starting at ```zero```.
+fn digits(value: u32, zero: char) -> String {
+    value
+        .to_string()
+        .chars()
+        .map(|digit| std::char::from_u32(zero as u32 + digit.to_digit(10).unwrap()).unwrap())
+        .collect()
+}
+
+impl Interval {
+    /// Formats the non-zero components in the language of ```locale```, a
+    /// BCP 47 identifier like ```"pl"``` or ```"de-AT"```, picking each word
+    /// with the CLDR plural rules. A negative interval starts with ```-```,
+    /// a zero one is 0 days.
+    ///
+    /// Numbers use the locale's numbering system, Arabic-Indic digits for
+    /// Arabic and Persian, or the one asked for with a ```-u-nu-``` extension
+    /// like ```"hi-u-nu-deva"``` among ```latn```, ```arab```, ```arabext```,
+    /// ```deva```, ```beng``` and ```thai```. Arabic, Persian and Hebrew text
+    /// starts with a right-to-left mark, U+200F, so it's laid out right to
+    /// left with the minus sign on the right even within left-to-right text.
+    ///
+    /// Messages exist for English, German, French, Spanish, Polish, Russian,
+    /// Czech, Hindi, Arabic, Persian and Hebrew, other locales and numbering
+    /// systems give an ```UnsupportedLocaleError```.
+    ///
+    /// Needs the ```i18n``` feature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[cfg(feature = "i18n")]
+    /// # {
+    /// use datediff::Interval;
+    ///
+    /// let interval = Interval::new(2, 5, 22, true).unwrap();
+    ///
+    /// println!("{}", interval.format_locale("pl").unwrap());
+    /// # assert_eq!(interval.format_locale("pl").unwrap(), "2 lata 5 miesięcy 22 dni");
+    /// # assert_eq!(interval.format_locale("en-GB").unwrap(), "2 years 5 months 22 days");
+    /// # assert_eq!(interval.format_locale("hi-u-nu-deva").unwrap(), "२ वर्ष ५ महीने २२ दिन");
+    /// # assert_eq!(interval.format_locale("ar").unwrap(), "\u{200F}سنتان ٥ أشهر ٢٢ يومًا");
+    /// # assert!(interval.format_locale("tlh").is_err());
+    /// # }
+    /// ```
+    pub fn format_locale(&self, locale: &str) -> Result<String, UnsupportedLocaleError> {
+        let unsupported = || UnsupportedLocaleError(locale.to_string());
+        let parsed = Locale::try_from_str(locale).map_err(|_| unsupported())?;
+        let language = parsed.id.language.as_str();
+        let words = messages(language).ok_or_else(unsupported)?;
+        let rules = PluralRules::try_new_cardinal((&parsed).into()).map_err(|_| unsupported())?;
+        let numbering_system = match parsed.extensions.unicode.keywords.get(&key!("nu")) {
+            Some(value) => value.to_string(),
+            None => match language {
+                "ar" => "arab",
+                "fa" => "arabext",
+                _ => "latn",
+            }
+            .to_string(),
+        };
+        let zero = zero_digit(&numbering_system).ok_or_else(unsupported)?;
+
+        let mut parts: Vec<_> = [self.years, self.months, self.days]
+            .iter()
+            .zip(words.iter())
+            .filter(|&(&value, _)| value != 0)
+            .map(|(&value, forms)| forms.format(&digits(value, zero), rules.category_for(value)))
+            .collect();
+        if parts.is_empty() {
+            parts.push(words[2].format(&digits(0, zero), rules.category_for(0u32)));
+        }
+        let text = parts.join(" ");
+        match (self.positive, RTL_LANGUAGES.contains(&language)) {
+            (true, false) => Ok(text),
+            (false, false) => Ok(format!("-{}", text)),
+            //a right-to-left mark in front, so the text and its minus sign
+            //start on the right also within left-to-right text
+            (true, true) => Ok(format!("\u{200F}{}", text)),
+            (false, true) => Ok(format!("\u{200F}-{}", text)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Interval;
+
+    #[test]
+    fn format_locale() {
+        let interval = |y, m, d| Interval::new(y, m, d, true).unwrap();
+        for &(i, locale, expected) in &[
+            (interval(1, 2, 5), "pl", "1 rok 2 miesiące 5 dni"),
+            (interval(22, 11, 1), "pl", "22 lata 11 miesięcy 1 dzień"),
+            (interval(12, 0, 0), "pl", "12 lat"),
+            (interval(21, 1, 3), "ru", "21 год 1 месяц 3 дня"),
+            (interval(5, 0, 11), "ru-RU", "5 лет 11 дней"),
+            (interval(2, 0, 5), "cs", "2 roky 5 dní"),
+            (interval(1, 1, 1), "de", "1 Jahr 1 Monat 1 Tag"),
+            (interval(0, 3, 2), "es-MX", "3 meses 2 días"),
+            (interval(1, 1, 0), "fr", "1 an 1 mois"),
+            (Interval::zero(), "fr", "0 jour"),
+            (Interval::zero(), "en", "0 days"),
+        ] {
+            assert_eq!(
+                i.format_locale(locale).unwrap(),
+                expected,
+                "{} {}",
+                i,
+                locale
+            );
+        }
+        assert_eq!(
+            interval(0, 0, 3).inverse().format_locale("pl").unwrap(),
+            "-3 dni"
+        );
+
+        for &locale in &["tlh", "ja", "", "not a locale"] {
+            let error = Interval::zero().format_locale(locale).unwrap_err();
+            assert_eq!(error.locale(), locale);
+        }
+    }
+
+    #[test]
+    fn numbering_systems() {
+        let interval = |y, m, d| Interval::new(y, m, d, true).unwrap();
+        for &(i, locale, expected) in &[
+            (interval(2, 5, 10), "ar", "\u{200F}سنتان ٥ أشهر ١٠ أيام"),
+            (interval(11, 1, 0), "ar-u-nu-latn", "\u{200F}11 سنة 1 شهر"),
+            (Interval::zero(), "ar", "\u{200F}٠ يوم"),
+            (interval(0, 2, 2), "ar-u-nu-latn", "\u{200F}شهران يومان"),
+            (interval(2, 2, 2), "he", "\u{200F}שנתיים חודשיים יומיים"),
+            (interval(0, 3, 12), "fa", "\u{200F}۳ ماه ۱۲ روز"),
+            (interval(2, 0, 1), "he", "\u{200F}שנתיים 1 יום"),
+            (interval(1, 2, 0), "hi", "1 वर्ष 2 महीने"),
+            (interval(1, 2, 30), "hi-u-nu-deva", "१ वर्ष २ महीने ३० दिन"),
+            (interval(0, 0, 5), "en-u-nu-thai", "๕ days"),
+            (interval(0, 0, 5), "en-u-nu-beng", "৫ days"),
+        ] {
+            assert_eq!(
+                i.format_locale(locale).unwrap(),
+                expected,
+                "{} {}",
+                i,
+                locale
+            );
+        }
+        assert_eq!(
+            interval(0, 1, 3).inverse().format_locale("ar").unwrap(),
+            "\u{200F}-١ شهر ٣ أيام"
+        );
+        assert!(Interval::zero().format_locale("en-u-nu-klingon").is_err());
+    }
+}
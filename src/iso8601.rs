@@ -0,0 +1,512 @@
+use chrono::NaiveDate;
+
+use crate::{get_diff, EomPolicy, Interval, ParseIntervalError};
+
+/// Components of an ISO 8601 duration like ```-P1Y2M3W4DT5H6M7S```, each
+/// given at most once and in this order.
+#[derive(Debug, Default)]
+pub(crate) struct Designated {
+    pub(crate) positive: bool,
+    pub(crate) years: u32,
+    pub(crate) months: u32,
+    pub(crate) weeks: u32,
+    pub(crate) days: u32,
+    pub(crate) hours: u32,
+    pub(crate) minutes: u32,
+    pub(crate) seconds: u32,
+}
+
+impl Designated {
+    /// Drops the time of day, which must be zero, and gives the rest as an
+    /// Interval. Months over 11 carry into years, weeks are 7 days. Fails
+    /// with ```ParseIntervalError::Overflow``` when years exceed
+    /// ```Interval::MAX_YEARS``` or days an ```i32```.
+    pub(crate) fn to_interval(&self) -> Result<Interval, ParseIntervalError> {
+        for &(value, unit) in &[(self.hours, 'H'), (self.minutes, 'M'), (self.seconds, 'S')] {
+            if value != 0 {
+                return Err(ParseIntervalError::NonZeroTime(format!(
+                    "{}{}",
+                    value, unit
+                )));
+            }
+        }
+        let months = i64::from(self.years) * 12 + i64::from(self.months);
+        let days = i64::from(self.weeks) * 7 + i64::from(self.days);
+        Interval::checked_from_months_days(months, days)
+            .map(|interval| interval.with_sign(self.positive))
+            .ok_or(ParseIntervalError::Overflow)
+    }
+}
+
+/// Parses the designators of one part, date or time, of a duration.
+fn parse_part<'a>(
+    mut rest: &'a str,
+    designators: &[char],
+    slots: &mut [&mut u32],
+) -> Result<&'a str, ParseIntervalError> {
+    let mut next = 0;
+    while !rest.is_empty() && !rest.starts_with('T') {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if digits == 0 {
+            return Err(ParseIntervalError::InvalidNumber(rest.to_string()));
+        }
+        let (number, tail) = rest.split_at(digits);
+        let unit = match tail.chars().next() {
+            Some('.') | Some(',') => {
+                return Err(ParseIntervalError::InvalidNumber(rest.to_string()))
+            }
+            Some(unit) => unit,
+            None => return Err(ParseIntervalError::MissingUnit(number.to_string())),
+        };
+        let index = match designators.iter().position(|&d| d == unit) {
+            Some(index) => index,
+            None => return Err(ParseIntervalError::UnknownUnit(tail.to_string())),
+        };
+        if index < next {
+            return Err(if index + 1 == next {
+                ParseIntervalError::DuplicateUnit(unit.to_ascii_lowercase())
+            } else {
+                ParseIntervalError::InvalidFormat(rest.to_string())
+            });
+        }
+        *slots[index] = number.parse().map_err(|_| ParseIntervalError::Overflow)?;
+        next = index + 1;
+        rest = &tail[1..];
+    }
+    Ok(rest)
+}
+
+/// Parses ```[+-]P[nY][nM][nW][nD][T[nH][nM][nS]]``` with whole numbers,
+/// at least one component and no time designator without time components.
+pub(crate) fn parse_designated(s: &str) -> Result<Designated, ParseIntervalError> {
+    let s = s.trim();
+    let (positive, rest) = match s.strip_prefix('-') {
+        Some(rest) => (false, rest),
+        None => (true, s.strip_prefix('+').unwrap_or(s)),
+    };
+    if rest.is_empty() {
+        return Err(ParseIntervalError::Empty);
+    }
+    let body = match rest.strip_prefix('P') {
+        Some(body) if !body.is_empty() => body,
+        _ => return Err(ParseIntervalError::InvalidFormat(s.to_string())),
+    };
+
+    let mut d = Designated {
+        positive,
+        ..Designated::default()
+    };
+    let rest = parse_part(
+        body,
+        &['Y', 'M', 'W', 'D'],
+        &mut [&mut d.years, &mut d.months, &mut d.weeks, &mut d.days],
+    )?;
+    if let Some(time) = rest.strip_prefix('T') {
+        if time.is_empty() {
+            return Err(ParseIntervalError::InvalidFormat(s.to_string()));
+        }
+        let rest = parse_part(
+            time,
+            &['H', 'M', 'S'],
+            &mut [&mut d.hours, &mut d.minutes, &mut d.seconds],
+        )?;
+        if !rest.is_empty() {
+            return Err(ParseIntervalError::InvalidFormat(rest.to_string()));
+        }
+    }
+    Ok(d)
+}
+
+impl Interval {
+    /// Formats the interval as an ISO 8601 duration, e.g. ```P2Y3M4D```,
+    /// ```-P5D``` when negative and ```P0D``` when zero. Zero components are
+    /// left out.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::{get_diff, Interval};
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2022, 4, 5).unwrap();
+    ///
+    /// println!("{}", get_diff(&start, &end).to_iso8601());
+    /// # assert_eq!(get_diff(&start, &end).to_iso8601(), "P2Y3M4D");
+    /// # assert_eq!(get_diff(&end, &start).to_iso8601(), "-P2Y3M4D");
+    /// # assert_eq!(Interval::zero().to_iso8601(), "P0D");
+    /// ```
+    pub fn to_iso8601(&self) -> String {
+        let mut text = String::from(if self.positive { "P" } else { "-P" });
+        for &(value, unit) in &[(self.years, 'Y'), (self.months, 'M'), (self.days, 'D')] {
+            if value != 0 {
+                text.push_str(&value.to_string());
+                text.push(unit);
+            }
+        }
+        if self.is_zero() {
+            text.push_str("0D");
+        }
+        text
+    }
+
+    /// Parses an ISO 8601 duration such as ```P2Y3M4D```, ```-P5D``` or
+    /// ```P2W```. Components must be whole numbers in the standard order.
+    /// Weeks count as 7 days and months over 11 carry into years, days are
+    /// kept as given. Time components like ```PT0S``` are accepted only
+    /// when zero, since an Interval has no time of day.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::{Interval, ParseIntervalError};
+    ///
+    /// # assert_eq!(Interval::from_iso8601("P2Y3M4D"), Ok(Interval::new(2, 3, 4, true).unwrap()));
+    /// # assert_eq!(Interval::from_iso8601("-P18M"), Ok(Interval::new(1, 6, 0, false).unwrap()));
+    /// # assert_eq!(Interval::from_iso8601("P1W2D").map(|i| i.days()), Ok(9));
+    /// # assert_eq!(
+    /// #     Interval::from_iso8601("PT5H"),
+    /// #     Err(ParseIntervalError::NonZeroTime("5H".to_string()))
+    /// # );
+    /// ```
+    pub fn from_iso8601(s: &str) -> Result<Interval, ParseIntervalError> {
+        parse_designated(s)?.to_interval()
+    }
+
+    /// Formats the interval as an iCalendar DURATION value (RFC 5545), e.g.
+    /// ```P15D```, ```-P2W``` or ```P0D```. These only have weeks and days,
+    /// so this gives ```None``` for an interval with years or months, see
+    /// ```Interval::to_ical_duration_at```. Whole weeks use the week form.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::Interval;
+    ///
+    /// # assert_eq!(Interval::of_days(15).to_ical_duration(), Some("P15D".to_string()));
+    /// # assert_eq!(Interval::of_days(14).inverse().to_ical_duration(), Some("-P2W".to_string()));
+    /// # assert_eq!(Interval::of_months(1).to_ical_duration(), None);
+    /// ```
+    pub fn to_ical_duration(&self) -> Option<String> {
+        if self.years != 0 || self.months != 0 {
+            return None;
+        }
+        let sign = if self.positive { "" } else { "-" };
+        Some(match (self.days / 7, self.days % 7) {
+            (weeks, 0) if weeks != 0 => format!("{}P{}W", sign, weeks),
+            _ => format!("{}P{}D", sign, self.days),
+        })
+    }
+
+    /// Formats the interval as an iCalendar DURATION value like
+    /// ```Interval::to_ical_duration```, turning years and months into days
+    /// as they fall from ```anchor```, e.g. the start of the event.
+    pub fn to_ical_duration_at(&self, anchor: NaiveDate) -> String {
+        let days = self.total_days(anchor);
+        Interval::from_months_days(0, days)
+            .to_ical_duration()
+            .unwrap()
+    }
+
+    /// Parses an iCalendar DURATION value (RFC 5545) such as ```P15D```,
+    /// ```-P2W``` or ```P1DT0H0M0S```, with an optional sign. Weeks count as
+    /// 7 days. Time components are accepted only when zero, since an
+    /// Interval has no time of day.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::Interval;
+    ///
+    /// # assert_eq!(Interval::from_ical_duration("P1DT0H0M0S"), Ok(Interval::of_days(1)));
+    /// # assert_eq!(Interval::from_ical_duration("-P2W"), Ok(Interval::of_days(14).inverse()));
+    /// # assert!(Interval::from_ical_duration("P1M").is_err());
+    /// ```
+    pub fn from_ical_duration(s: &str) -> Result<Interval, ParseIntervalError> {
+        let date_part = s.trim().split('T').next().unwrap_or("");
+        if let Some(index) = date_part.find(['Y', 'M']) {
+            return Err(ParseIntervalError::UnknownUnit(
+                date_part[index..].to_string(),
+            ));
+        }
+        //the week form stands alone
+        if date_part.contains('W') && (date_part.contains('D') || s.contains('T')) {
+            return Err(ParseIntervalError::InvalidFormat(s.trim().to_string()));
+        }
+        parse_designated(s)?.to_interval()
+    }
+}
+
+/// Parses a calendar date of an interval expression. ```start``` fills in
+/// the leading components an end date may leave out, like in
+/// ```2020-01-01/06-30``` or ```2020-01-01/15```.
+fn parse_date(s: &str, start: Option<&NaiveDate>) -> Result<NaiveDate, ParseIntervalError> {
+    let invalid = || ParseIntervalError::InvalidFormat(s.to_string());
+    let full = match (start, s.len()) {
+        (Some(start), 2) => format!("{}-{}", start.format("%Y-%m"), s),
+        (Some(start), 5) => format!("{}-{}", start.format("%Y"), s),
+        _ => s.to_string(),
+    };
+    //chrono also takes 2020-1-1, which isn't ISO 8601
+    match NaiveDate::parse_from_str(&full, "%Y-%m-%d") {
+        Ok(date) if date.format("%Y-%m-%d").to_string() == full => Ok(date),
+        _ => Err(invalid()),
+    }
+}
+
+/// Parses an ISO 8601 time interval of calendar dates into its start and
+/// end date, in any of the forms ```2020-01-01/2020-06-30```,
+/// ```2020-01-01/P3M``` and ```P3M/2020-06-30```. The end date may leave
+/// out the year, or year and month, which are then the start's. A duration
+/// is added to the start, or taken from the end, with
+/// ```EomPolicy::Clamp```, so ```2020-01-31/P1M``` ends on 2020-02-29.
+/// See ```Interval::from_iso8601_interval``` for the difference.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::parse_iso8601_interval;
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// # assert_eq!(parse_iso8601_interval("2020-01-01/2020-06-30"), Ok((date(2020, 1, 1), date(2020, 6, 30))));
+/// # assert_eq!(parse_iso8601_interval("2020-01-01/P3M"), Ok((date(2020, 1, 1), date(2020, 4, 1))));
+/// # assert_eq!(parse_iso8601_interval("P1Y/2020-06-30"), Ok((date(2019, 6, 30), date(2020, 6, 30))));
+/// # assert_eq!(parse_iso8601_interval("2020-01-01/06-30"), Ok((date(2020, 1, 1), date(2020, 6, 30))));
+/// ```
+pub fn parse_iso8601_interval(s: &str) -> Result<(NaiveDate, NaiveDate), ParseIntervalError> {
+    let s = s.trim();
+    let invalid = || ParseIntervalError::InvalidFormat(s.to_string());
+    let (first, second) = match s.find('/') {
+        Some(index) => (&s[..index], &s[index + 1..]),
+        None => return Err(invalid()),
+    };
+    let duration = |text: &str| match Interval::from_iso8601(text)? {
+        interval if interval.positive() => Ok(interval),
+        _ => Err(ParseIntervalError::InvalidFormat(text.to_string())),
+    };
+    match (first.starts_with('P'), second.starts_with('P')) {
+        (false, false) => {
+            let start = parse_date(first, None)?;
+            Ok((start, parse_date(second, Some(&start))?))
+        }
+        (false, true) => {
+            let start = parse_date(first, None)?;
+            let end = duration(second)?
+                .checked_apply_to(start, EomPolicy::Clamp)
+                .ok_or(ParseIntervalError::Overflow)?;
+            Ok((start, end))
+        }
+        (true, false) => {
+            let end = parse_date(second, None)?;
+            let start = duration(first)?
+                .inverse()
+                .checked_apply_to(end, EomPolicy::Clamp)
+                .ok_or(ParseIntervalError::Overflow)?;
+            Ok((start, end))
+        }
+        (true, true) => Err(invalid()),
+    }
+}
+
+impl Interval {
+    /// Parses an ISO 8601 time interval like ```2020-01-01/2020-06-30``` or
+    /// ```2020-01-01/P3M``` with ```parse_iso8601_interval``` and gives the
+    /// difference from its start to its end, like ```get_diff```. Negative
+    /// when the end is before the start.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::Interval;
+    ///
+    /// # assert_eq!(Interval::from_iso8601_interval("2020-01-15/2020-06-30"), Ok(Interval::new(0, 5, 15, true).unwrap()));
+    /// # assert_eq!(Interval::from_iso8601_interval("2020-01-31/P1M"), Ok(Interval::of_days(29)));
+    /// ```
+    pub fn from_iso8601_interval(s: &str) -> Result<Interval, ParseIntervalError> {
+        let (start, end) = parse_iso8601_interval(s)?;
+        Ok(get_diff(&start, &end))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use crate::{parse_iso8601_interval, Interval, ParseIntervalError};
+
+    #[test]
+    fn iso8601() {
+        let interval = |y, m, d, p| {
+            Interval::builder()
+                .years(y)
+                .months(m)
+                .days(d)
+                .build_unchecked()
+                .with_sign(p)
+        };
+
+        for &(text, expected) in &[
+            ("P2Y3M4D", interval(2, 3, 4, true)),
+            ("-P5D", interval(0, 0, 5, false)),
+            ("P1Y", interval(1, 0, 0, true)),
+            ("P11M", interval(0, 11, 0, true)),
+            ("P45D", interval(0, 0, 45, true)),
+            ("P0D", Interval::zero()),
+        ] {
+            assert_eq!(expected.to_iso8601(), text);
+            assert_eq!(Interval::from_iso8601(text), Ok(expected), "{}", text);
+        }
+
+        let parse = Interval::from_iso8601;
+        assert_eq!(parse(" +P2W "), Ok(interval(0, 0, 14, true)));
+        assert_eq!(parse("P25M"), Ok(interval(2, 1, 0, true)));
+        assert_eq!(parse("P1DT0H0M0S"), Ok(interval(0, 0, 1, true)));
+        assert_eq!(parse("-PT0S"), Ok(Interval::zero()));
+        assert_eq!(parse("-P0D"), Ok(Interval::zero()));
+
+        assert_eq!(parse(""), Err(ParseIntervalError::Empty));
+        assert_eq!(parse("-"), Err(ParseIntervalError::Empty));
+        for text in &["P", "2Y", "PT", "P1DT", "P1D2", "p1d"] {
+            assert!(
+                matches!(
+                    parse(text),
+                    Err(ParseIntervalError::InvalidFormat(_))
+                        | Err(ParseIntervalError::MissingUnit(_))
+                ),
+                "{}",
+                text
+            );
+        }
+        assert_eq!(
+            parse("P1D2Y"),
+            Err(ParseIntervalError::InvalidFormat("2Y".to_string()))
+        );
+        assert_eq!(parse("P1D2D"), Err(ParseIntervalError::DuplicateUnit('d')));
+        assert_eq!(
+            parse("P1.5Y"),
+            Err(ParseIntervalError::InvalidNumber("1.5Y".to_string()))
+        );
+        assert_eq!(
+            parse("P1X"),
+            Err(ParseIntervalError::UnknownUnit("X".to_string()))
+        );
+        assert_eq!(
+            parse("PT1M"),
+            Err(ParseIntervalError::NonZeroTime("1M".to_string()))
+        );
+        assert_eq!(parse("P9999999999D"), Err(ParseIntervalError::Overflow));
+        assert_eq!(parse("P700000000W"), Err(ParseIntervalError::Overflow));
+        for text in &[
+            "P4294967295Y",
+            "P2147483647Y12M",
+            "P2147483648D",
+            "P306783379W",
+        ] {
+            assert_eq!(parse(text), Err(ParseIntervalError::Overflow), "{}", text);
+        }
+        assert_eq!(
+            parse("-P2147483647Y11M2147483647D").map(|interval| interval.signed()),
+            Ok((-i32::MAX, -11, -i32::MAX))
+        );
+    }
+
+    #[test]
+    fn ical() {
+        let days = Interval::of_days;
+
+        for &(text, expected) in &[
+            ("P15D", days(15)),
+            ("-P1D", days(1).inverse()),
+            ("P2W", days(14)),
+            ("-P1W", days(7).inverse()),
+            ("P0D", Interval::zero()),
+        ] {
+            assert_eq!(expected.to_ical_duration().as_deref(), Some(text));
+            assert_eq!(Interval::from_ical_duration(text), Ok(expected), "{}", text);
+        }
+
+        let parse = Interval::from_ical_duration;
+        assert_eq!(parse("+P1DT0H0M0S"), Ok(days(1)));
+        assert_eq!(parse("PT0S"), Ok(Interval::zero()));
+        assert_eq!(parse("P7D"), Ok(days(7)));
+        assert_eq!(
+            parse("PT1H"),
+            Err(ParseIntervalError::NonZeroTime("1H".to_string()))
+        );
+        assert_eq!(
+            parse("P1Y2D"),
+            Err(ParseIntervalError::UnknownUnit("Y2D".to_string()))
+        );
+        assert_eq!(
+            parse("P1W2D"),
+            Err(ParseIntervalError::InvalidFormat("P1W2D".to_string()))
+        );
+        assert!(parse("P").is_err());
+
+        let anchor = NaiveDate::from_ymd_opt(2021, 2, 1).unwrap();
+        let interval = Interval::new(0, 1, 3, true).unwrap();
+        assert_eq!(interval.to_ical_duration(), None);
+        assert_eq!(interval.to_ical_duration_at(anchor), "P31D");
+        assert_eq!(interval.inverse().to_ical_duration_at(anchor), "-P34D");
+        assert_eq!(
+            Interval::from_ical_duration(&days(31).to_ical_duration_at(anchor))
+                .map(|i| i.shift(anchor)),
+            Ok(interval.shift(anchor))
+        );
+    }
+
+    #[test]
+    fn iso8601_interval() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        for &(text, start, end) in &[
+            ("2020-01-01/2020-06-30", date(2020, 1, 1), date(2020, 6, 30)),
+            ("2020-06-30/2020-01-01", date(2020, 6, 30), date(2020, 1, 1)),
+            (" 2020-01-01/P3M ", date(2020, 1, 1), date(2020, 4, 1)),
+            ("2020-01-31/P1M", date(2020, 1, 31), date(2020, 2, 29)),
+            ("2020-01-01/P2W", date(2020, 1, 1), date(2020, 1, 15)),
+            ("P1M/2020-03-31", date(2020, 2, 29), date(2020, 3, 31)),
+            ("2020-01-01/06-30", date(2020, 1, 1), date(2020, 6, 30)),
+            ("2020-01-01/15", date(2020, 1, 1), date(2020, 1, 15)),
+            (
+                "-0044-03-15/-0044-03-16",
+                date(-44, 3, 15),
+                date(-44, 3, 16),
+            ),
+        ] {
+            assert_eq!(parse_iso8601_interval(text), Ok((start, end)), "{}", text);
+        }
+        assert_eq!(
+            Interval::from_iso8601_interval("2020-06-30/2020-01-01"),
+            Ok(Interval::new(0, 5, 29, false).unwrap())
+        );
+
+        let invalid = |text: &str| Err(ParseIntervalError::InvalidFormat(text.to_string()));
+        for (text, error) in vec![
+            ("2020-01-01", invalid("2020-01-01")),
+            ("P1M/P2M", invalid("P1M/P2M")),
+            ("2020-01-01/-P1M", invalid("-P1M")),
+            ("2020-13-01/2020-06-30", invalid("2020-13-01")),
+            ("2020-1-1/2020-06-30", invalid("2020-1-1")),
+            ("20200101/2020-06-30", invalid("20200101")),
+            ("2020-01-01/2020-06-30T00:00", invalid("2020-06-30T00:00")),
+            ("2020-01-01/6-30", invalid("6-30")),
+            ("2020-01-01/", invalid("")),
+        ] {
+            assert_eq!(parse_iso8601_interval(text), error, "{}", text);
+        }
+        assert_eq!(
+            parse_iso8601_interval("2020-01-01/PT1H"),
+            Err(ParseIntervalError::NonZeroTime("1H".to_string()))
+        );
+        assert_eq!(
+            parse_iso8601_interval("9999-01-01/P300000Y"),
+            Err(ParseIntervalError::Overflow)
+        );
+    }
+}
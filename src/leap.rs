@@ -0,0 +1,264 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Timelike};
+
+use crate::{get_diff_datetime, DateTimeInterval};
+
+/// UTC days that ended with a leap second, 23:59:60, since leap seconds
+/// were introduced in 1972. All were positive. The IERS announces them in
+/// Bulletin C about six months ahead, none has been announced after
+/// 2016-12-31 at the time of writing, so results past the next
+/// announcement may be a second short.
+const LEAP_SECOND_DAYS: [(i32, u32, u32); 27] = [
+    (1972, 6, 30),
+    (1972, 12, 31),
+    (1973, 12, 31),
+    (1974, 12, 31),
+    (1975, 12, 31),
+    (1976, 12, 31),
+    (1977, 12, 31),
+    (1978, 12, 31),
+    (1979, 12, 31),
+    (1981, 6, 30),
+    (1982, 6, 30),
+    (1983, 6, 30),
+    (1985, 6, 30),
+    (1987, 12, 31),
+    (1989, 12, 31),
+    (1990, 12, 31),
+    (1992, 6, 30),
+    (1993, 6, 30),
+    (1994, 6, 30),
+    (1995, 12, 31),
+    (1997, 6, 30),
+    (1998, 12, 31),
+    (2005, 12, 31),
+    (2008, 12, 31),
+    (2012, 6, 30),
+    (2015, 6, 30),
+    (2016, 12, 31),
+];
+
+/// UTC days that ended with a leap second, oldest first.
+///
+/// Needs the ```leap-seconds``` feature.
+pub fn leap_second_days() -> impl Iterator<Item = NaiveDate> {
+    LEAP_SECOND_DAYS
+        .iter()
+        .map(|&(y, m, d)| NaiveDate::from_ymd_opt(y, m, d).unwrap())
+}
+
+/// UTC time of ```instant```, a leap second is folded into the end of the
+/// second before it.
+fn folded_utc<Tz: TimeZone>(instant: &DateTime<Tz>) -> NaiveDateTime {
+    let utc = instant.naive_utc();
+    utc.with_nanosecond(utc.nanosecond().min(999_999_999))
+        .unwrap()
+}
+
+/// Leap seconds inserted at or before ```instant```, i.e. since 1972.
+fn leap_seconds_before(instant: &NaiveDateTime) -> i64 {
+    leap_second_days()
+        .take_while(|day| day.succ_opt().unwrap().and_hms_opt(0, 0, 0).unwrap() <= *instant)
+        .count() as i64
+}
+
+/// Signed number of leap seconds inserted between ```start``` and ```end```,
+/// negative when ```end``` is before ```start```. An instant within a leap
+/// second, with chrono's nanoseconds of 1 second or more, counts as the end
+/// of the second before it, like in ```get_diff_datetime```.
+///
+/// Needs the ```leap-seconds``` feature.
+pub fn leap_seconds_between<Tz: TimeZone>(start: &DateTime<Tz>, end: &DateTime<Tz>) -> i64 {
+    leap_seconds_before(&folded_utc(end)) - leap_seconds_before(&folded_utc(start))
+}
+
+/// True elapsed time between ```start``` and ```end``` in SI seconds,
+/// counting the leap seconds in between which chrono leaves out and
+/// ```TzMode::Absolute``` breaks down as if they didn't exist. Whole
+/// seconds, the fraction is dropped.
+///
+/// Needs the ```leap-seconds``` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "leap-seconds")]
+/// # {
+/// use chrono::{TimeZone, Utc};
+///
+/// use datediff::elapsed_seconds;
+///
+/// let start = Utc.with_ymd_and_hms(1972, 1, 1, 0, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2017, 1, 1, 0, 0, 0).unwrap();
+///
+/// let drift = elapsed_seconds(&start, &end) - end.signed_duration_since(start).num_seconds();
+/// # assert_eq!(drift, 27);
+/// # }
+/// ```
+pub fn elapsed_seconds<Tz: TimeZone>(start: &DateTime<Tz>, end: &DateTime<Tz>) -> i64 {
+    let elapsed = folded_utc(end).signed_duration_since(folded_utc(start));
+    elapsed.num_seconds() + leap_seconds_between(start, end)
+}
+
+/// TAI - UTC in seconds during the UTC day ```date```, from 10 seconds on
+/// 1972-01-01 up to 37 since 2017-01-01. ```None``` before 1972, when UTC
+/// seconds weren't SI seconds and the offset wasn't a whole number.
+///
+/// As current as the leap second table, see ```leap_second_days```: after
+/// a newly announced leap second the offset is one second short.
+///
+/// Needs the ```leap-seconds``` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "leap-seconds")]
+/// # {
+/// use chrono::NaiveDate;
+///
+/// use datediff::utc_to_tai_offset;
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// # assert_eq!(utc_to_tai_offset(&date(1999, 1, 1)), Some(32));
+/// # assert_eq!(utc_to_tai_offset(&date(2024, 5, 1)), Some(37));
+/// # assert_eq!(utc_to_tai_offset(&date(1971, 12, 31)), None);
+/// # }
+/// ```
+pub fn utc_to_tai_offset(date: &NaiveDate) -> Option<i64> {
+    let midnight = date.and_hms_opt(0, 0, 0).unwrap();
+    tai_offset(&midnight)
+}
+
+fn tai_offset(utc: &NaiveDateTime) -> Option<i64> {
+    if utc.year() < 1972 {
+        return None;
+    }
+    Some(10 + leap_seconds_before(utc))
+}
+
+/// Takes two instants from 1972 on and gives their difference on the TAI
+/// timescale, i.e. both are moved to TAI before breaking the difference
+/// down like ```get_diff_datetime```. Days are then always 86400 SI
+/// seconds, so leap seconds in between show up in the seconds. ```None```
+/// before 1972, see ```utc_to_tai_offset```.
+///
+/// Needs the ```leap-seconds``` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "leap-seconds")]
+/// # {
+/// use chrono::{TimeZone, Utc};
+///
+/// use datediff::get_diff_tai;
+///
+/// let start = Utc.with_ymd_and_hms(2016, 6, 1, 12, 0, 0).unwrap();
+/// let end = Utc.with_ymd_and_hms(2017, 6, 1, 12, 0, 0).unwrap();
+///
+/// let diff = get_diff_tai(&start, &end).unwrap();
+/// # assert_eq!((diff.years(), diff.months(), diff.days()), (1, 0, 0));
+/// # assert_eq!(diff.seconds(), 1);
+/// # }
+/// ```
+pub fn get_diff_tai<Tz: TimeZone>(
+    start: &DateTime<Tz>,
+    end: &DateTime<Tz>,
+) -> Option<DateTimeInterval> {
+    let to_tai = |instant: &DateTime<Tz>| {
+        let utc = folded_utc(instant);
+        Some(utc + Duration::seconds(tai_offset(&utc)?))
+    };
+    Some(get_diff_datetime(&to_tai(start)?, &to_tai(end)?))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Datelike, NaiveDate, TimeZone, Utc};
+
+    use crate::{
+        elapsed_seconds, get_diff_tai, leap_second_days, leap_seconds_between, utc_to_tai_offset,
+        Interval,
+    };
+
+    #[test]
+    fn leap_seconds() {
+        let days: Vec<_> = leap_second_days().collect();
+        assert_eq!(days.len(), 27);
+        assert!(days.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(days.iter().all(|day| day.succ_opt().unwrap().day() == 1));
+
+        let at = |y, m, d, h, min, s| Utc.with_ymd_and_hms(y, m, d, h, min, s).unwrap();
+
+        // around the one at the end of 2016
+        let before = at(2016, 12, 31, 23, 59, 59);
+        let after = at(2017, 1, 1, 0, 0, 0);
+        assert_eq!(leap_seconds_between(&before, &after), 1);
+        assert_eq!(leap_seconds_between(&after, &before), -1);
+        assert_eq!(elapsed_seconds(&before, &after), 2);
+        assert_eq!(elapsed_seconds(&after, &before), -2);
+        assert_eq!(elapsed_seconds(&after, &at(2017, 1, 1, 0, 0, 1)), 1);
+        let leap = Utc.from_utc_datetime(
+            &NaiveDate::from_ymd_opt(2016, 12, 31)
+                .unwrap()
+                .and_hms_milli_opt(23, 59, 59, 1_500)
+                .unwrap(),
+        );
+        assert_eq!(leap_seconds_between(&before, &leap), 0);
+        assert_eq!(leap_seconds_between(&leap, &after), 1);
+        assert_eq!(elapsed_seconds(&before, &leap), 0);
+        assert_eq!(elapsed_seconds(&leap, &after), 1);
+
+        assert_eq!(
+            leap_seconds_between(&at(1970, 1, 1, 0, 0, 0), &at(2026, 1, 1, 0, 0, 0)),
+            27
+        );
+        assert_eq!(
+            leap_seconds_between(&at(1999, 1, 1, 0, 0, 0), &at(2005, 12, 31, 12, 0, 0)),
+            0
+        );
+        assert_eq!(
+            leap_seconds_between(&at(1981, 7, 1, 0, 0, 0), &at(1981, 6, 30, 0, 0, 0)),
+            -1
+        );
+    }
+
+    #[test]
+    fn tai() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        for &(day, offset) in &[
+            (date(1972, 1, 1), 10),
+            (date(1972, 6, 30), 10),
+            (date(1972, 7, 1), 11),
+            (date(1980, 1, 1), 19),
+            (date(2009, 1, 1), 34),
+            (date(2016, 12, 31), 36),
+            (date(2017, 1, 1), 37),
+        ] {
+            assert_eq!(utc_to_tai_offset(&day), Some(offset), "{}", day);
+        }
+        assert_eq!(utc_to_tai_offset(&date(1970, 1, 1)), None);
+
+        let at = |y, m, d, h, min, s| Utc.with_ymd_and_hms(y, m, d, h, min, s).unwrap();
+
+        // elapsed seconds on both timescales agree
+        let start = at(1975, 3, 14, 8, 0, 0);
+        let end = at(2020, 3, 14, 8, 0, 0);
+        let diff = get_diff_tai(&start, &end).unwrap();
+        assert_eq!((diff.years(), diff.months(), diff.days()), (45, 0, 0));
+        assert_eq!((diff.hours(), diff.minutes(), diff.seconds()), (0, 0, 23));
+        assert_eq!(
+            get_diff_tai(&end, &start).unwrap().date_part(),
+            diff.date_part().inverse()
+        );
+
+        // same as UTC without a leap second in between
+        let diff = get_diff_tai(&at(2000, 1, 1, 0, 0, 0), &at(2005, 1, 1, 0, 0, 0)).unwrap();
+        assert!(diff
+            .date_part()
+            .eq_at(&Interval::of_years(5), date(2000, 1, 1)));
+        assert_eq!(diff.seconds(), 0);
+
+        assert_eq!(get_diff_tai(&at(1971, 1, 1, 0, 0, 0), &start), None);
+    }
+}
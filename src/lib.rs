@@ -10,8 +10,8 @@
 //!
 //! use datediff::get_diff;
 //!
-//! let start_date = NaiveDate::from_ymd(1947, 8, 15);
-//! let end_date = NaiveDate::from_ymd(1950, 1, 26);
+//! let start_date = NaiveDate::from_ymd_opt(1947, 8, 15).unwrap();
+//! let end_date = NaiveDate::from_ymd_opt(1950, 1, 26).unwrap();
 //!
 //! println!("Duration is {}", get_diff(&start_date, &end_date));
 //!
@@ -23,10 +23,119 @@
 //! # assert_eq!(duration.positive(), true);
 //! ```
 //!
+//! # Features
+//!
+//! - ```arrow```: conversions to and from Arrow's ```IntervalMonthDayNano```
+//!   and ```IntervalYearMonth``` values through ```arrow-buffer```.
+//! - ```diesel```: Postgres INTERVAL columns as ```Interval``` and
+//!   conversions to and from Diesel's ```PgInterval```, also for
+//!   ```SignedInterval``` with mixed signs.
+//! - ```i18n```: intervals in other languages with CLDR plural rules through
+//!   ```icu_plurals```, see ```Interval::format_locale```.
+//! - ```leap-seconds```: a table of historical leap seconds for true elapsed
+//!   time in SI seconds and the TAI timescale, see ```elapsed_seconds``` and
+//!   ```get_diff_tai```.
+//! - ```natural```: dates like ```yesterday```, ```next friday``` or ```3
+//!   weeks ago``` relative to a ```Clock```, see ```parse_natural```.
+//! - ```parse```: dates in common formats like ```01/02/2020``` or ```Jan
+//!   2, 2020```, see ```dates::parse_date```.
+//! - ```schemars```: ```JsonSchema``` for ```Interval```, describing the ISO
+//!   8601 string of the ```serde``` feature, which it turns on.
+//! - ```serde```: ```Serialize``` and ```Deserialize``` for ```Interval```, an
+//!   ISO 8601 duration in human readable formats and a tuple otherwise, and
+//!   other representations in the ```serde``` module.
+//! - ```sqlx```: ```Type```, ```Encode``` and ```Decode``` for ```Interval```
+//!   in the Postgres INTERVAL binary format.
+//! - ```tz```: IANA time zones by name through ```chrono-tz```, see
+//!   ```get_diff_in_zone```.
+
+use chrono::{Datelike, Days, Duration, Months, NaiveDate};
+
+use std::{
+    cmp::Ordering,
+    convert::TryFrom,
+    fmt,
+    iter::Sum,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+mod algorithm;
+#[cfg(feature = "arrow")]
+mod arrow;
+mod clock;
+#[cfg(feature = "parse")]
+pub mod dates;
+mod datetime;
+mod display;
+mod ext;
+mod fiscal;
+mod human;
+#[cfg(feature = "i18n")]
+mod i18n;
+mod iso8601;
+#[cfg(feature = "leap-seconds")]
+mod leap;
+#[cfg(feature = "natural")]
+mod natural;
+mod options;
+mod ordinal;
+mod parse;
+mod partial;
+#[cfg(any(feature = "diesel", feature = "sqlx"))]
+mod pg;
+mod round;
+#[cfg(feature = "schemars")]
+mod schema;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod signed;
+mod sql;
+pub mod stats;
+mod tz;
+mod unit;
+mod unix;
 
-use chrono::{Datelike, NaiveDate};
+use display::plural;
 
-use std::{fmt, mem};
+pub use algorithm::{
+    days_360, get_diff_by, months_between, php_diff, timestamp_diff, verify_roundtrip,
+    DiffAlgorithm, PhpDateInterval,
+};
+pub use clock::{since_today, until_today, Clock, FixedClock, SystemClock};
+pub use datetime::{
+    age_exact, get_diff_date_datetime, get_diff_datetime, get_diff_datetime_date, get_time_diff,
+    until_next_time, until_next_weekday_time, DateTimeInterval, MidnightPolicy,
+    TimeOfDayAssumption,
+};
+pub use display::{DisplayOptions, FormatError, IntervalDisplay, SignStyle, Style};
+pub use ext::DateDiffExt;
+pub use fiscal::FiscalCalendar;
+pub use human::{Granularity, HumanizeThresholds};
+#[cfg(feature = "i18n")]
+pub use i18n::UnsupportedLocaleError;
+pub use iso8601::parse_iso8601_interval;
+#[cfg(feature = "leap-seconds")]
+pub use leap::{
+    elapsed_seconds, get_diff_tai, leap_second_days, leap_seconds_between, utc_to_tai_offset,
+};
+#[cfg(feature = "natural")]
+pub use natural::{get_diff_natural, parse_natural};
+pub use options::{diff_in_with, get_diff_with, DiffOptions, LeapDayPolicy};
+pub use ordinal::{day_of_year_diff, format_ordinal, from_ordinal, parse_ordinal, to_ordinal};
+pub use parse::{get_diff_str, ParseIntervalError};
+pub use partial::{get_diff_partial, PartialDate, PartialFill, Year, YearMonth};
+pub use round::{get_diff_rounded, RoundingMode};
+pub use signed::SignedInterval;
+pub use sql::SqlDialect;
+pub use tz::{calendar_days_between_tz, get_diff_tz, TzMode};
+#[cfg(feature = "tz")]
+pub use tz::{get_diff_in_zone, localize, UnknownZoneError};
+pub use unit::{
+    days_in_quarter, diff_in, fortnights_between, full_weeks_between, get_diff_units, iso_week_of,
+    iso_weeks_between, iso_weeks_in_year, quarter_of, quarters_between, week_of_year,
+    weeks_between, Unit,
+};
+pub use unix::{get_diff_systemtime, get_diff_unix, get_diff_unix_millis};
 
 /// Gives no of days in a given month for given year.
 /// It takes care of leap day as well. Panics for invalid input.
@@ -42,20 +151,19 @@ use std::{fmt, mem};
 /// println!("No of Days: {}", total_days_in_month(year, month));
 /// # assert_eq!(total_days_in_month(year, month), 29);
 /// ```
-
 pub fn total_days_in_month(year: i32, month: u32) -> u32 {
-    if month == 12 {
-        NaiveDate::from_ymd(year + 1, 1, 1)
-    } else {
-        NaiveDate::from_ymd(year, month + 1, 1)
-    }
-    .signed_duration_since(NaiveDate::from_ymd(year, month, 1))
-    .num_days() as u32
+    NaiveDate::from_ymd_opt(year, month, 1).expect("invalid year or month");
+    days_in_month(year, month)
 }
 
 /// Holds the difference in days, months, years.
 /// ```positive``` flag tells whether the difference of two dates was positive or negative
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(diesel::expression::AsExpression, diesel::deserialize::FromSqlRow),
+    diesel(sql_type = diesel::sql_types::Interval)
+)]
 pub struct Interval {
     days: u32,
     months: u32,
@@ -63,7 +171,257 @@ pub struct Interval {
     positive: bool,
 }
 
+/// Error returned when an ```Interval``` can't be built from the given
+/// components or converted into another type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntervalError {
+    /// Years must fit in an ```i32``` so the signed accessors can't overflow.
+    YearsOutOfRange(u32),
+    /// Months must be below 12, anything more belongs in years.
+    MonthsOutOfRange(u32),
+    /// Days must be below 31, the longest month.
+    DaysOutOfRange(u32),
+    /// The target type only holds whole months, but the interval has days.
+    NotWholeMonths(u32),
+    /// The source has a time part, in its own unit such as microseconds for
+    /// Postgres or nanoseconds for Arrow, but an ```Interval``` only holds
+    /// whole days.
+    NotWholeDays(i64),
+    /// The target type can't hold a negative interval.
+    Negative,
+    /// Components have different signs, e.g. +1 month -3 days.
+    MixedSigns,
+}
+
+impl fmt::Display for IntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IntervalError::YearsOutOfRange(years) => write!(
+                f,
+                "years out of range: {} (expected 0..={})",
+                years,
+                Interval::MAX_YEARS
+            ),
+            IntervalError::MonthsOutOfRange(months) => {
+                write!(f, "months out of range: {} (expected 0..=11)", months)
+            }
+            IntervalError::DaysOutOfRange(days) => {
+                write!(f, "days out of range: {} (expected 0..=30)", days)
+            }
+            IntervalError::NotWholeMonths(days) => {
+                write!(f, "interval has {} days besides whole months", days)
+            }
+            IntervalError::NotWholeDays(time) => {
+                write!(f, "interval has a time part besides whole days: {}", time)
+            }
+            IntervalError::Negative => write!(f, "interval is negative"),
+            IntervalError::MixedSigns => write!(f, "components have different signs"),
+        }
+    }
+}
+
+impl std::error::Error for IntervalError {}
+
 impl Interval {
+    /// Largest value of ```months``` accepted by ```Interval::new```.
+    pub const MAX_MONTHS: u32 = 11;
+    /// Largest value of ```days``` accepted by ```Interval::new```.
+    pub const MAX_DAYS: u32 = 30;
+    /// Largest value of ```years``` accepted by ```Interval::new``` and
+    /// produced by arithmetic, so that ```signed_years``` fits in an ```i32```.
+    pub const MAX_YEARS: u32 = i32::MAX as u32;
+
+    /// Longest positive interval ```Interval::new``` accepts.
+    pub const MAX: Interval = Interval {
+        days: Interval::MAX_DAYS,
+        months: Interval::MAX_MONTHS,
+        years: Interval::MAX_YEARS,
+        positive: true,
+    };
+
+    /// Longest negative interval ```Interval::new``` accepts.
+    pub const MIN: Interval = Interval {
+        positive: false,
+        ..Interval::MAX
+    };
+
+    /// Builds an Interval from its components, as ```get_diff``` would give it.
+    /// Months must be in ```0..=11```, days in ```0..=30``` and years no
+    /// more than ```Interval::MAX_YEARS```.
+    /// A zero interval is always positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::{get_diff, Interval, IntervalError};
+    ///
+    /// let expected = Interval::new(2, 5, 11, true).unwrap();
+    /// let duration = get_diff(
+    ///     &NaiveDate::from_ymd_opt(1947, 8, 15).unwrap(),
+    ///     &NaiveDate::from_ymd_opt(1950, 1, 26).unwrap(),
+    /// );
+    ///
+    /// # assert_eq!(duration, expected);
+    /// # assert_eq!(Interval::new(1, 12, 0, true), Err(IntervalError::MonthsOutOfRange(12)));
+    /// ```
+    pub fn new(
+        years: u32,
+        months: u32,
+        days: u32,
+        positive: bool,
+    ) -> Result<Interval, IntervalError> {
+        if years > Self::MAX_YEARS {
+            return Err(IntervalError::YearsOutOfRange(years));
+        }
+        if months > Self::MAX_MONTHS {
+            return Err(IntervalError::MonthsOutOfRange(months));
+        }
+        if days > Self::MAX_DAYS {
+            return Err(IntervalError::DaysOutOfRange(days));
+        }
+        Ok(Interval {
+            days,
+            months,
+            years,
+            positive: positive || (years == 0 && months == 0 && days == 0),
+        })
+    }
+
+    /// Same as ```get_diff```, for code that only imports ```Interval```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::Interval;
+    ///
+    /// let (start, end) = (NaiveDate::from_ymd_opt(1947, 8, 15).unwrap(), NaiveDate::from_ymd_opt(1950, 1, 26).unwrap());
+    ///
+    /// println!("Duration is {}", Interval::between(&start, &end));
+    /// # assert_eq!(Interval::between(&start, &end), Interval::new(2, 5, 11, true).unwrap());
+    /// # assert_eq!(Interval::from((start, end)), Interval::between(&start, &end));
+    /// ```
+    pub fn between(start: &NaiveDate, end: &NaiveDate) -> Interval {
+        get_diff(start, end)
+    }
+
+    /// Interval of no time at all, the same as the difference of two equal dates.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::{get_diff, Interval};
+    ///
+    /// let today = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+    ///
+    /// # assert_eq!(Interval::zero(), get_diff(&today, &today));
+    /// # assert!(Interval::zero().is_zero());
+    /// # assert_eq!(Interval::zero(), Interval::default());
+    /// ```
+    pub fn zero() -> Interval {
+        Interval {
+            days: 0,
+            months: 0,
+            years: 0,
+            positive: true,
+        }
+    }
+
+    /// Positive interval of ```years``` years, panics with "interval
+    /// overflow" when ```years``` exceeds ```Interval::MAX_YEARS```.
+    ///
+    /// These shorthands can't be called ```years()``` and so on since those
+    /// names are taken by the getters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::Interval;
+    ///
+    /// let signed_on = NaiveDate::from_ymd_opt(2020, 1, 15).unwrap();
+    ///
+    /// let renewal = signed_on + Interval::of_years(2);
+    /// let review = signed_on + Interval::of_months(6);
+    /// let probation_end = signed_on + Interval::of_days(90);
+    ///
+    /// # assert_eq!(renewal, NaiveDate::from_ymd_opt(2022, 1, 15).unwrap());
+    /// # assert_eq!(review, NaiveDate::from_ymd_opt(2020, 7, 15).unwrap());
+    /// # assert_eq!(probation_end, NaiveDate::from_ymd_opt(2020, 4, 14).unwrap());
+    /// ```
+    pub fn of_years(years: u32) -> Interval {
+        Interval::builder().years(years).build_unchecked()
+    }
+
+    /// Positive interval of ```months``` months, 12 months or more carry into years.
+    pub fn of_months(months: u32) -> Interval {
+        Interval::from_months_days(i64::from(months), 0)
+    }
+
+    /// Positive interval of ```days``` days. Days never carry into months,
+    /// so this may have more than 30 days, up to ```i32::MAX```. Panics with
+    /// "interval overflow" beyond that.
+    pub fn of_days(days: u32) -> Interval {
+        Interval::builder().days(days).build_unchecked()
+    }
+
+    /// Tells whether all components are zero.
+    pub fn is_zero(&self) -> bool {
+        self.years == 0 && self.months == 0 && self.days == 0
+    }
+
+    /// Gives ```(years, months, days, positive)``` in one go, in the same
+    /// order as ```Interval::new``` takes them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::{get_diff, Interval};
+    ///
+    /// let duration = get_diff(&NaiveDate::from_ymd_opt(1947, 8, 15).unwrap(), &NaiveDate::from_ymd_opt(1950, 1, 26).unwrap());
+    ///
+    /// match duration.into_parts() {
+    ///     (0, 0, days, _) => println!("{} days", days),
+    ///     (years, months, days, _) => println!("{}y {}m {}d", years, months, days),
+    /// }
+    /// # assert_eq!(Interval::new(2, 5, 11, true).unwrap().into_parts(), (2, 5, 11, true));
+    /// # assert_eq!(Interval::from_parts((2, 5, 11, true)), Interval::new(2, 5, 11, true));
+    /// ```
+    pub fn into_parts(self) -> (u32, u32, u32, bool) {
+        (self.years, self.months, self.days, self.positive)
+    }
+
+    /// Builds an Interval from the output of ```into_parts```, with the same
+    /// validation as ```Interval::new```.
+    pub fn from_parts(
+        (years, months, days, positive): (u32, u32, u32, bool),
+    ) -> Result<Interval, IntervalError> {
+        Interval::new(years, months, days, positive)
+    }
+
+    /// Starts an ```IntervalBuilder```, positive with all components zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::Interval;
+    ///
+    /// let interval = Interval::builder().years(2).months(3).days(4).negative().build();
+    ///
+    /// # assert_eq!(interval, Interval::new(2, 3, 4, false));
+    /// ```
+    pub fn builder() -> IntervalBuilder {
+        IntervalBuilder::default()
+    }
+
     pub fn days(&self) -> u32 {
         self.days
     }
@@ -76,148 +434,1886 @@ impl Interval {
     pub fn positive(&self) -> bool {
         self.positive
     }
-}
 
-impl fmt::Display for Interval {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "({} years {} months {} days {})",
-            self.years,
-            self.months,
-            self.days,
-            if self.positive { "Ahead" } else { "Behind" }
+    fn sign(&self) -> i32 {
+        if self.positive {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Years with the sign of the interval applied. This never wraps, every
+    /// way of building an ```Interval``` keeps years within
+    /// ```Interval::MAX_YEARS``` and months and days within an ```i32```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::get_diff;
+    ///
+    /// let duration = get_diff(
+    ///     &NaiveDate::from_ymd_opt(2020, 2, 5).unwrap(),
+    ///     &NaiveDate::from_ymd_opt(2013, 1, 1).unwrap(),
+    /// );
+    ///
+    /// # assert_eq!(duration.signed_years(), -7);
+    /// # assert_eq!(duration.signed_months(), -1);
+    /// # assert_eq!(duration.signed_days(), -4);
+    /// ```
+    pub fn signed_years(&self) -> i32 {
+        self.sign() * self.years as i32
+    }
+
+    /// Months with the sign of the interval applied.
+    pub fn signed_months(&self) -> i32 {
+        self.sign() * self.months as i32
+    }
+
+    /// Days with the sign of the interval applied.
+    pub fn signed_days(&self) -> i32 {
+        self.sign() * self.days as i32
+    }
+
+    /// Gives signed ```(years, months, days)``` so arithmetic on the result
+    /// doesn't need to branch on ```positive```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::get_diff;
+    ///
+    /// let duration = get_diff(
+    ///     &NaiveDate::from_ymd_opt(1950, 1, 26).unwrap(),
+    ///     &NaiveDate::from_ymd_opt(1947, 8, 15).unwrap(),
+    /// );
+    ///
+    /// let (years, months, days) = duration.signed();
+    /// # assert_eq!((years, months, days), (-2, -5, -11));
+    /// ```
+    pub fn signed(&self) -> (i32, i32, i32) {
+        (
+            self.signed_years(),
+            self.signed_months(),
+            self.signed_days(),
         )
     }
+
+    /// Gives the same interval with the ```positive``` flag flipped,
+    /// so that ```get_diff(a, b)``` equals ```get_diff(b, a).inverse()```.
+    /// A zero interval stays positive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::get_diff;
+    ///
+    /// let (a, b) = (NaiveDate::from_ymd_opt(1947, 8, 15).unwrap(), NaiveDate::from_ymd_opt(1950, 1, 26).unwrap());
+    ///
+    /// # assert_eq!(get_diff(&a, &b), get_diff(&b, &a).inverse());
+    /// # assert_eq!(get_diff(&a, &b), -get_diff(&b, &a));
+    /// ```
+    pub fn inverse(&self) -> Interval {
+        self.with_sign(!self.positive)
+    }
+
+    /// Sets the sign, keeping a zero interval positive.
+    pub(crate) fn with_sign(self, positive: bool) -> Interval {
+        Interval {
+            positive: positive || self.is_zero(),
+            ..self
+        }
+    }
+
+    /// Adds ```other``` to this interval, resolving month lengths against
+    /// ```anchor```. Both intervals are applied to the anchor one after the
+    /// other and the result is the difference between the anchor and the
+    /// date reached, so it is always in canonical form.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::Interval;
+    ///
+    /// let first = Interval::new(0, 1, 20, true).unwrap();
+    /// let second = Interval::new(0, 0, 15, true).unwrap();
+    ///
+    /// // 2020-01-01 + 1 month 20 days = 2020-02-21, + 15 days = 2020-03-07
+    /// let total = first.add_at(&second, NaiveDate::from_ymd_opt(2020, 1, 1).unwrap());
+    ///
+    /// # assert_eq!(total, Interval::new(0, 2, 6, true).unwrap());
+    /// ```
+    pub fn add_at(&self, other: &Interval, anchor: NaiveDate) -> Interval {
+        get_diff(&anchor, &other.shift(self.shift(anchor)))
+    }
+
+    /// Adds up ```intervals``` by laying them end to end starting from
+    /// ```anchor``` and taking the difference to the date reached, so month
+    /// lengths are resolved exactly like ```add_at```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::{get_diff, Interval};
+    ///
+    /// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+    /// let jobs = vec![
+    ///     get_diff(&date(2015, 3, 1), &date(2017, 8, 20)),
+    ///     get_diff(&date(2018, 1, 15), &date(2020, 1, 1)),
+    /// ];
+    ///
+    /// let tenure = Interval::sum_at(jobs, date(2015, 3, 1));
+    ///
+    /// # assert_eq!(tenure, Interval::new(4, 5, 5, true).unwrap());
+    /// ```
+    pub fn sum_at<I: IntoIterator<Item = Interval>>(intervals: I, anchor: NaiveDate) -> Interval {
+        let end = intervals
+            .into_iter()
+            .fold(anchor, |date, interval| interval.shift(date));
+        get_diff(&anchor, &end)
+    }
+
+    /// Gives the canonical form of the interval at ```anchor```: months over
+    /// 11 carry into years, and days carry into months for as long as they
+    /// cover a whole calendar month, counting months from the anchor in the
+    /// direction of the interval (backwards for a negative interval).
+    /// Canonical intervals are left unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::Interval;
+    ///
+    /// let interval = Interval::builder().months(14).days(40).build_unchecked();
+    ///
+    /// // 2020-01-01 + 14 months is 2021-03-01, March 2021 has 31 days
+    /// let normalized = interval.normalize(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap());
+    ///
+    /// # assert_eq!(normalized, Interval::new(1, 3, 9, true).unwrap());
+    /// ```
+    pub fn normalize(&self, anchor: NaiveDate) -> Interval {
+        let sign = i64::from(self.sign());
+        let mut months = self.total_months().abs();
+        let mut days = i64::from(self.days);
+        loop {
+            //no month is longer than 31 days, so skip ahead in bulk
+            let step = (days / 31).max(1);
+            let from = add_months(anchor, sign * months);
+            let to = add_months(anchor, sign * (months + step));
+            let length = to.signed_duration_since(from).num_days().abs();
+            if length > days {
+                break;
+            }
+            months += step;
+            days -= length;
+        }
+        Interval::from_months_days(sign * months, sign * days)
+    }
+
+    /// Compares the elapsed time of two intervals by applying both to
+    /// ```anchor``` and comparing the dates reached. Unlike ```PartialOrd```
+    /// this takes real month lengths into account.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::Interval;
+    ///
+    /// let month = Interval::new(0, 1, 0, true).unwrap();
+    /// let days = Interval::new(0, 0, 30, true).unwrap();
+    ///
+    /// # assert_eq!(month.cmp_at(&days, NaiveDate::from_ymd_opt(2020, 1, 1).unwrap()), Ordering::Greater);
+    /// # assert_eq!(month.cmp_at(&days, NaiveDate::from_ymd_opt(2020, 4, 1).unwrap()), Ordering::Equal);
+    /// # assert_eq!(month.cmp_at(&days, NaiveDate::from_ymd_opt(2020, 2, 1).unwrap()), Ordering::Less);
+    /// ```
+    pub fn cmp_at(&self, other: &Interval, anchor: NaiveDate) -> Ordering {
+        self.shift(anchor).cmp(&other.shift(anchor))
+    }
+
+    /// Tells whether the interval is at least ```years```, ```months``` and
+    /// ```days``` long, comparing whole months first and then days, e.g. for
+    /// age gates. A negative interval is never at least a positive length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::get_diff;
+    ///
+    /// let date_of_birth = NaiveDate::from_ymd_opt(2002, 6, 15).unwrap();
+    /// let today = NaiveDate::from_ymd_opt(2020, 6, 14).unwrap();
+    ///
+    /// let age = get_diff(&date_of_birth, &today);
+    ///
+    /// # assert!(!age.at_least_years(18));
+    /// # assert!(age.at_least(17, 11, 30));
+    /// # assert!(!age.at_least(17, 12, 0));
+    /// ```
+    pub fn at_least(&self, years: u32, months: u32, days: u32) -> bool {
+        let months = i64::from(years) * 12 + i64::from(months);
+        (self.total_months(), i64::from(self.signed_days())) >= (months, i64::from(days))
+    }
+
+    /// Same as ```at_least(years, 0, 0)```.
+    pub fn at_least_years(&self, years: u32) -> bool {
+        self.at_least(years, 0, 0)
+    }
+
+    /// Tells whether the interval reaches past ```other``` from ```anchor```,
+    /// see ```cmp_at```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::Interval;
+    ///
+    /// let sla = Interval::of_days(30);
+    /// let month = Interval::of_months(1);
+    ///
+    /// # assert!(month.longer_than(&sla, NaiveDate::from_ymd_opt(2020, 1, 1).unwrap()));
+    /// # assert!(!month.longer_than(&sla, NaiveDate::from_ymd_opt(2020, 4, 1).unwrap()));
+    /// ```
+    pub fn longer_than(&self, other: &Interval, anchor: NaiveDate) -> bool {
+        self.cmp_at(other, anchor) == Ordering::Greater
+    }
+
+    /// Semantic equality: tells whether both intervals reach the same date
+    /// from ```anchor```, while ```==``` compares the components.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::Interval;
+    ///
+    /// let month = Interval::new(0, 1, 0, true).unwrap();
+    /// let days = Interval::new(0, 0, 30, true).unwrap();
+    ///
+    /// # assert_ne!(month, days);
+    /// # assert!(month.eq_at(&days, NaiveDate::from_ymd_opt(2020, 4, 1).unwrap()));
+    /// # assert!(!month.eq_at(&days, NaiveDate::from_ymd_opt(2020, 5, 1).unwrap()));
+    /// ```
+    pub fn eq_at(&self, other: &Interval, anchor: NaiveDate) -> bool {
+        self.cmp_at(other, anchor) == Ordering::Equal
+    }
+
+    /// Exact signed number of days the interval spans when applied to ```anchor```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::get_diff;
+    ///
+    /// let (start, end) = (NaiveDate::from_ymd_opt(1947, 8, 15).unwrap(), NaiveDate::from_ymd_opt(1950, 1, 26).unwrap());
+    ///
+    /// let duration = get_diff(&start, &end);
+    ///
+    /// # assert_eq!(duration.total_days(start), 895);
+    /// # assert_eq!(duration.total_weeks(start), 127);
+    /// # assert_eq!(duration.total_months(), 29);
+    /// # assert_eq!(duration.inverse().total_days(end), -895);
+    /// ```
+    pub fn total_days(&self, anchor: NaiveDate) -> i64 {
+        self.shift(anchor).signed_duration_since(anchor).num_days()
+    }
+
+    /// Signed number of whole weeks the interval spans when applied to
+    /// ```anchor```, leftover days are dropped.
+    pub fn total_weeks(&self, anchor: NaiveDate) -> i64 {
+        self.total_days(anchor) / 7
+    }
+
+    /// Gives the whole months of the interval, without the sign, for chrono's
+    /// ```checked_add_months``` and ```checked_sub_months```. Saturates at
+    /// ```u32::MAX``` months.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::get_diff;
+    ///
+    /// let (start, end) = (NaiveDate::from_ymd_opt(1947, 8, 15).unwrap(), NaiveDate::from_ymd_opt(1950, 1, 26).unwrap());
+    /// let duration = get_diff(&start, &end);
+    ///
+    /// let reached = start
+    ///     .checked_add_months(duration.to_chrono_months())
+    ///     .and_then(|date| date.checked_add_days(duration.to_chrono_days()));
+    ///
+    /// # assert_eq!(reached, Some(end));
+    /// ```
+    pub fn to_chrono_months(&self) -> Months {
+        let months = i64::from(self.years) * 12 + i64::from(self.months);
+        Months::new(u32::try_from(months).unwrap_or(u32::MAX))
+    }
+
+    /// Gives the days component of the interval, without the sign, for
+    /// chrono's ```checked_add_days``` and ```checked_sub_days```.
+    pub fn to_chrono_days(&self) -> Days {
+        Days::new(u64::from(self.days))
+    }
+
+    /// Converts the interval into a single ```chrono::Duration``` without an
+    /// anchor, using fixed lengths: a year is always 365.25 days, a month is
+    /// as long as ```assumption``` says and a day is 24 hours. Panics if the
+    /// result doesn't fit in a ```Duration```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::Duration;
+    ///
+    /// use datediff::{Interval, MonthLengthAssumption};
+    ///
+    /// let interval = Interval::new(1, 2, 3, true).unwrap();
+    ///
+    /// let duration = interval.to_duration_approx(MonthLengthAssumption::ThirtyDays);
+    ///
+    /// # assert_eq!(duration, Duration::hours(365 * 24 + 6) + Duration::days(63));
+    /// # assert_eq!(
+    /// #     interval.to_duration_approx(MonthLengthAssumption::AverageYear),
+    /// #     Duration::hours(365 * 24 + 6) + Duration::hours(60 * 24 + 21) + Duration::days(3)
+    /// # );
+    /// ```
+    pub fn to_duration_approx(&self, assumption: MonthLengthAssumption) -> Duration {
+        const DAY: i64 = 24 * 60 * 60;
+        const YEAR: i64 = DAY * 36525 / 100;
+        let month = match assumption {
+            MonthLengthAssumption::ThirtyDays => 30 * DAY,
+            MonthLengthAssumption::AverageYear => YEAR / 12,
+        };
+        let seconds = i64::from(self.years) * YEAR
+            + i64::from(self.months) * month
+            + i64::from(self.days) * DAY;
+        Duration::seconds(i64::from(self.sign()) * seconds)
+    }
+
+    /// Breaks the interval down into weeks and days when applied to ```anchor```,
+    /// since weeks can't be derived from months without knowing their lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::Interval;
+    ///
+    /// let interval = Interval::new(0, 6, 19, true).unwrap();
+    ///
+    /// let weeks_days = interval.to_weeks_days(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap());
+    ///
+    /// # assert_eq!(weeks_days.weeks(), 28);
+    /// # assert_eq!(weeks_days.days(), 5);
+    /// ```
+    pub fn to_weeks_days(&self, anchor: NaiveDate) -> WeeksDays {
+        WeeksDays::from_days(self.total_days(anchor))
+    }
+
+    /// Signed number of whole months, leaving out the days. Needs no anchor
+    /// since every year has 12 months.
+    pub fn total_months(&self) -> i64 {
+        i64::from(self.sign()) * (i64::from(self.years) * 12 + i64::from(self.months))
+    }
+
+    fn days_i64(&self) -> i64 {
+        i64::from(self.sign()) * i64::from(self.days)
+    }
+
+    /// Builds an interval from signed total months and days which must not
+    /// have opposite signs, ```None``` if years or days exceed ```i32```.
+    fn checked_from_months_days(months: i64, days: i64) -> Option<Interval> {
+        let years = months.unsigned_abs() / 12;
+        if years > u64::from(Self::MAX_YEARS) || days.unsigned_abs() > i32::MAX as u64 {
+            return None;
+        }
+        Some(Interval {
+            days: days.unsigned_abs() as u32,
+            months: (months.unsigned_abs() % 12) as u32,
+            years: years as u32,
+            positive: months >= 0 && days >= 0,
+        })
+    }
+
+    fn from_months_days(months: i64, days: i64) -> Interval {
+        Interval::checked_from_months_days(months, days).expect("interval overflow")
+    }
+
+    /// Same as ```+```, but gives ```None``` instead of panicking when years
+    /// would exceed ```Interval::MAX_YEARS``` or days an ```i32```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::Interval;
+    ///
+    /// # assert_eq!(Interval::MAX.checked_add(Interval::of_years(1)), None);
+    /// # assert_eq!(Interval::MAX.checked_add(Interval::MIN), Some(Interval::zero()));
+    /// ```
+    pub fn checked_add(self, other: Interval) -> Option<Interval> {
+        let mut months = self.total_months() + other.total_months();
+        let mut days = self.days_i64() + other.days_i64();
+        //borrow 30 day months until months and days agree in sign
+        if months > 0 && days < 0 {
+            let borrow = ((-days + 29) / 30).min(months);
+            months -= borrow;
+            days += borrow * 30;
+        } else if months < 0 && days > 0 {
+            let borrow = ((days + 29) / 30).min(-months);
+            months += borrow;
+            days -= borrow * 30;
+        }
+        Interval::checked_from_months_days(months, days)
+    }
+
+    /// Same as ```-```, but gives ```None``` instead of panicking on overflow.
+    pub fn checked_sub(self, other: Interval) -> Option<Interval> {
+        self.checked_add(-other)
+    }
+
+    /// Same as ```*```, but gives ```None``` instead of panicking on overflow.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::Interval;
+    ///
+    /// # assert_eq!(Interval::of_years(2).checked_mul(3), Some(Interval::of_years(6)));
+    /// # assert_eq!(Interval::of_years(Interval::MAX_YEARS / 2 + 1).checked_mul(2), None);
+    /// ```
+    pub fn checked_mul(self, factor: u32) -> Option<Interval> {
+        let months = self.total_months().checked_mul(i64::from(factor))?;
+        let days = self.days_i64().checked_mul(i64::from(factor))?;
+        Interval::checked_from_months_days(months, days)
+    }
+
+    /// Same as ```/```, but gives ```None``` instead of panicking when
+    /// ```divisor``` is zero.
+    pub fn checked_div(self, divisor: u32) -> Option<Interval> {
+        if divisor == 0 {
+            return None;
+        }
+        let divisor = i64::from(divisor);
+        let months = self.total_months();
+        let days = (months % divisor) * 30 + self.days_i64();
+        Interval::checked_from_months_days(months / divisor, days / divisor)
+    }
+
+    /// Moves ```date``` by this interval, months first and then days, the
+    /// inverse of ```get_diff```. ```policy``` decides what happens when the
+    /// day of month doesn't exist in the month reached, e.g. Jan 31 + 1 month.
+    /// Anchored methods such as ```add_at``` and ```cmp_at``` use
+    /// ```EomPolicy::Clamp```.
+    ///
+    /// Panics when the day doesn't exist under ```EomPolicy::Error```,
+    /// or when the result is out of the range of ```NaiveDate```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::{EomPolicy, Interval};
+    ///
+    /// let interval = Interval::new(0, 1, 1, true).unwrap();
+    /// let date = NaiveDate::from_ymd_opt(2019, 1, 31).unwrap();
+    ///
+    /// println!("Next due on {}", interval.apply_to(date, EomPolicy::Clamp));
+    /// # assert_eq!(interval.apply_to(date, EomPolicy::Clamp), NaiveDate::from_ymd_opt(2019, 3, 1).unwrap());
+    /// # assert_eq!(interval.apply_to(date, EomPolicy::RollOver), NaiveDate::from_ymd_opt(2019, 3, 4).unwrap());
+    /// ```
+    pub fn apply_to(&self, date: NaiveDate, policy: EomPolicy) -> NaiveDate {
+        match self.checked_apply_to(date, policy) {
+            Some(date) => date,
+            None => panic!("{} can't be applied to {} with {:?}", self, date, policy),
+        }
+    }
+
+    /// Same as ```apply_to```, but gives ```None``` instead of panicking.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::{EomPolicy, Interval};
+    ///
+    /// let month = Interval::new(0, 1, 0, true).unwrap();
+    ///
+    /// # assert_eq!(month.checked_apply_to(NaiveDate::MAX, EomPolicy::Clamp), None);
+    /// # assert_eq!(month.checked_apply_to(NaiveDate::from_ymd_opt(2020, 1, 31).unwrap(), EomPolicy::Error), None);
+    /// # assert_eq!(
+    /// #     month.checked_apply_to(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(), EomPolicy::Error),
+    /// #     Some(NaiveDate::from_ymd_opt(2020, 2, 1).unwrap())
+    /// # );
+    /// ```
+    pub fn checked_apply_to(&self, date: NaiveDate, policy: EomPolicy) -> Option<NaiveDate> {
+        checked_add_months(date, self.total_months(), policy)?
+            .checked_add_signed(Duration::days(self.days_i64()))
+    }
+
+    /// Same as ```apply_to```, but stops at ```NaiveDate::MIN``` or ```NaiveDate::MAX```
+    /// instead of going out of range. Still panics when the day doesn't
+    /// exist under ```EomPolicy::Error```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::{EomPolicy, Interval};
+    ///
+    /// let year = Interval::new(1, 0, 0, true).unwrap();
+    ///
+    /// # assert_eq!(year.saturating_apply_to(NaiveDate::MAX, EomPolicy::Clamp), NaiveDate::MAX);
+    /// # assert_eq!(year.inverse().saturating_apply_to(NaiveDate::MIN, EomPolicy::Clamp), NaiveDate::MIN);
+    /// ```
+    pub fn saturating_apply_to(&self, date: NaiveDate, policy: EomPolicy) -> NaiveDate {
+        match self.checked_apply_to(date, policy) {
+            Some(date) => date,
+            //only out of range if clamping doesn't help either
+            None if self.checked_apply_to(date, EomPolicy::Clamp).is_none() => {
+                if self.positive {
+                    NaiveDate::MAX
+                } else {
+                    NaiveDate::MIN
+                }
+            }
+            None => panic!("{} can't be applied to {} with {:?}", self, date, policy),
+        }
+    }
+
+    fn shift(&self, date: NaiveDate) -> NaiveDate {
+        self.apply_to(date, EomPolicy::Clamp)
+    }
 }
 
-/// Takes two ```chrono::NaiveDate``` as arguments to get the duration in between
-/// and gives the difference as Interval
+/// Difference of ```(start, end)```, same as ```get_diff```.
+impl From<(NaiveDate, NaiveDate)> for Interval {
+    fn from((start, end): (NaiveDate, NaiveDate)) -> Interval {
+        get_diff(&start, &end)
+    }
+}
+
+/// Converts a positive interval of whole months, fails if it has days or
+/// is negative. Saturates at ```u32::MAX``` months.
 ///
 /// # Example
 ///
 /// ```
-/// use chrono::NaiveDate;
+/// use std::convert::TryFrom;
 ///
-/// use datediff::get_diff;
+/// use chrono::Months;
 ///
-/// let start_date = NaiveDate::from_ymd(1857, 1, 5);
-/// let end_date = NaiveDate::from_ymd(2020, 1, 1);
+/// use datediff::{Interval, IntervalError};
 ///
-/// println!("Duration is {}", get_diff(&start_date, &end_date));
+/// # assert_eq!(Months::try_from(Interval::new(1, 6, 0, true).unwrap()), Ok(Months::new(18)));
+/// # assert_eq!(Months::try_from(Interval::new(1, 6, 2, true).unwrap()), Err(IntervalError::NotWholeMonths(2)));
+/// # assert_eq!(Months::try_from(Interval::new(1, 6, 0, false).unwrap()), Err(IntervalError::Negative));
+/// ```
+impl TryFrom<Interval> for Months {
+    type Error = IntervalError;
+
+    fn try_from(interval: Interval) -> Result<Months, IntervalError> {
+        if interval.days != 0 {
+            Err(IntervalError::NotWholeMonths(interval.days))
+        } else if !interval.positive {
+            Err(IntervalError::Negative)
+        } else {
+            Ok(interval.to_chrono_months())
+        }
+    }
+}
+
+impl Default for Interval {
+    fn default() -> Interval {
+        Interval::zero()
+    }
+}
+
+/// Compares ```(years, months, days)``` lexicographically with the sign
+/// applied, so negative intervals come before positive ones. This is exact
+/// for canonical intervals but not for ones with overflowing components
+/// (30 days sorts below 1 month), use ```Interval::cmp_at``` for those.
 ///
+/// # Example
 ///
-/// # use datediff::Interval;
+/// ```
+/// use datediff::Interval;
 ///
-/// # let duration = get_diff(&start_date, &end_date);
-/// # assert_eq!(duration.days(), 27);
-/// # assert_eq!(duration.months(),11);
-/// # assert_eq!(duration.years(), 162);
-/// # assert_eq!(duration.positive(), true);
+/// let mut intervals = vec![
+///     Interval::new(1, 0, 0, true).unwrap(),
+///     Interval::new(0, 11, 30, true).unwrap(),
+///     Interval::new(0, 2, 0, false).unwrap(),
+/// ];
 ///
+/// intervals.sort_by(|a, b| a.partial_cmp(b).unwrap());
+///
+/// # assert_eq!(intervals[0], Interval::new(0, 2, 0, false).unwrap());
+/// # assert_eq!(intervals[2], Interval::new(1, 0, 0, true).unwrap());
 /// ```
-pub fn get_diff(start: &NaiveDate, end: &NaiveDate) -> Interval {
-    let mut positive = true;
-    let (mut start, mut end) = (start.clone(), end.clone());
-    if end < start {
-        positive = false;
-        mem::swap(&mut start, &mut end);
+impl PartialOrd for Interval {
+    fn partial_cmp(&self, other: &Interval) -> Option<Ordering> {
+        Some(
+            self.signed()
+                .cmp(&other.signed())
+                .then(self.positive.cmp(&other.positive)),
+        )
     }
+}
 
-    //Typecasting for calculations
-    let (start_day, mut end_day) = (start.day() as i32, end.day() as i32);
-    let (start_month, mut end_month) = (start.month() as i32, end.month() as i32);
-    let (start_year, mut end_year) = (start.year(), end.year());
+/// Holds a difference as weeks and days, see ```get_diff_weeks```.
+/// ```positive``` flag tells whether the difference was positive or negative
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct WeeksDays {
+    weeks: u32,
+    days: u32,
+    positive: bool,
+}
 
-    if end_day < start_day {
-        //borrow days from previous month
-        if end_month > 1 {
-            end_day += total_days_in_month(end_year, (end_month - 1) as u32) as i32;
+impl WeeksDays {
+    fn from_days(days: i64) -> WeeksDays {
+        WeeksDays {
+            weeks: (days.unsigned_abs() / 7) as u32,
+            days: (days.unsigned_abs() % 7) as u32,
+            positive: days >= 0,
         }
-        //borrow days from last month of previous year
-        else {
-            end_day += total_days_in_month(end_year - 1, 12) as i32;
-        }
-        end_month -= 1;
-    }
-    if end_month < start_month {
-        //borrow months from previous year
-        end_month += 12;
-        end_year -= 1;
     }
 
-    Interval {
-        days: (end_day - start_day) as u32,
-        months: (end_month - start_month) as u32,
-        years: (end_year - start_year) as u32,
-        positive,
+    pub fn weeks(&self) -> u32 {
+        self.weeks
+    }
+    pub fn days(&self) -> u32 {
+        self.days
+    }
+    pub fn positive(&self) -> bool {
+        self.positive
     }
 }
 
-#[cfg(test)]
-mod test {
-    use crate::{get_diff, Interval, total_days_in_month};
-    use chrono::{NaiveDate, Utc};
+impl fmt::Display for WeeksDays {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({} week{} {} day{} {})",
+            self.weeks,
+            plural(i64::from(self.weeks)),
+            self.days,
+            plural(i64::from(self.days)),
+            if self.positive { "Ahead" } else { "Behind" }
+        )
+    }
+}
 
-    #[test]
-    fn validate() {
-        assert_eq!(
-            get_diff(
-                &NaiveDate::from_ymd(2020, 1, 1),
-                &NaiveDate::from_ymd(2020, 1, 1)
-            ),
-            Interval {
-                years: 0,
-                months: 0,
-                days: 0,
-                positive: true
-            }
-        );
+/// Builder for ```Interval```, see ```Interval::builder```.
+#[derive(Debug, Clone, Default)]
+pub struct IntervalBuilder {
+    years: u32,
+    months: u32,
+    days: u32,
+    negative: bool,
+}
 
-        assert_eq!(
-            get_diff(
-                &NaiveDate::from_ymd(2020, 2, 5),
-                &NaiveDate::from_ymd(2013, 1, 1)
-            ),
-            Interval {
-                years: 7,
-                months: 1,
-                days: 4,
-                positive: false
-            }
-        );
+impl IntervalBuilder {
+    pub fn years(mut self, years: u32) -> Self {
+        self.years = years;
+        self
+    }
+    pub fn months(mut self, months: u32) -> Self {
+        self.months = months;
+        self
+    }
+    pub fn days(mut self, days: u32) -> Self {
+        self.days = days;
+        self
+    }
+    pub fn positive(mut self) -> Self {
+        self.negative = false;
+        self
+    }
+    pub fn negative(mut self) -> Self {
+        self.negative = true;
+        self
+    }
 
-        assert_eq!(
-            get_diff(
-                &NaiveDate::from_ymd(2013, 2, 5),
-                &NaiveDate::from_ymd(2020, 1, 1)
-            ),
-            Interval {
-                years: 6,
-                months: 10,
-                days: 27,
-                positive: true
-            }
-        );
+    /// Validates the components the same way as ```Interval::new```.
+    pub fn build(self) -> Result<Interval, IntervalError> {
+        Interval::new(self.years, self.months, self.days, !self.negative)
+    }
 
-        assert_eq!(
-            get_diff(
-                &NaiveDate::from_ymd(3040, 3, 1),
-                &NaiveDate::from_ymd(1102, 1, 5)
-            ),
-            Interval {
-                years: 1938,
-                months: 1,
-                days: 25,
-                positive: false
-            }
-        );
+    /// Skips validation, so months and days may overflow their usual range
+    /// (e.g. 14 months 40 days). Still panics with "interval overflow" when
+    /// years exceed ```Interval::MAX_YEARS``` or months or days an ```i32```,
+    /// so the signed accessors can't wrap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::Interval;
+    ///
+    /// let interval = Interval::builder().months(14).days(40).build_unchecked();
+    ///
+    /// # assert_eq!(interval.months(), 14);
+    /// # assert_eq!(interval.days(), 40);
+    /// # assert_eq!(Interval::builder().negative().build_unchecked(), Interval::zero());
+    /// ```
+    pub fn build_unchecked(self) -> Interval {
+        let limit = i32::MAX as u32;
+        if self.years > Interval::MAX_YEARS || self.months > limit || self.days > limit {
+            panic!("interval overflow");
+        }
+        Interval {
+            days: self.days,
+            months: self.months,
+            years: self.years,
+            positive: true,
+        }
+        .with_sign(!self.negative)
+    }
+}
 
-        // Leap Year
-        assert_eq!(total_days_in_month(1752, 2), 29);
+/// Adds two intervals without an anchor date.
+///
+/// Months carry into years exactly, but days are never carried into months
+/// since a month has no fixed length, so the result may have more than 30
+/// days (see ```Interval::add_at``` for a canonical result). When the months
+/// and days of the sum have opposite signs, months are borrowed as 30 days
+/// each until the signs agree.
+///
+/// Panics on overflow, see ```Interval::checked_add```.
+///
+/// # Example
+///
+/// ```
+/// use datediff::Interval;
+///
+/// let first = Interval::new(1, 8, 20, true).unwrap();
+/// let second = Interval::new(2, 6, 15, true).unwrap();
+///
+/// let total = first + second;
+///
+/// # assert_eq!(total.years(), 4);
+/// # assert_eq!(total.months(), 2);
+/// # assert_eq!(total.days(), 35);
+/// # assert_eq!(
+/// #     Interval::new(0, 2, 10, true).unwrap() - Interval::new(0, 0, 15, true).unwrap(),
+/// #     Interval::new(0, 1, 25, true).unwrap()
+/// # );
+/// ```
+impl Add for Interval {
+    type Output = Interval;
 
-        assert_eq!(total_days_in_month(2019, 2), 28);
+    fn add(self, other: Interval) -> Interval {
+        self.checked_add(other).expect("interval overflow")
+    }
+}
 
-        assert_eq!(total_days_in_month(3016, 6), 30);
+/// Adds up intervals with the same normalization as ```Add```,
+/// see ```Interval::sum_at``` for a canonical result.
+///
+/// # Example
+///
+/// ```
+/// use datediff::Interval;
+///
+/// let total: Interval = vec![Interval::of_months(8), Interval::of_months(6)].into_iter().sum();
+///
+/// # assert_eq!(total, Interval::new(1, 2, 0, true).unwrap());
+/// ```
+impl Sum for Interval {
+    fn sum<I: Iterator<Item = Interval>>(iter: I) -> Interval {
+        iter.fold(Interval::zero(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Interval> for Interval {
+    fn sum<I: Iterator<Item = &'a Interval>>(iter: I) -> Interval {
+        iter.copied().sum()
+    }
+}
+
+/// Subtracts intervals by adding the negated ```other```, with the same
+/// normalization as ```Add```. Panics on overflow.
+impl Sub for Interval {
+    type Output = Interval;
+
+    fn sub(self, other: Interval) -> Interval {
+        self.checked_sub(other).expect("interval overflow")
+    }
+}
+
+/// Multiplies every component, carrying months into years. Days are not
+/// carried into months, same as ```Add```, so ```interval * 3``` equals
+/// ```interval + interval + interval```.
+///
+/// Panics on overflow, see ```Interval::checked_mul```.
+///
+/// # Example
+///
+/// ```
+/// use datediff::Interval;
+///
+/// let billing_period = Interval::new(0, 5, 12, true).unwrap();
+///
+/// let three_periods = billing_period * 3;
+///
+/// # assert_eq!(three_periods.years(), 1);
+/// # assert_eq!(three_periods.months(), 3);
+/// # assert_eq!(three_periods.days(), 36);
+/// ```
+impl Mul<u32> for Interval {
+    type Output = Interval;
+
+    fn mul(self, factor: u32) -> Interval {
+        self.checked_mul(factor).expect("interval overflow")
+    }
+}
+
+/// Divides the interval, converting the months left over after dividing
+/// the total months into days at 30 days a month. Leftover days are dropped.
+/// Panics if ```divisor``` is zero.
+///
+/// # Example
+///
+/// ```
+/// use datediff::Interval;
+///
+/// let interval = Interval::new(1, 1, 2, true).unwrap();
+///
+/// let half = interval / 2;
+///
+/// # assert_eq!(half, Interval::new(0, 6, 16, true).unwrap());
+/// ```
+impl Div<u32> for Interval {
+    type Output = Interval;
+
+    fn div(self, divisor: u32) -> Interval {
+        self.checked_div(divisor)
+            .expect("attempt to divide by zero")
+    }
+}
+
+/// Same as ```Interval::inverse```.
+impl Neg for Interval {
+    type Output = Interval;
+
+    fn neg(self) -> Interval {
+        self.inverse()
+    }
+}
+
+/// Moves the date by the interval with ```EomPolicy::Clamp```,
+/// see ```Interval::apply_to```. Panics when out of range.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::get_diff;
+///
+/// let (start, end) = (NaiveDate::from_ymd_opt(1947, 8, 15).unwrap(), NaiveDate::from_ymd_opt(1950, 1, 26).unwrap());
+/// let diff = get_diff(&start, &end);
+///
+/// # assert_eq!(start + diff, end);
+/// # assert_eq!(NaiveDate::from_ymd_opt(2020, 3, 31).unwrap() - get_diff(&start, &end), NaiveDate::from_ymd_opt(2017, 10, 20).unwrap());
+/// ```
+impl Add<Interval> for NaiveDate {
+    type Output = NaiveDate;
+
+    fn add(self, interval: Interval) -> NaiveDate {
+        interval.apply_to(self, EomPolicy::Clamp)
+    }
+}
+
+/// Moves the date back by the interval with ```EomPolicy::Clamp```.
+/// Panics when out of range.
+impl Sub<Interval> for NaiveDate {
+    type Output = NaiveDate;
+
+    fn sub(self, interval: Interval) -> NaiveDate {
+        interval.inverse().apply_to(self, EomPolicy::Clamp)
+    }
+}
+
+/// What adding months to a date does when its day doesn't exist in the
+/// month reached, e.g. Jan 31 + 1 month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EomPolicy {
+    /// Use the last day of the month instead (Jan 31 + 1 month = Feb 28).
+    #[default]
+    Clamp,
+    /// Carry the extra days into the next month (Jan 31 + 1 month = Mar 3).
+    RollOver,
+    /// Treat it as an error.
+    Error,
+}
+
+/// Month length used by ```Interval::to_duration_approx```.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonthLengthAssumption {
+    /// Every month is 30 days long.
+    ThirtyDays,
+    /// Every month is a twelfth of a 365.25 day year, i.e. 30.4375 days.
+    AverageYear,
+}
+
+/// Proleptic Gregorian month length, without building any ```NaiveDate```.
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+/// Moves ```date``` by signed ```months```, resolving missing days with ```policy```.
+fn checked_add_months(date: NaiveDate, months: i64, policy: EomPolicy) -> Option<NaiveDate> {
+    let total = (i64::from(date.year()) * 12 + i64::from(date.month0())).checked_add(months)?;
+    let year = i32::try_from(total.div_euclid(12)).ok()?;
+    let month = total.rem_euclid(12) as u32 + 1;
+    let last_day = days_in_month(year, month);
+    if date.day() <= last_day {
+        return NaiveDate::from_ymd_opt(year, month, date.day());
+    }
+    let end_of_month = NaiveDate::from_ymd_opt(year, month, last_day)?;
+    match policy {
+        EomPolicy::Clamp => Some(end_of_month),
+        EomPolicy::RollOver => {
+            end_of_month.checked_add_signed(Duration::days(i64::from(date.day() - last_day)))
+        }
+        EomPolicy::Error => None,
+    }
+}
+
+/// Moves ```date``` by signed ```months```, clamping the day to the end of the month.
+fn add_months(date: NaiveDate, months: i64) -> NaiveDate {
+    checked_add_months(date, months, EomPolicy::Clamp).expect("date out of range")
+}
+
+/// Takes two ```chrono::NaiveDate``` as arguments to get the duration in between
+/// and gives the difference as Interval
+///
+/// The difference is always broken down from the earlier date, so swapping
+/// the dates only flips the sign, see ```DiffAlgorithm::is_symmetric```.
+///
+/// A Feb 29 start has no anniversary in common years, 2020-02-29 to
+/// 2021-02-28 is 11 months 30 days and to 2021-03-01 is 1 year 1 day. See
+/// ```LeapDayPolicy``` to count either date as a whole year.
+///
+/// A start day past the end of the month borrowed from counts from that
+/// month's last day, 2021-01-31 to 2021-03-01 is 1 month 1 day.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::get_diff;
+///
+/// let start_date = NaiveDate::from_ymd_opt(1857, 1, 5).unwrap();
+/// let end_date = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+///
+/// println!("Duration is {}", get_diff(&start_date, &end_date));
+///
+///
+/// # use datediff::Interval;
+///
+/// # let duration = get_diff(&start_date, &end_date);
+/// # assert_eq!(duration.days(), 27);
+/// # assert_eq!(duration.months(),11);
+/// # assert_eq!(duration.years(), 162);
+/// # assert_eq!(duration.positive(), true);
+///
+/// ```
+pub fn get_diff(start: &NaiveDate, end: &NaiveDate) -> Interval {
+    let (years, months, days) = get_diff_extended(start, end);
+    Interval::from_months_days(years * 12 + months, days)
+}
+
+/// Same breakdown as ```get_diff```, given as signed years, months and days
+/// in ```i64``` so that further arithmetic on them can't overflow for any
+/// dates chrono supports, ```NaiveDate::MIN``` to ```NaiveDate::MAX```.
+///
+/// BCE dates use astronomical year numbering like chrono, year 0 is 1 BCE
+/// and -44 is 45 BCE, so the years are those of the proleptic Gregorian
+/// calendar with no year skipped.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::get_diff_extended;
+///
+/// let ides = NaiveDate::from_ymd_opt(-43, 3, 15).unwrap();
+/// let era = NaiveDate::from_ymd_opt(1, 1, 1).unwrap();
+///
+/// println!("{:?}", get_diff_extended(&era, &ides));
+/// # assert_eq!(get_diff_extended(&era, &ides), (-43, -9, -17));
+/// # assert_eq!(get_diff_extended(&NaiveDate::MIN, &NaiveDate::MAX), (524285, 11, 30));
+/// ```
+pub fn get_diff_extended(start: &NaiveDate, end: &NaiveDate) -> (i64, i64, i64) {
+    let sign = if end < start { -1 } else { 1 };
+    let (start, end) = if end < start {
+        (*end, *start)
+    } else {
+        (*start, *end)
+    };
+
+    let (mut start_day, mut end_day) = (i64::from(start.day()), i64::from(end.day()));
+    let (start_month, mut end_month) = (i64::from(start.month()), i64::from(end.month()));
+    let (start_year, mut end_year) = (i64::from(start.year()), i64::from(end.year()));
+
+    if end_day < start_day {
+        //borrow days from previous month, or the last month of previous year
+        let borrowed = if end_month > 1 {
+            days_in_month(end.year(), end.month() - 1)
+        } else {
+            days_in_month(end.year() - 1, 12)
+        };
+        //start day past the end of the borrowed month counts from its last day
+        start_day = start_day.min(i64::from(borrowed));
+        end_day += i64::from(borrowed);
+        end_month -= 1;
+    }
+    if end_month < start_month {
+        //borrow months from previous year
+        end_month += 12;
+        end_year -= 1;
+    }
+
+    (
+        sign * (end_year - start_year),
+        sign * (end_month - start_month),
+        sign * (end_day - start_day),
+    )
+}
+
+/// Takes two ```chrono::NaiveDate``` as arguments and gives the difference
+/// as weeks and days, e.g. for gestational age or sprints.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::get_diff_weeks;
+///
+/// let last_period = NaiveDate::from_ymd_opt(2020, 1, 6).unwrap();
+/// let today = NaiveDate::from_ymd_opt(2020, 7, 29).unwrap();
+///
+/// println!("Pregnancy is {}", get_diff_weeks(&last_period, &today));
+/// # assert_eq!(get_diff_weeks(&last_period, &today).weeks(), 29);
+/// # assert_eq!(get_diff_weeks(&last_period, &today).days(), 2);
+/// ```
+pub fn get_diff_weeks(start: &NaiveDate, end: &NaiveDate) -> WeeksDays {
+    WeeksDays::from_days(end.signed_duration_since(*start).num_days())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        diff_in, get_diff, get_diff_extended, get_diff_weeks, total_days_in_month, EomPolicy,
+        Interval, IntervalError, MonthLengthAssumption, Unit,
+    };
+    use chrono::{Datelike, NaiveDate};
+
+    #[test]
+    fn validate() {
+        assert_eq!(
+            get_diff(
+                &NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+                &NaiveDate::from_ymd_opt(2020, 1, 1).unwrap()
+            ),
+            Interval {
+                years: 0,
+                months: 0,
+                days: 0,
+                positive: true
+            }
+        );
+
+        assert_eq!(
+            get_diff(
+                &NaiveDate::from_ymd_opt(2020, 2, 5).unwrap(),
+                &NaiveDate::from_ymd_opt(2013, 1, 1).unwrap()
+            ),
+            Interval {
+                years: 7,
+                months: 1,
+                days: 4,
+                positive: false
+            }
+        );
+
+        assert_eq!(
+            get_diff(
+                &NaiveDate::from_ymd_opt(2013, 2, 5).unwrap(),
+                &NaiveDate::from_ymd_opt(2020, 1, 1).unwrap()
+            ),
+            Interval {
+                years: 6,
+                months: 10,
+                days: 27,
+                positive: true
+            }
+        );
+
+        assert_eq!(
+            get_diff(
+                &NaiveDate::from_ymd_opt(3040, 3, 1).unwrap(),
+                &NaiveDate::from_ymd_opt(1102, 1, 5).unwrap()
+            ),
+            Interval {
+                years: 1938,
+                months: 1,
+                days: 25,
+                positive: false
+            }
+        );
+
+        // Leap Year
+        assert_eq!(total_days_in_month(1752, 2), 29);
+
+        assert_eq!(total_days_in_month(2019, 2), 28);
+
+        assert_eq!(total_days_in_month(3016, 6), 30);
+    }
+
+    #[test]
+    fn borrow_from_shorter_month() {
+        assert_eq!(
+            get_diff(
+                &NaiveDate::from_ymd_opt(2020, 1, 31).unwrap(),
+                &NaiveDate::from_ymd_opt(2020, 3, 1).unwrap()
+            ),
+            Interval::new(0, 1, 1, true).unwrap()
+        );
+        assert_eq!(
+            get_diff(
+                &NaiveDate::from_ymd_opt(2019, 1, 30).unwrap(),
+                &NaiveDate::from_ymd_opt(2019, 3, 2).unwrap()
+            ),
+            Interval::new(0, 1, 2, true).unwrap()
+        );
+    }
+
+    #[test]
+    fn signed() {
+        let ahead = get_diff(
+            &NaiveDate::from_ymd_opt(2013, 2, 5).unwrap(),
+            &NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+        );
+        assert_eq!(ahead.signed(), (6, 10, 27));
+
+        let behind = get_diff(
+            &NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+            &NaiveDate::from_ymd_opt(2013, 2, 5).unwrap(),
+        );
+        assert_eq!(behind.signed(), (-6, -10, -27));
+
+        let zero = get_diff(
+            &NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+            &NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+        );
+        assert_eq!(zero.signed(), (0, 0, 0));
+    }
+
+    #[test]
+    fn new() {
+        assert_eq!(
+            Interval::new(7, 1, 4, false),
+            Ok(get_diff(
+                &NaiveDate::from_ymd_opt(2020, 2, 5).unwrap(),
+                &NaiveDate::from_ymd_opt(2013, 1, 1).unwrap()
+            ))
+        );
+        assert_eq!(
+            Interval::new(0, 0, 0, false),
+            Ok(get_diff(
+                &NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+                &NaiveDate::from_ymd_opt(2020, 1, 1).unwrap()
+            ))
+        );
+        assert!(Interval::new(0, 11, 30, true).is_ok());
+        assert_eq!(
+            Interval::new(0, 12, 0, true),
+            Err(IntervalError::MonthsOutOfRange(12))
+        );
+        assert_eq!(
+            Interval::new(0, 0, 31, true),
+            Err(IntervalError::DaysOutOfRange(31))
+        );
+    }
+
+    #[test]
+    fn builder() {
+        assert_eq!(
+            Interval::builder()
+                .years(7)
+                .months(1)
+                .days(4)
+                .negative()
+                .build(),
+            Interval::new(7, 1, 4, false)
+        );
+        assert_eq!(
+            Interval::builder().negative().positive().days(3).build(),
+            Interval::new(0, 0, 3, true)
+        );
+        assert_eq!(
+            Interval::builder().months(12).build(),
+            Err(IntervalError::MonthsOutOfRange(12))
+        );
+        assert_eq!(
+            Interval::builder().days(31).build_unchecked(),
+            Interval {
+                years: 0,
+                months: 0,
+                days: 31,
+                positive: true
+            }
+        );
+
+        // a zero interval stays positive, as with build
+        let zero = Interval::builder().negative().build_unchecked();
+        assert!(zero.positive());
+        assert_eq!(zero, Interval::zero());
+        assert_eq!(zero.to_string(), Interval::zero().to_string());
+        assert_eq!(Interval::builder().negative().build(), Ok(zero));
+    }
+
+    #[test]
+    fn add_sub() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        assert_eq!(
+            interval(1, 8, 20, true) + interval(2, 6, 15, true),
+            Interval::builder()
+                .years(4)
+                .months(2)
+                .days(35)
+                .build_unchecked()
+        );
+        assert_eq!(
+            interval(0, 2, 10, true) + interval(0, 0, 15, false),
+            interval(0, 1, 25, true)
+        );
+        assert_eq!(
+            interval(0, 0, 10, true) + interval(0, 1, 0, false),
+            interval(0, 0, 20, false)
+        );
+        assert_eq!(
+            interval(1, 0, 0, true) - interval(1, 0, 0, true),
+            interval(0, 0, 0, true)
+        );
+        assert_eq!(
+            interval(0, 3, 0, false) - interval(0, 0, 5, true),
+            interval(0, 3, 5, false)
+        );
+    }
+
+    #[test]
+    fn add_at() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+        let anchor = NaiveDate::from_ymd_opt(2020, 1, 31).unwrap();
+
+        // Jan 31 + 1 month clamps to Feb 29, + 1 day is Mar 1
+        assert_eq!(
+            interval(0, 1, 0, true).add_at(&interval(0, 0, 1, true), anchor),
+            interval(0, 1, 1, true)
+        );
+        assert_eq!(
+            interval(0, 0, 30, true).add_at(&interval(0, 0, 30, true), anchor),
+            interval(0, 2, 0, true)
+        );
+        assert_eq!(
+            interval(0, 0, 10, false).add_at(&interval(0, 1, 0, false), anchor),
+            interval(0, 1, 10, false)
+        );
+    }
+
+    #[test]
+    fn inverse() {
+        let (a, b) = (
+            NaiveDate::from_ymd_opt(2013, 2, 5).unwrap(),
+            NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+        );
+        assert_eq!(-get_diff(&a, &b), Interval::new(6, 10, 27, false).unwrap());
+        assert_eq!(
+            get_diff(&b, &a).inverse(),
+            Interval::new(6, 10, 27, true).unwrap()
+        );
+        assert_eq!(-get_diff(&a, &a), get_diff(&a, &a));
+        assert_eq!(-(-get_diff(&a, &b)), get_diff(&a, &b));
+    }
+
+    #[test]
+    fn mul_div() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        assert_eq!(
+            interval(0, 5, 12, false) * 3,
+            Interval::builder()
+                .years(1)
+                .months(3)
+                .days(36)
+                .negative()
+                .build_unchecked()
+        );
+        assert_eq!(
+            interval(0, 5, 12, true) * 3,
+            interval(0, 5, 12, true) + interval(0, 5, 12, true) + interval(0, 5, 12, true)
+        );
+        assert_eq!(interval(1, 0, 0, true) / 2, interval(0, 6, 0, true));
+        assert_eq!(interval(0, 1, 0, false) / 2, interval(0, 0, 15, false));
+        assert_eq!(interval(0, 0, 5, true) / 2, interval(0, 0, 2, true));
+        assert_eq!(interval(0, 0, 1, false) / 2, interval(0, 0, 0, true));
+    }
+
+    #[test]
+    fn zero() {
+        assert!(Interval::zero().is_zero());
+        assert!(Interval::zero().positive());
+        assert!(!Interval::new(0, 0, 1, true).unwrap().is_zero());
+        assert!((-Interval::zero()).is_zero());
+
+        let tenure = vec![
+            Interval::new(1, 2, 0, true).unwrap(),
+            Interval::new(0, 11, 3, true).unwrap(),
+        ]
+        .into_iter()
+        .fold(Interval::default(), |total, interval| total + interval);
+        assert_eq!(tenure, Interval::new(2, 1, 3, true).unwrap());
+    }
+
+    #[test]
+    fn normalize() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+        let unchecked = |m, d, p: bool| {
+            let builder = Interval::builder().months(m).days(d);
+            if p { builder } else { builder.negative() }.build_unchecked()
+        };
+
+        assert_eq!(
+            unchecked(14, 40, true).normalize(NaiveDate::from_ymd_opt(2020, 1, 1).unwrap()),
+            interval(1, 3, 9, true)
+        );
+        // Mar 1 2020 - 40 days is Jan 21, one month back covers February's 29 days
+        assert_eq!(
+            unchecked(0, 40, false).normalize(NaiveDate::from_ymd_opt(2020, 3, 1).unwrap()),
+            interval(0, 1, 11, false)
+        );
+        assert_eq!(
+            unchecked(0, 30, true).normalize(NaiveDate::from_ymd_opt(2019, 4, 1).unwrap()),
+            interval(0, 1, 0, true)
+        );
+        assert_eq!(
+            unchecked(0, 30, true).normalize(NaiveDate::from_ymd_opt(2019, 5, 1).unwrap()),
+            interval(0, 0, 30, true)
+        );
+        assert_eq!(
+            unchecked(0, 3654, true).normalize(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap()),
+            interval(10, 0, 1, true)
+        );
+
+        // canonical intervals stay as they are, even at month ends
+        let anchor = NaiveDate::from_ymd_opt(2020, 1, 31).unwrap();
+        assert_eq!(
+            interval(0, 1, 0, true).normalize(anchor),
+            interval(0, 1, 0, true)
+        );
+        assert_eq!(
+            interval(2, 5, 27, false).normalize(anchor),
+            interval(2, 5, 27, false)
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        use std::cmp::Ordering;
+
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        assert!(interval(1, 0, 0, true) > interval(0, 11, 30, true));
+        assert!(interval(0, 0, 1, false) < interval(0, 0, 0, true));
+        assert!(interval(1, 0, 0, false) < interval(0, 11, 0, false));
+        assert_eq!(
+            interval(2, 3, 4, true).partial_cmp(&interval(2, 3, 4, true)),
+            Some(Ordering::Equal)
+        );
+
+        let anchor = NaiveDate::from_ymd_opt(2019, 2, 1).unwrap();
+        assert_eq!(
+            interval(0, 1, 0, true).cmp_at(&interval(0, 0, 28, true), anchor),
+            Ordering::Equal
+        );
+        assert_eq!(
+            interval(0, 0, 1, false).cmp_at(&interval(0, 0, 0, true), anchor),
+            Ordering::Less
+        );
+        assert_eq!(
+            Interval::builder()
+                .days(40)
+                .build_unchecked()
+                .cmp_at(&interval(0, 1, 0, true), anchor),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn totals() {
+        let (start, end) = (
+            NaiveDate::from_ymd_opt(2013, 2, 5).unwrap(),
+            NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+        );
+        let duration = get_diff(&start, &end);
+        assert_eq!(
+            duration.total_days(start),
+            end.signed_duration_since(start).num_days()
+        );
+        // counted backwards from 2020-01-01 the interval reaches 2013-02-02
+        assert_eq!(duration.inverse().total_days(end), -2524);
+        assert_eq!(duration.total_months(), 82);
+        assert_eq!(duration.inverse().total_months(), -82);
+
+        let interval = Interval::new(0, 0, 13, false).unwrap();
+        assert_eq!(interval.total_weeks(start), -1);
+        assert_eq!(Interval::zero().total_days(start), 0);
+    }
+
+    #[test]
+    fn apply_to() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+        let date = NaiveDate::from_ymd_opt(2020, 1, 31).unwrap();
+
+        assert_eq!(
+            interval(0, 1, 0, true).apply_to(date, EomPolicy::Clamp),
+            NaiveDate::from_ymd_opt(2020, 2, 29).unwrap()
+        );
+        assert_eq!(
+            interval(0, 1, 0, true).apply_to(date, EomPolicy::RollOver),
+            NaiveDate::from_ymd_opt(2020, 3, 2).unwrap()
+        );
+        assert_eq!(
+            interval(1, 1, 0, false).apply_to(date, EomPolicy::Clamp),
+            NaiveDate::from_ymd_opt(2018, 12, 31).unwrap()
+        );
+        assert_eq!(
+            interval(0, 2, 0, true).apply_to(date, EomPolicy::Error),
+            NaiveDate::from_ymd_opt(2020, 3, 31).unwrap()
+        );
+
+        let (start, end) = (
+            NaiveDate::from_ymd_opt(1947, 8, 15).unwrap(),
+            NaiveDate::from_ymd_opt(1950, 1, 26).unwrap(),
+        );
+        assert_eq!(
+            get_diff(&start, &end).apply_to(start, EomPolicy::Clamp),
+            end
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn apply_to_missing_day() {
+        Interval::new(0, 1, 0, true).unwrap().apply_to(
+            NaiveDate::from_ymd_opt(2020, 1, 31).unwrap(),
+            EomPolicy::Error,
+        );
+    }
+
+    #[test]
+    fn checked_saturating_apply_to() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        assert_eq!(
+            interval(0, 0, 1, true).checked_apply_to(NaiveDate::MAX, EomPolicy::Clamp),
+            None
+        );
+        assert_eq!(
+            interval(0, 0, 1, false).checked_apply_to(NaiveDate::MAX, EomPolicy::Clamp),
+            NaiveDate::MAX.pred_opt()
+        );
+        assert_eq!(
+            interval(5000, 0, 0, false).checked_apply_to(NaiveDate::MIN, EomPolicy::RollOver),
+            None
+        );
+        assert_eq!(
+            interval(0, 0, 1, true).saturating_apply_to(NaiveDate::MAX, EomPolicy::Error),
+            NaiveDate::MAX
+        );
+        assert_eq!(
+            interval(5000, 0, 0, false).saturating_apply_to(NaiveDate::MIN, EomPolicy::Clamp),
+            NaiveDate::MIN
+        );
+        assert_eq!(
+            interval(0, 1, 0, true).saturating_apply_to(
+                NaiveDate::from_ymd_opt(2020, 1, 31).unwrap(),
+                EomPolicy::Clamp
+            ),
+            NaiveDate::from_ymd_opt(2020, 2, 29).unwrap()
+        );
+    }
+
+    #[test]
+    fn date_arithmetic() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+        let date = NaiveDate::from_ymd_opt(2020, 1, 31).unwrap();
+
+        assert_eq!(
+            date + interval(0, 1, 0, true),
+            NaiveDate::from_ymd_opt(2020, 2, 29).unwrap()
+        );
+        assert_eq!(
+            date - interval(0, 1, 0, true),
+            NaiveDate::from_ymd_opt(2019, 12, 31).unwrap()
+        );
+        assert_eq!(
+            date + interval(0, 0, 3, false),
+            NaiveDate::from_ymd_opt(2020, 1, 28).unwrap()
+        );
+        assert_eq!(
+            date - interval(0, 0, 3, false),
+            NaiveDate::from_ymd_opt(2020, 2, 3).unwrap()
+        );
+        assert_eq!(date + Interval::zero(), date);
+    }
+
+    #[test]
+    fn between() {
+        let (start, end) = (
+            NaiveDate::from_ymd_opt(3040, 3, 1).unwrap(),
+            NaiveDate::from_ymd_opt(1102, 1, 5).unwrap(),
+        );
+        assert_eq!(Interval::between(&start, &end), get_diff(&start, &end));
+        let interval: Interval = (start, end).into();
+        assert_eq!(interval, Interval::new(1938, 1, 25, false).unwrap());
+    }
+
+    #[test]
+    fn parts() {
+        let (start, end) = (
+            NaiveDate::from_ymd_opt(2020, 2, 5).unwrap(),
+            NaiveDate::from_ymd_opt(2013, 1, 1).unwrap(),
+        );
+        assert_eq!(get_diff(&start, &end).into_parts(), (7, 1, 4, false));
+        assert_eq!(
+            Interval::from_parts(get_diff(&start, &end).into_parts()),
+            Ok(get_diff(&start, &end))
+        );
+        assert_eq!(
+            Interval::from_parts((0, 0, 31, true)),
+            Err(IntervalError::DaysOutOfRange(31))
+        );
+    }
+
+    #[test]
+    fn weeks_days() {
+        let (start, end) = (
+            NaiveDate::from_ymd_opt(2020, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2020, 3, 1).unwrap(),
+        );
+        let weeks_days = get_diff_weeks(&start, &end);
+        assert_eq!((weeks_days.weeks(), weeks_days.days()), (8, 4));
+        assert!(weeks_days.positive());
+        assert_eq!(get_diff(&start, &end).to_weeks_days(start), weeks_days);
+
+        let behind = get_diff_weeks(&end, &start);
+        assert_eq!((behind.weeks(), behind.days()), (8, 4));
+        assert!(!behind.positive());
+        assert!(get_diff_weeks(&start, &start).positive());
+    }
+
+    #[test]
+    fn hash_and_eq_at() {
+        use std::collections::HashMap;
+
+        let (start, end) = (
+            NaiveDate::from_ymd_opt(2019, 2, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2019, 3, 1).unwrap(),
+        );
+        let mut counts = HashMap::new();
+        for interval in &[
+            get_diff(&start, &end),
+            get_diff(&start, &end),
+            Interval::zero(),
+        ] {
+            *counts.entry(*interval).or_insert(0) += 1;
+        }
+        assert_eq!(counts[&Interval::new(0, 1, 0, true).unwrap()], 2);
+
+        let month = get_diff(&start, &end);
+        let days = Interval::new(0, 0, 28, true).unwrap();
+        assert!(month.eq_at(&days, start));
+        assert!(!month.eq_at(&days, end));
+        assert!(!month.eq_at(&month.inverse(), start));
+    }
+
+    #[test]
+    fn shorthands() {
+        assert_eq!(Interval::of_years(2), Interval::new(2, 0, 0, true).unwrap());
+        assert_eq!(
+            Interval::of_months(6),
+            Interval::new(0, 6, 0, true).unwrap()
+        );
+        assert_eq!(
+            Interval::of_months(18),
+            Interval::new(1, 6, 0, true).unwrap()
+        );
+        assert_eq!(Interval::of_days(90).days(), 90);
+        assert_eq!(Interval::of_days(0), Interval::zero());
+        assert_eq!(
+            Interval::of_years(1) + Interval::of_months(2) + Interval::of_days(3),
+            Interval::new(1, 2, 3, true).unwrap()
+        );
+    }
+
+    #[test]
+    fn chrono_units() {
+        use chrono::{Days, Months};
+        use std::convert::TryFrom;
+
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        assert_eq!(interval(2, 3, 4, false).to_chrono_months(), Months::new(27));
+        assert_eq!(interval(2, 3, 4, false).to_chrono_days(), Days::new(4));
+        assert_eq!(
+            Months::try_from(interval(0, 0, 0, true)),
+            Ok(Months::new(0))
+        );
+        assert_eq!(
+            Months::try_from(interval(0, 2, 1, true)),
+            Err(IntervalError::NotWholeMonths(1))
+        );
+        assert_eq!(
+            Months::try_from(interval(0, 2, 0, false)),
+            Err(IntervalError::Negative)
+        );
+    }
+
+    #[test]
+    fn duration_approx() {
+        use chrono::Duration;
+
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        assert_eq!(
+            interval(0, 1, 0, true).to_duration_approx(MonthLengthAssumption::ThirtyDays),
+            Duration::days(30)
+        );
+        assert_eq!(
+            interval(0, 1, 0, false).to_duration_approx(MonthLengthAssumption::AverageYear),
+            -(Duration::days(30) + Duration::hours(10) + Duration::minutes(30))
+        );
+        assert_eq!(
+            interval(4, 0, 1, true).to_duration_approx(MonthLengthAssumption::ThirtyDays),
+            Duration::days(4 * 365 + 2)
+        );
+        assert_eq!(
+            Interval::zero().to_duration_approx(MonthLengthAssumption::AverageYear),
+            Duration::zero()
+        );
+    }
+
+    #[test]
+    fn sum() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+        let intervals = [
+            interval(1, 8, 20, true),
+            interval(0, 6, 15, true),
+            interval(0, 0, 10, false),
+        ];
+
+        assert_eq!(intervals.iter().sum::<Interval>(), interval(2, 2, 25, true));
+        assert_eq!(
+            Vec::<Interval>::new().into_iter().sum::<Interval>(),
+            Interval::zero()
+        );
+
+        // 2020-01-31 + 1y 8m 20d = 2021-10-20, + 6m 15d = 2022-05-05, - 10d = 2022-04-25
+        assert_eq!(
+            Interval::sum_at(
+                intervals.iter().copied(),
+                NaiveDate::from_ymd_opt(2020, 1, 31).unwrap()
+            ),
+            interval(2, 2, 25, true)
+        );
+        assert_eq!(
+            Interval::sum_at(vec![], NaiveDate::from_ymd_opt(2020, 1, 31).unwrap()),
+            Interval::zero()
+        );
+    }
+
+    #[test]
+    fn at_least() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        assert!(interval(18, 0, 0, true).at_least_years(18));
+        assert!(interval(18, 0, 0, true).at_least(17, 12, 0));
+        assert!(!interval(17, 11, 30, true).at_least_years(18));
+        assert!(interval(0, 1, 0, true).at_least(0, 0, 45));
+        assert!(Interval::zero().at_least(0, 0, 0));
+        assert!(!interval(0, 0, 1, false).at_least(0, 0, 0));
+        assert!(!interval(20, 0, 0, false).at_least_years(18));
+
+        let anchor = NaiveDate::from_ymd_opt(2021, 2, 1).unwrap();
+        assert!(interval(0, 0, 29, true).longer_than(&interval(0, 1, 0, true), anchor));
+        assert!(!interval(0, 0, 28, true).longer_than(&interval(0, 1, 0, true), anchor));
+        assert!(Interval::zero().longer_than(&interval(0, 0, 1, false), anchor));
+    }
+
+    #[test]
+    fn overflow() {
+        assert_eq!(Interval::MAX.signed_years(), i32::MAX);
+        assert_eq!(Interval::MIN.signed_years(), -i32::MAX);
+        assert_eq!(
+            Interval::new(Interval::MAX_YEARS, 11, 30, true),
+            Ok(Interval::MAX)
+        );
+        assert_eq!(
+            Interval::new(Interval::MAX_YEARS + 1, 0, 0, true),
+            Err(IntervalError::YearsOutOfRange(Interval::MAX_YEARS + 1))
+        );
+        assert_eq!(Interval::MAX.checked_add(Interval::of_months(1)), None);
+        assert_eq!(Interval::MIN.checked_sub(Interval::of_months(1)), None);
+        assert_eq!(
+            Interval::MAX.checked_add(Interval::of_days(1)),
+            Some(
+                Interval::builder()
+                    .years(Interval::MAX_YEARS)
+                    .months(11)
+                    .days(31)
+                    .build_unchecked()
+            )
+        );
+        assert_eq!(Interval::MAX.checked_mul(2), None);
+        assert_eq!(Interval::MAX.checked_mul(1), Some(Interval::MAX));
+        assert_eq!(Interval::MAX.checked_div(0), None);
+        assert_eq!(
+            Interval::of_days(i32::MAX as u32).checked_add(Interval::of_days(1)),
+            None
+        );
+        assert_eq!(
+            Interval::MAX.checked_apply_to(NaiveDate::MIN, EomPolicy::Clamp),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "interval overflow")]
+    fn add_overflow() {
+        let _ = Interval::MAX + Interval::of_years(1);
+    }
+
+    #[test]
+    fn component_limits() {
+        let days = Interval::of_days(i32::MAX as u32);
+        assert_eq!(days.signed_days(), i32::MAX);
+        assert_eq!(days.inverse().signed_days(), -i32::MAX);
+        let date = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+        assert_eq!(days.checked_apply_to(date, EomPolicy::Clamp), None);
+        assert_eq!(
+            days.inverse().checked_apply_to(date, EomPolicy::Clamp),
+            None
+        );
+
+        let years = Interval::of_years(Interval::MAX_YEARS);
+        assert_eq!(years.signed_years(), i32::MAX);
+        assert_eq!(years.inverse().signed(), (-i32::MAX, 0, 0));
+
+        let months = Interval::builder()
+            .months(i32::MAX as u32)
+            .build_unchecked();
+        assert_eq!(months.signed_months(), i32::MAX);
+
+        for build in [
+            || Interval::of_days(u32::MAX),
+            || Interval::of_days(i32::MAX as u32 + 1),
+            || Interval::of_years(u32::MAX),
+            || Interval::builder().months(u32::MAX).build_unchecked(),
+        ] {
+            assert!(std::panic::catch_unwind(build).is_err());
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "interval overflow")]
+    fn days_overflow() {
+        let _ = Interval::of_days(u32::MAX);
+    }
+
+    #[test]
+    fn extremes() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let (min, max) = (NaiveDate::MIN, NaiveDate::MAX);
+
+        assert_eq!(total_days_in_month(max.year(), 12), 31);
+        assert_eq!(total_days_in_month(min.year(), 1), 31);
+
+        let cases = [
+            (min, max, (524285, 11, 30)),
+            (max, min, (-524285, -11, -30)),
+            (min, min, (0, 0, 0)),
+            (max, max, (0, 0, 0)),
+            (date(max.year(), 1, 31), max, (0, 11, 0)),
+            (min, date(min.year(), 12, 31), (0, 11, 30)),
+            // year 0 is 1 BCE and a leap year
+            (date(-1, 3, 1), date(1, 3, 1), (2, 0, 0)),
+            (date(0, 2, 29), date(1, 2, 28), (0, 11, 30)),
+            (date(-44, 3, 15), date(-43, 3, 14), (0, 11, 27)),
+            (date(-1, 12, 31), date(1, 1, 1), (1, 0, 1)),
+        ];
+        for &(start, end, expected) in &cases {
+            assert_eq!(
+                get_diff_extended(&start, &end),
+                expected,
+                "{} {}",
+                start,
+                end
+            );
+            let interval = get_diff(&start, &end);
+            assert_eq!(
+                interval.signed(),
+                (expected.0 as i32, expected.1 as i32, expected.2 as i32)
+            );
+            assert_eq!(
+                interval.apply_to(start, EomPolicy::Clamp),
+                end,
+                "{} {}",
+                start,
+                end
+            );
+        }
+
+        assert_eq!(
+            diff_in(&min, &max, Unit::Day),
+            max.signed_duration_since(min).num_days()
+        );
+        assert_eq!(diff_in(&max, &min, Unit::Month), -(524285 * 12 + 11));
     }
 }
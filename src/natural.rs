@@ -0,0 +1,163 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use crate::{get_diff, Clock, EomPolicy, Interval};
+
+/// Reads a date relative to today on ```clock``` from an expression like
+/// people write them:
+///
+/// - ```today```, ```yesterday```, ```tomorrow```
+/// - ```next friday``` and ```last mon```, the closest such weekday after or
+///   before today, never today itself
+/// - an interval for ```Interval::parse_loose``` followed by ```ago``` or
+///   ```from now```, or after ```in```, like ```3 weeks ago```,
+///   ```in 2 months``` or ```a year and 3 days from now```
+/// - a ```YYYY-MM-DD``` date
+///
+/// Words may be in any case. Intervals are applied with
+/// ```EomPolicy::Clamp```. ```None``` for anything else, or a date outside
+/// of chrono's range.
+///
+/// Needs the ```natural``` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "natural")]
+/// # {
+/// use chrono::NaiveDate;
+///
+/// use datediff::{parse_natural, FixedClock};
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+/// //a Sunday
+/// let clock = FixedClock::on(date(2021, 3, 14));
+///
+/// # assert_eq!(parse_natural("yesterday", &clock), Some(date(2021, 3, 13)));
+/// # assert_eq!(parse_natural("next friday", &clock), Some(date(2021, 3, 19)));
+/// # assert_eq!(parse_natural("3 weeks ago", &clock), Some(date(2021, 2, 21)));
+/// # assert_eq!(parse_natural("the day after", &clock), None);
+/// # }
+/// ```
+pub fn parse_natural<C: Clock + ?Sized>(s: &str, clock: &C) -> Option<NaiveDate> {
+    let today = clock.today();
+    let text = s.trim().to_lowercase();
+    let words: Vec<_> = text.split_whitespace().collect();
+    match words.as_slice() {
+        ["today"] | ["now"] => return Some(today),
+        ["yesterday"] => return today.pred_opt(),
+        ["tomorrow"] => return today.succ_opt(),
+        [direction @ ("next" | "last"), weekday] => {
+            let weekday: Weekday = weekday.parse().ok()?;
+            let ahead = i64::from(
+                (7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7,
+            );
+            let days = match (*direction, ahead) {
+                ("next", 0) => 7,
+                ("next", ahead) => ahead,
+                (_, 0) => -7,
+                (_, ahead) => ahead - 7,
+            };
+            return today.checked_add_signed(Duration::days(days));
+        }
+        _ => {}
+    }
+
+    let (interval, forward) = if let Some(rest) = text.strip_suffix("ago") {
+        (rest, false)
+    } else if let Some(rest) = text.strip_suffix("from now") {
+        (rest, true)
+    } else if let Some(rest) = text.strip_prefix("in ") {
+        (rest, true)
+    } else {
+        return NaiveDate::parse_from_str(&text, "%Y-%m-%d").ok();
+    };
+    let interval = Interval::parse_loose(interval).ok()?;
+    let interval = if forward {
+        interval
+    } else {
+        interval.inverse()
+    };
+    interval.checked_apply_to(today, EomPolicy::Clamp)
+}
+
+/// Difference between two dates given as expressions for
+/// ```parse_natural```, like ```get_diff```. ```None``` when either isn't
+/// understood.
+///
+/// Needs the ```natural``` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "natural")]
+/// # {
+/// use chrono::NaiveDate;
+///
+/// use datediff::{get_diff_natural, FixedClock, Interval};
+///
+/// let clock = FixedClock::on(NaiveDate::from_ymd_opt(2021, 3, 14).unwrap());
+///
+/// # assert_eq!(get_diff_natural("2020-12-25", "today", &clock), Some(Interval::new(0, 2, 17, true).unwrap()));
+/// # assert_eq!(get_diff_natural("last friday", "next friday", &clock), Some(Interval::of_days(7)));
+/// # }
+/// ```
+pub fn get_diff_natural<C: Clock + ?Sized>(start: &str, end: &str, clock: &C) -> Option<Interval> {
+    Some(get_diff(
+        &parse_natural(start, clock)?,
+        &parse_natural(end, clock)?,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use crate::{get_diff_natural, parse_natural, FixedClock, Interval};
+
+    #[test]
+    fn natural() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        // a Wednesday
+        let clock = FixedClock::on(date(2021, 3, 31));
+        for &(text, expected) in &[
+            ("today", date(2021, 3, 31)),
+            (" Now ", date(2021, 3, 31)),
+            ("yesterday", date(2021, 3, 30)),
+            ("TOMORROW", date(2021, 4, 1)),
+            ("next friday", date(2021, 4, 2)),
+            ("next wednesday", date(2021, 4, 7)),
+            ("next Tue", date(2021, 4, 6)),
+            ("last friday", date(2021, 3, 26)),
+            ("last wed", date(2021, 3, 24)),
+            ("last thursday", date(2021, 3, 25)),
+            ("3 weeks ago", date(2021, 3, 10)),
+            ("a month ago", date(2021, 2, 28)),
+            ("in 2 months", date(2021, 5, 31)),
+            ("1 year and 2 days from now", date(2022, 4, 2)),
+            ("2020-02-29", date(2020, 2, 29)),
+        ] {
+            assert_eq!(parse_natural(text, &clock), Some(expected), "{}", text);
+        }
+        for &text in &[
+            "",
+            "next",
+            "next week",
+            "someday",
+            "3 fortnights ago",
+            "in",
+            "ago",
+        ] {
+            assert_eq!(parse_natural(text, &clock), None, "{}", text);
+        }
+        assert_eq!(
+            parse_natural("tomorrow", &FixedClock::on(NaiveDate::MAX)),
+            None
+        );
+
+        assert_eq!(
+            get_diff_natural("today", "3 weeks ago", &clock),
+            Some(Interval::of_days(21).inverse())
+        );
+        assert_eq!(get_diff_natural("today", "wat", &clock), None);
+    }
+}
@@ -0,0 +1,581 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::{
+    days_in_month, diff_in, fortnights_between, get_diff_by, week_of_year, weeks_between,
+    DiffAlgorithm, Interval, RoundingMode, Unit,
+};
+
+/// Where the anniversary of Feb 29 falls in years without one, when the
+/// earlier date is Feb 29 and the later one is in a common year.
+///
+/// Only the end dates around the anniversary are affected, so Feb 29 to
+/// Feb 27 is 11 months 29 days and to Mar 29 is 1 year 1 month with every
+/// policy. The sign doesn't matter, 2021-02-28 to 2020-02-29 is 1 year
+/// behind with ```LeapDayPolicy::Feb28```.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum LeapDayPolicy {
+    /// No special treatment, whatever the algorithm gives. For
+    /// ```get_diff``` Feb 28 is short of the 29th and borrows the 31 days of
+    /// January, so 2020-02-29 to 2021-02-28 is 11 months 30 days. In March
+    /// the 28 days of February are borrowed and the start day is clamped to
+    /// the 28th, so Mar 1 is 1 year 1 day and Mar 2 is 1 year 2 days.
+    #[default]
+    Calendar,
+    /// The anniversary is on Feb 28, which becomes whole years. March dates
+    /// are counted from Feb 28 like ```get_diff``` already does, so Mar 1 is
+    /// 1 year 1 day.
+    Feb28,
+    /// The anniversary is on Mar 1, which becomes whole years. Feb 28 stays
+    /// 11 months 30 days and the days of March before the 29th are counted
+    /// from Mar 1, so Mar 2 is 1 year 1 day. From the 29th on, months are
+    /// counted from the 29th again.
+    Mar1,
+}
+
+/// Settings for ```get_diff_with```. The defaults give the same result as
+/// ```get_diff```.
+///
+/// # Example
+///
+/// ```
+/// use datediff::{DiffAlgorithm, DiffOptions, RoundingMode, Unit};
+///
+/// let options = DiffOptions::new()
+///     .with_algorithm(DiffAlgorithm::PostgresAge)
+///     .with_inclusive_end(true)
+///     .with_rounding(Unit::Month, RoundingMode::HalfUp);
+/// # assert_eq!(options.algorithm(), DiffAlgorithm::PostgresAge);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DiffOptions {
+    algorithm: DiffAlgorithm,
+    inclusive_end: bool,
+    symmetric: bool,
+    month_end: bool,
+    leap_day: LeapDayPolicy,
+    rounding: Option<(Unit, RoundingMode)>,
+    week_start: Weekday,
+}
+
+impl Default for DiffOptions {
+    fn default() -> DiffOptions {
+        DiffOptions {
+            algorithm: DiffAlgorithm::Default,
+            inclusive_end: false,
+            symmetric: false,
+            month_end: false,
+            leap_day: LeapDayPolicy::Calendar,
+            rounding: None,
+            week_start: Weekday::Sun,
+        }
+    }
+}
+
+impl DiffOptions {
+    pub fn new() -> DiffOptions {
+        DiffOptions::default()
+    }
+
+    /// How the difference is broken down, see ```DiffAlgorithm```.
+    pub fn with_algorithm(mut self, algorithm: DiffAlgorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+    /// Counts both dates, as legal and HR rules often do, so employment from
+    /// Jan 1 to Jan 31 is 1 month and from Jan 1 to Dec 31 is 1 year. The
+    /// later date is moved one day forward before the difference is broken
+    /// down, whichever of the dates comes first, so the same date twice is
+    /// 1 day. ```get_diff_with``` panics when the later date is
+    /// ```NaiveDate::MAX```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::{get_diff_with, DiffOptions, Interval};
+    ///
+    /// let hired = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+    /// let left = NaiveDate::from_ymd_opt(2021, 12, 31).unwrap();
+    ///
+    /// let options = DiffOptions::new().with_inclusive_end(true);
+    /// # assert_eq!(get_diff_with(&hired, &left, &options), Interval::of_years(1));
+    /// # assert_eq!(get_diff_with(&hired, &hired, &options), Interval::of_days(1));
+    /// ```
+    pub fn with_inclusive_end(mut self, inclusive_end: bool) -> Self {
+        self.inclusive_end = inclusive_end;
+        self
+    }
+    /// Always breaks the difference down from the earlier date to the later
+    /// one and applies the sign afterwards, so swapping the dates only flips
+    /// the sign whatever the algorithm. A negative ```DiffAlgorithm::JavaPeriod```
+    /// then no longer matches Java, since Java counts back from the start
+    /// date.
+    pub fn with_symmetric(mut self, symmetric: bool) -> Self {
+        self.symmetric = symmetric;
+        self
+    }
+    /// Treats two last days of a month as whole months apart, like
+    /// accountants and Oracle's ```MONTHS_BETWEEN``` do, so Jan 31 to Feb 28
+    /// is 1 month rather than 28 days and Feb 28 to Mar 31 is 1 month rather
+    /// than 1 month 3 days. Other pairs are left to the algorithm.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::{get_diff_with, DiffOptions, Interval};
+    ///
+    /// let start = NaiveDate::from_ymd_opt(2021, 1, 31).unwrap();
+    /// let end = NaiveDate::from_ymd_opt(2021, 2, 28).unwrap();
+    ///
+    /// let options = DiffOptions::new().with_month_end(true);
+    /// # assert_eq!(get_diff_with(&start, &end, &options), Interval::of_months(1));
+    /// # assert_eq!(get_diff_with(&start, &end, &DiffOptions::new()), Interval::of_days(28));
+    /// ```
+    pub fn with_month_end(mut self, month_end: bool) -> Self {
+        self.month_end = month_end;
+        self
+    }
+    /// Anniversary of a Feb 29 start in common years, see ```LeapDayPolicy```.
+    pub fn with_leap_day(mut self, leap_day: LeapDayPolicy) -> Self {
+        self.leap_day = leap_day;
+        self
+    }
+    /// Caps the precision of the result at ```unit```, rounding what's below
+    /// with ```Interval::round_to```, e.g. years only for an age of "42
+    /// years". ```RoundingMode::Truncate``` drops it instead, see
+    /// ```DiffOptions::truncate_to```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::{get_diff_with, DiffOptions, Interval, RoundingMode, Unit};
+    ///
+    /// let birth = NaiveDate::from_ymd_opt(1978, 3, 2).unwrap();
+    /// let today = NaiveDate::from_ymd_opt(2020, 11, 20).unwrap();
+    ///
+    /// let options = DiffOptions::new().with_rounding(Unit::Year, RoundingMode::HalfUp);
+    /// println!("{} years old", get_diff_with(&birth, &today, &options).years());
+    /// # assert_eq!(get_diff_with(&birth, &today, &options), Interval::of_years(43));
+    /// # assert_eq!(get_diff_with(&birth, &today, &DiffOptions::new().truncate_to(Unit::Year)), Interval::of_years(42));
+    /// ```
+    pub fn with_rounding(mut self, unit: Unit, mode: RoundingMode) -> Self {
+        self.rounding = Some((unit, mode));
+        self
+    }
+    /// Caps the precision of the result at ```unit``` by dropping what's
+    /// below, same as ```with_rounding(unit, RoundingMode::Truncate)```.
+    pub fn truncate_to(self, unit: Unit) -> Self {
+        self.with_rounding(unit, RoundingMode::Truncate)
+    }
+    /// Gives the full precision again, the default.
+    pub fn full_precision(mut self) -> Self {
+        self.rounding = None;
+        self
+    }
+    /// First day of the week for ```DiffOptions::weeks_between```,
+    /// ```DiffOptions::week_of_year``` and ```diff_in_with```, Sunday by
+    /// default like ```diff_in```.
+    pub fn with_week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    pub fn algorithm(&self) -> DiffAlgorithm {
+        self.algorithm
+    }
+    pub fn inclusive_end(&self) -> bool {
+        self.inclusive_end
+    }
+    pub fn symmetric(&self) -> bool {
+        self.symmetric
+    }
+    pub fn month_end(&self) -> bool {
+        self.month_end
+    }
+    pub fn leap_day(&self) -> LeapDayPolicy {
+        self.leap_day
+    }
+    pub fn rounding(&self) -> Option<(Unit, RoundingMode)> {
+        self.rounding
+    }
+    pub fn week_start(&self) -> Weekday {
+        self.week_start
+    }
+
+    /// Same as ```weeks_between``` with the configured week start.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{NaiveDate, Weekday};
+    ///
+    /// use datediff::DiffOptions;
+    ///
+    /// let options = DiffOptions::new().with_week_start(Weekday::Mon);
+    /// let sunday = NaiveDate::from_ymd_opt(2021, 1, 3).unwrap();
+    /// let monday = NaiveDate::from_ymd_opt(2021, 1, 4).unwrap();
+    ///
+    /// # assert_eq!(options.weeks_between(&sunday, &monday), 1);
+    /// # assert_eq!(options.week_of_year(&monday), 2);
+    /// # assert_eq!(DiffOptions::new().weeks_between(&sunday, &monday), 0);
+    /// ```
+    pub fn weeks_between(&self, start: &NaiveDate, end: &NaiveDate) -> i64 {
+        weeks_between(start, end, self.week_start)
+    }
+
+    /// Same as ```week_of_year``` with the configured week start.
+    pub fn week_of_year(&self, date: &NaiveDate) -> u32 {
+        week_of_year(date, self.week_start)
+    }
+
+    /// Tells whether ```get_diff_with(a, b, self)``` is always the inverse of
+    /// ```get_diff_with(b, a, self)```, either because the algorithm is
+    /// symmetric or because ```DiffOptions::with_symmetric``` is set. The other
+    /// options keep the property.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::{DiffAlgorithm, DiffOptions};
+    ///
+    /// let java = DiffOptions::new().with_algorithm(DiffAlgorithm::JavaPeriod);
+    /// # assert!(!java.is_symmetric());
+    /// # assert!(java.with_symmetric(true).is_symmetric());
+    /// # assert!(DiffOptions::new().is_symmetric());
+    /// ```
+    pub fn is_symmetric(&self) -> bool {
+        self.symmetric || self.algorithm.is_symmetric()
+    }
+}
+
+/// Difference from a Feb 29 ```start``` to a later ```end``` where
+/// ```policy``` moves the anniversary, ```None``` for every other pair.
+fn diff_from_leap_day(
+    start: &NaiveDate,
+    end: &NaiveDate,
+    policy: LeapDayPolicy,
+) -> Option<Interval> {
+    let common_year = NaiveDate::from_ymd_opt(end.year(), 2, 29).is_none();
+    if start.month() != 2 || start.day() != 29 || !common_year || end.year() <= start.year() {
+        return None;
+    }
+    let years = i64::from(end.year() - start.year());
+    match (policy, end.month(), end.day()) {
+        (LeapDayPolicy::Feb28, 2, 28) => Some(Interval::from_months_days(years * 12, 0)),
+        //days of March before the 29th count from Mar 1 instead of Feb 28
+        (LeapDayPolicy::Mar1, 3, day) if day < 29 => {
+            Some(Interval::from_months_days(years * 12, i64::from(day) - 1))
+        }
+        _ => None,
+    }
+}
+
+fn is_month_end(date: &NaiveDate) -> bool {
+    date.day() == days_in_month(date.year(), date.month())
+}
+
+/// Takes two ```chrono::NaiveDate``` and gives the difference as Interval,
+/// computed as set in ```options```.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::{get_diff_with, DiffOptions, Interval, LeapDayPolicy};
+///
+/// let start_date = NaiveDate::from_ymd_opt(2020, 2, 29).unwrap();
+/// let end_date = NaiveDate::from_ymd_opt(2021, 2, 28).unwrap();
+///
+/// let options = DiffOptions::new().with_leap_day(LeapDayPolicy::Feb28);
+/// println!("Duration is {}", get_diff_with(&start_date, &end_date, &options));
+/// # assert_eq!(get_diff_with(&start_date, &end_date, &options), Interval::of_years(1));
+/// # assert_eq!(get_diff_with(&start_date, &end_date, &DiffOptions::new()), Interval::new(0, 11, 30, true).unwrap());
+/// ```
+pub fn get_diff_with(start: &NaiveDate, end: &NaiveDate, options: &DiffOptions) -> Interval {
+    let positive = start <= end;
+    let (earlier, mut later) = if positive {
+        (*start, *end)
+    } else {
+        (*end, *start)
+    };
+    if options.inclusive_end {
+        later = later.succ_opt().expect("date out of range");
+    }
+    let month_ends = options.month_end && is_month_end(&earlier) && is_month_end(&later);
+    let interval = match diff_from_leap_day(&earlier, &later, options.leap_day) {
+        _ if month_ends => Interval::from_months_days(
+            (i64::from(later.year()) - i64::from(earlier.year())) * 12 + i64::from(later.month())
+                - i64::from(earlier.month()),
+            0,
+        ),
+        Some(interval) => interval,
+        None if positive || options.symmetric => get_diff_by(&earlier, &later, options.algorithm),
+        None => get_diff_by(&later, &earlier, options.algorithm),
+    }
+    .with_sign(positive);
+    match options.rounding {
+        Some((unit, mode)) => interval.round_to(unit, mode),
+        None => interval,
+    }
+}
+
+/// Same as ```diff_in```, but weeks start on ```DiffOptions::with_week_start```.
+/// Fortnights start on every other such day from its first one in 0001,
+/// which is 0001-01-07 for Sunday as in ```diff_in```.
+pub fn diff_in_with(start: &NaiveDate, end: &NaiveDate, unit: Unit, options: &DiffOptions) -> i64 {
+    match unit {
+        Unit::Week => options.weeks_between(start, end),
+        Unit::Fortnight => {
+            //0001-01-01 was a Monday
+            let day = 1 + options.week_start.num_days_from_monday();
+            fortnights_between(start, end, &NaiveDate::from_ymd_opt(1, 1, day).unwrap())
+        }
+        _ => diff_in(start, end, unit),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Datelike, NaiveDate, Weekday};
+
+    use crate::{
+        diff_in, diff_in_with, get_diff, get_diff_by, get_diff_with, DiffAlgorithm, DiffOptions,
+        Interval, LeapDayPolicy, RoundingMode, Unit,
+    };
+
+    #[test]
+    fn options() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+        let (a, b) = (date(1957, 6, 13), date(2001, 4, 10));
+
+        assert_eq!(get_diff_with(&a, &b, &DiffOptions::new()), get_diff(&a, &b));
+        assert_eq!(
+            get_diff_with(&b, &a, &DiffOptions::default()),
+            get_diff(&b, &a)
+        );
+
+        let postgres = DiffOptions::new().with_algorithm(DiffAlgorithm::PostgresAge);
+        assert_eq!(
+            get_diff_with(&a, &b, &postgres),
+            get_diff_by(&a, &b, DiffAlgorithm::PostgresAge)
+        );
+
+        let inclusive = DiffOptions::new().with_inclusive_end(true);
+        let inclusive_cases = [
+            (date(2021, 1, 1), date(2021, 1, 31), interval(0, 1, 0, true)),
+            (date(2021, 2, 1), date(2021, 2, 28), interval(0, 1, 0, true)),
+            (
+                date(2020, 2, 1),
+                date(2020, 2, 28),
+                interval(0, 0, 28, true),
+            ),
+            (
+                date(2021, 1, 1),
+                date(2021, 12, 31),
+                interval(1, 0, 0, true),
+            ),
+            (
+                date(2021, 3, 15),
+                date(2021, 3, 15),
+                interval(0, 0, 1, true),
+            ),
+            (
+                date(2021, 1, 31),
+                date(2021, 2, 27),
+                interval(0, 0, 28, true),
+            ),
+            (
+                date(2021, 1, 31),
+                date(2021, 2, 28),
+                interval(0, 1, 1, true),
+            ),
+        ];
+        for (start, end, expected) in inclusive_cases.iter() {
+            assert_eq!(get_diff_with(start, end, &inclusive), *expected);
+            if start != end {
+                assert_eq!(get_diff_with(end, start, &inclusive), expected.inverse());
+            }
+        }
+        assert_eq!(
+            get_diff_with(&date(2021, 1, 1), &date(2021, 1, 31), &inclusive),
+            Interval::of_months(1)
+        );
+        assert_eq!(
+            get_diff_with(&date(2021, 1, 31), &date(2021, 1, 1), &inclusive),
+            interval(0, 1, 0, false)
+        );
+
+        let java = DiffOptions::new().with_algorithm(DiffAlgorithm::JavaPeriod);
+        let (c, d) = (date(2019, 1, 15), date(2019, 3, 10));
+        assert_eq!(get_diff_with(&d, &c, &java), interval(0, 1, 26, false));
+        assert_eq!(
+            get_diff_with(&d, &c, &java.with_symmetric(true)),
+            interval(0, 1, 23, false)
+        );
+
+        let rounded = DiffOptions::new().with_rounding(Unit::Year, RoundingMode::HalfUp);
+        assert_eq!(get_diff_with(&a, &b, &rounded), Interval::of_years(44));
+        assert_eq!(
+            get_diff_with(&b, &a, &rounded.truncate_to(Unit::Year)),
+            Interval::of_years(43).inverse()
+        );
+        assert_eq!(
+            get_diff_with(&a, &b, &rounded.truncate_to(Unit::Month)),
+            interval(43, 9, 0, true)
+        );
+        assert_eq!(rounded.full_precision(), DiffOptions::new());
+    }
+
+    #[test]
+    fn week_start() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let (friday, saturday) = (date(2021, 1, 8), date(2021, 1, 9));
+
+        let saturdays = DiffOptions::new().with_week_start(Weekday::Sat);
+        assert_eq!(diff_in_with(&friday, &saturday, Unit::Week, &saturdays), 1);
+        assert_eq!(diff_in_with(&saturday, &friday, Unit::Week, &saturdays), -1);
+        assert_eq!(
+            diff_in_with(&friday, &saturday, Unit::Week, &DiffOptions::new()),
+            diff_in(&friday, &saturday, Unit::Week)
+        );
+        assert_eq!(
+            diff_in_with(&friday, &date(2022, 1, 1), Unit::Year, &saturdays),
+            1
+        );
+        assert_eq!(saturdays.week_of_year(&saturday), 3);
+
+        let mondays = DiffOptions::new().with_week_start(Weekday::Mon);
+        let days: Vec<_> = date(2021, 1, 1).iter_days().take(60).collect();
+        let boundaries = |options: &DiffOptions| -> Vec<NaiveDate> {
+            days.iter()
+                .copied()
+                .filter(|day| {
+                    diff_in_with(&day.pred_opt().unwrap(), day, Unit::Fortnight, options) == 1
+                })
+                .collect()
+        };
+        for boundaries in [boundaries(&mondays), boundaries(&saturdays)] {
+            assert!(boundaries.len() >= 4);
+            assert!(boundaries
+                .windows(2)
+                .all(|pair| pair[1].signed_duration_since(pair[0]).num_days() == 14));
+        }
+        assert!(boundaries(&mondays)
+            .iter()
+            .all(|day| day.weekday() == Weekday::Mon));
+        assert!(boundaries(&saturdays)
+            .iter()
+            .all(|day| day.weekday() == Weekday::Sat));
+        for day in &days {
+            assert_eq!(
+                diff_in_with(&days[0], day, Unit::Fortnight, &DiffOptions::new()),
+                diff_in(&days[0], day, Unit::Fortnight),
+                "{}",
+                day
+            );
+        }
+    }
+
+    #[test]
+    fn month_end() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let interval = |y, m, d| Interval::new(y, m, d, true).unwrap();
+        let month_end = DiffOptions::new().with_month_end(true);
+
+        let cases = [
+            (date(2021, 1, 31), date(2021, 2, 28), interval(0, 1, 0)),
+            (date(2021, 2, 28), date(2021, 3, 31), interval(0, 1, 0)),
+            (date(2020, 2, 29), date(2021, 2, 28), interval(1, 0, 0)),
+            (date(2020, 4, 30), date(2021, 5, 31), interval(1, 1, 0)),
+            (date(2020, 12, 31), date(2020, 12, 31), Interval::zero()),
+            // not both at the end of the month
+            (date(2021, 1, 30), date(2021, 2, 28), interval(0, 0, 29)),
+            (date(2020, 2, 28), date(2020, 3, 31), interval(0, 1, 3)),
+        ];
+        for (start, end, expected) in cases.iter() {
+            assert_eq!(get_diff_with(start, end, &month_end), *expected);
+            assert_eq!(get_diff_with(end, start, &month_end), expected.inverse());
+        }
+    }
+
+    #[test]
+    fn leap_day() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let interval = |y, m, d| Interval::new(y, m, d, true).unwrap();
+        let leap_day = date(2020, 2, 29);
+        let feb28 = DiffOptions::new().with_leap_day(LeapDayPolicy::Feb28);
+        let mar1 = DiffOptions::new().with_leap_day(LeapDayPolicy::Mar1);
+
+        let cases = [
+            // end, Calendar, Feb28, Mar1
+            (date(2021, 2, 27), (0, 11, 29), (0, 11, 29), (0, 11, 29)),
+            (date(2021, 2, 28), (0, 11, 30), (1, 0, 0), (0, 11, 30)),
+            (date(2021, 3, 1), (1, 0, 1), (1, 0, 1), (1, 0, 0)),
+            (date(2021, 3, 2), (1, 0, 2), (1, 0, 2), (1, 0, 1)),
+            (date(2021, 3, 29), (1, 1, 0), (1, 1, 0), (1, 1, 0)),
+            (date(2023, 3, 1), (3, 0, 1), (3, 0, 1), (3, 0, 0)),
+            (date(2024, 2, 29), (4, 0, 0), (4, 0, 0), (4, 0, 0)),
+            (date(2024, 2, 28), (3, 11, 30), (3, 11, 30), (3, 11, 30)),
+        ];
+        for (end, calendar, on_feb28, on_mar1) in cases.iter() {
+            let expected = |(y, m, d)| interval(y, m, d);
+            assert_eq!(
+                get_diff_with(&leap_day, end, &DiffOptions::new()),
+                expected(*calendar),
+                "{}",
+                end
+            );
+            assert_eq!(
+                get_diff_with(&leap_day, end, &feb28),
+                expected(*on_feb28),
+                "{}",
+                end
+            );
+            assert_eq!(
+                get_diff_with(&leap_day, end, &mar1),
+                expected(*on_mar1),
+                "{}",
+                end
+            );
+        }
+        assert_eq!(
+            get_diff_with(&date(2021, 2, 28), &leap_day, &feb28),
+            Interval::of_years(1).inverse()
+        );
+        assert_eq!(
+            get_diff_with(&date(2022, 3, 1), &leap_day, &mar1),
+            Interval::of_years(2).inverse()
+        );
+        // only a Feb 29 start is moved
+        assert_eq!(
+            get_diff_with(&date(2020, 2, 28), &date(2021, 2, 28), &mar1),
+            Interval::of_years(1)
+        );
+        assert_eq!(
+            get_diff_with(&date(2021, 2, 28), &date(2022, 2, 28), &feb28),
+            Interval::of_years(1)
+        );
+        // the policy applies whatever the algorithm
+        let java = feb28.with_algorithm(DiffAlgorithm::JavaPeriod);
+        assert_eq!(
+            get_diff_with(&date(2021, 2, 28), &leap_day, &java),
+            Interval::of_years(1).inverse()
+        );
+        assert_eq!(
+            get_diff_with(&leap_day, &date(2021, 2, 27), &java),
+            interval(0, 11, 29)
+        );
+    }
+}
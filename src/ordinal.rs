@@ -0,0 +1,164 @@
+use chrono::{Datelike, NaiveDate};
+
+use crate::total_days_in_month;
+
+/// Year and day of year of ```date```, e.g. ```(2020, 61)``` for 2020-03-01.
+pub fn to_ordinal(date: &NaiveDate) -> (i32, u32) {
+    (date.year(), date.ordinal())
+}
+
+/// Date from a year and day of year, ```None``` when the year doesn't have
+/// that many days, e.g. day 366 of a common year.
+pub fn from_ordinal(year: i32, ordinal: u32) -> Option<NaiveDate> {
+    NaiveDate::from_yo_opt(year, ordinal)
+}
+
+/// Formats ```date``` as an ordinal date, ```yyyy-ddd```.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::format_ordinal;
+///
+/// let date = NaiveDate::from_ymd_opt(2020, 3, 1).unwrap();
+///
+/// println!("{}", format_ordinal(&date));
+/// # assert_eq!(format_ordinal(&date), "2020-061");
+/// ```
+pub fn format_ordinal(date: &NaiveDate) -> String {
+    format!("{:04}-{:03}", date.year(), date.ordinal())
+}
+
+/// Parses an ordinal date, ```yyyy-ddd``` or ```yyyyddd```. Gives ```None```
+/// for anything else, or a day the year doesn't have.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::parse_ordinal;
+///
+/// let date = parse_ordinal("2021-060");
+///
+/// # assert_eq!(date, NaiveDate::from_ymd_opt(2021, 3, 1));
+/// # assert_eq!(parse_ordinal("2020366"), NaiveDate::from_ymd_opt(2020, 12, 31));
+/// # assert_eq!(parse_ordinal("2021-366"), None);
+/// ```
+pub fn parse_ordinal(s: &str) -> Option<NaiveDate> {
+    let s = s.trim();
+    let (year, ordinal) = match s.find('-') {
+        Some(4) => (&s[..4], &s[5..]),
+        Some(_) => return None,
+        None if s.len() == 7 && s.is_char_boundary(4) => s.split_at(4),
+        None => return None,
+    };
+    if ordinal.len() != 3 || !(year.bytes().chain(ordinal.bytes())).all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    from_ordinal(year.parse().ok()?, ordinal.parse().ok()?)
+}
+
+/// Takes two dates and gives how many days ```end``` falls after ```start```
+/// within a year, ignoring the years themselves, e.g. for comparing the
+/// timing of seasonal events. Unlike subtracting days of year, the same
+/// calendar day gives 0 even when only one of the years is a leap year.
+/// Both days are counted within a common year, Feb 29 as Feb 28, unless
+/// both years are leap years, so swapping the dates only flips the sign.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::day_of_year_diff;
+///
+/// let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+///
+/// println!("{} days later", day_of_year_diff(&date(2020, 3, 1), &date(2021, 3, 11)));
+/// # assert_eq!(day_of_year_diff(&date(2020, 3, 1), &date(2021, 3, 11)), 10);
+/// # assert_eq!(day_of_year_diff(&date(2021, 3, 1), &date(1900, 2, 20)), -9);
+/// ```
+pub fn day_of_year_diff(start: &NaiveDate, end: &NaiveDate) -> i64 {
+    let is_leap = |date: &NaiveDate| NaiveDate::from_ymd_opt(date.year(), 2, 29).is_some();
+    let year = if is_leap(start) && is_leap(end) {
+        2000
+    } else {
+        2001
+    };
+    let place = |date: &NaiveDate| {
+        let day = date.day().min(total_days_in_month(year, date.month()));
+        NaiveDate::from_ymd_opt(year, date.month(), day).unwrap()
+    };
+    place(end).signed_duration_since(place(start)).num_days()
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use crate::{day_of_year_diff, format_ordinal, from_ordinal, parse_ordinal, to_ordinal};
+
+    #[test]
+    fn ordinal() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        for &(d, text) in &[
+            (date(2021, 1, 1), "2021-001"),
+            (date(2021, 12, 31), "2021-365"),
+            (date(2020, 2, 29), "2020-060"),
+            (date(2020, 12, 31), "2020-366"),
+            (date(987, 7, 4), "0987-185"),
+        ] {
+            assert_eq!(format_ordinal(&d), text, "{}", d);
+            assert_eq!(parse_ordinal(text), Some(d), "{}", text);
+            let (year, day) = to_ordinal(&d);
+            assert_eq!(from_ordinal(year, day), Some(d), "{}", d);
+        }
+        assert_eq!(from_ordinal(2021, 366), None);
+        assert_eq!(from_ordinal(2021, 0), None);
+        for text in &[
+            "2021-1",
+            "2021-0010",
+            "21-001",
+            "2021-+01",
+            "2021/001",
+            "123é45",
+            "",
+        ] {
+            assert_eq!(parse_ordinal(text), None, "{}", text);
+        }
+
+        for &(start, end, expected) in &[
+            (date(2021, 3, 1), date(2021, 3, 1), 0),
+            (date(2021, 3, 1), date(2020, 3, 1), 0),
+            (date(2020, 12, 31), date(2021, 12, 31), 0),
+            (date(2021, 2, 28), date(2020, 3, 1), 1),
+            (date(2020, 2, 28), date(2021, 3, 1), 1),
+            (date(2020, 2, 28), date(2024, 3, 1), 2),
+            (date(2020, 2, 29), date(2021, 2, 28), 0),
+            (date(2020, 2, 29), date(2024, 2, 29), 0),
+            (date(2020, 2, 29), date(2021, 3, 1), 1),
+            (date(2021, 1, 1), date(2019, 12, 31), 364),
+            (date(2020, 12, 31), date(2021, 1, 1), -364),
+            (date(2020, 12, 31), date(2024, 1, 1), -365),
+        ] {
+            assert_eq!(
+                day_of_year_diff(&start, &end),
+                expected,
+                "{} {}",
+                start,
+                end
+            );
+            assert_eq!(
+                day_of_year_diff(&end, &start),
+                -expected,
+                "{} {}",
+                end,
+                start
+            );
+        }
+    }
+}
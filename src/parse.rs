@@ -0,0 +1,449 @@
+use std::{error, fmt, str::FromStr};
+
+use chrono::{DateTime, NaiveDate, ParseError};
+
+use crate::{get_diff, Interval};
+
+/// Error returned when a string can't be parsed into an ```Interval```.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseIntervalError {
+    /// Nothing but whitespace and maybe a sign was given.
+    Empty,
+    /// A number was expected, the offending text is included.
+    InvalidNumber(String),
+    /// A number wasn't followed by a unit.
+    MissingUnit(String),
+    /// A unit other than ```y```, ```m``` or ```d``` was given.
+    UnknownUnit(String),
+    /// The same unit was given twice.
+    DuplicateUnit(char),
+    /// The number doesn't fit in an ```Interval```, i.e. years exceed
+    /// ```Interval::MAX_YEARS``` or days an ```i32```.
+    Overflow,
+    /// The text isn't laid out as the format requires, the offending text
+    /// is included.
+    InvalidFormat(String),
+    /// A time of day component other than zero was given, which an
+    /// ```Interval``` can't hold.
+    NonZeroTime(String),
+    /// Components have different signs, which only a ```SignedInterval```
+    /// can hold.
+    MixedSigns,
+    /// A word of free text input that doesn't fit, see
+    /// ```Interval::parse_loose```.
+    UnexpectedToken {
+        /// The word, or character when it's neither letters nor digits.
+        token: String,
+        /// Byte offset of the word in the input.
+        offset: usize,
+        /// What would have fit in its place.
+        expected: &'static str,
+    },
+}
+
+impl fmt::Display for ParseIntervalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseIntervalError::Empty => write!(f, "empty interval"),
+            ParseIntervalError::InvalidNumber(text) => write!(f, "invalid number: {:?}", text),
+            ParseIntervalError::MissingUnit(number) => write!(f, "missing unit after {}", number),
+            ParseIntervalError::UnknownUnit(unit) => {
+                write!(f, "unknown unit: {:?} (expected y, m or d)", unit)
+            }
+            ParseIntervalError::DuplicateUnit(unit) => write!(f, "unit given twice: {}", unit),
+            ParseIntervalError::Overflow => write!(f, "interval too large"),
+            ParseIntervalError::InvalidFormat(text) => write!(f, "invalid format: {:?}", text),
+            ParseIntervalError::NonZeroTime(component) => {
+                write!(f, "time components must be zero: {}", component)
+            }
+            ParseIntervalError::MixedSigns => write!(f, "components have different signs"),
+            ParseIntervalError::UnexpectedToken {
+                token,
+                offset,
+                expected,
+            } => write!(
+                f,
+                "unexpected {:?} at {}, expected {}",
+                token, offset, expected
+            ),
+        }
+    }
+}
+
+impl error::Error for ParseIntervalError {}
+
+/// Parses compact notation like ```"2y 3m 4d"```, ```"-18m"``` or ```"10d"```.
+///
+/// Components may come in any order, each at most once, with or without
+/// spaces in between. A leading ```-``` makes the interval negative.
+/// Months over 11 carry into years, days are kept as given.
+///
+/// # Example
+///
+/// ```
+/// use datediff::Interval;
+///
+/// let interval: Interval = "2y 3m 4d".parse().unwrap();
+///
+/// # assert_eq!(interval, Interval::new(2, 3, 4, true).unwrap());
+/// # assert_eq!("-18m".parse().ok(), Interval::new(1, 6, 0, false).ok());
+/// # assert_eq!("10d".parse().ok(), Interval::new(0, 0, 10, true).ok());
+/// ```
+impl FromStr for Interval {
+    type Err = ParseIntervalError;
+
+    fn from_str(s: &str) -> Result<Interval, ParseIntervalError> {
+        let s = s.trim();
+        let (positive, mut rest) = match s.strip_prefix('-') {
+            Some(rest) => (false, rest),
+            None => (true, s.strip_prefix('+').unwrap_or(s)),
+        };
+        let (mut years, mut months, mut days) = (None, None, None);
+
+        rest = rest.trim_start();
+        if rest.is_empty() {
+            return Err(ParseIntervalError::Empty);
+        }
+        while !rest.is_empty() {
+            let digits = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            if digits == 0 {
+                let token = rest.split_whitespace().next().unwrap_or(rest);
+                return Err(ParseIntervalError::InvalidNumber(token.to_string()));
+            }
+            let (number, tail) = rest.split_at(digits);
+            let value: u32 = number.parse().map_err(|_| ParseIntervalError::Overflow)?;
+
+            let unit = match tail.chars().next() {
+                Some(unit) if !unit.is_whitespace() => unit,
+                _ => return Err(ParseIntervalError::MissingUnit(number.to_string())),
+            };
+            let slot = match unit {
+                'y' | 'Y' => &mut years,
+                'm' | 'M' => &mut months,
+                'd' | 'D' => &mut days,
+                _ => {
+                    let token = tail.split_whitespace().next().unwrap_or(tail);
+                    return Err(ParseIntervalError::UnknownUnit(token.to_string()));
+                }
+            };
+            if slot.replace(value).is_some() {
+                return Err(ParseIntervalError::DuplicateUnit(unit.to_ascii_lowercase()));
+            }
+            rest = tail[unit.len_utf8()..].trim_start();
+        }
+
+        let months = i64::from(years.unwrap_or(0)) * 12 + i64::from(months.unwrap_or(0));
+        Interval::checked_from_months_days(months, i64::from(days.unwrap_or(0)))
+            .map(|interval| interval.with_sign(positive))
+            .ok_or(ParseIntervalError::Overflow)
+    }
+}
+
+/// Splits free text into words of letters, runs of digits and single
+/// other characters, with their byte offsets. Whitespace and commas only
+/// separate words.
+fn words(s: &str) -> Vec<(usize, &str)> {
+    let mut words = Vec::new();
+    let mut chars = s.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c.is_whitespace() || c == ',' {
+            continue;
+        }
+        let mut end = start + c.len_utf8();
+        let same_kind = |next: char| {
+            (c.is_ascii_digit() && next.is_ascii_digit())
+                || (c.is_alphabetic() && next.is_alphabetic())
+        };
+        while let Some(&(index, next)) = chars.peek() {
+            if !same_kind(next) {
+                break;
+            }
+            end = index + next.len_utf8();
+            chars.next();
+        }
+        words.push((start, &s[start..end]));
+    }
+    words
+}
+
+impl Interval {
+    /// Parses free text as typed by people, like ```"2 years and 3 days"```,
+    /// ```"minus 3 months"``` or ```"1 week, 2y"```. Components may come in
+    /// any order, each unit at most once, separated by spaces, commas or
+    /// "and". Units may be abbreviated to ```y```/```yr```, ```m```/```mo```/
+    /// ```mon```, ```w```/```wk``` and ```d```, in any case, and "a" or "an"
+    /// stands for 1. A leading ```-``` or "minus" makes the interval
+    /// negative. Weeks are 7 days and months over 11 carry into years.
+    ///
+    /// The first word that doesn't fit gives
+    /// ```ParseIntervalError::UnexpectedToken``` with its offset and what
+    /// was expected instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::{Interval, ParseIntervalError};
+    ///
+    /// # assert_eq!(Interval::parse_loose("2 years and 3 days"), Ok(Interval::new(2, 0, 3, true).unwrap()));
+    /// # assert_eq!(Interval::parse_loose("minus 3 Months"), Ok(Interval::new(0, 3, 0, false).unwrap()));
+    /// # assert_eq!(Interval::parse_loose("a week, 2y"), Ok(Interval::new(2, 0, 7, true).unwrap()));
+    /// let error = Interval::parse_loose("2 years and 3 fortnights").unwrap_err();
+    ///
+    /// println!("{}", error);
+    /// # assert_eq!(error.to_string(), "unexpected \"fortnights\" at 14, expected a unit");
+    /// ```
+    pub fn parse_loose(s: &str) -> Result<Interval, ParseIntervalError> {
+        let mut words = words(s).into_iter().peekable();
+        let unexpected =
+            |(offset, token): (usize, &str), expected| ParseIntervalError::UnexpectedToken {
+                token: token.to_string(),
+                offset,
+                expected,
+            };
+
+        let positive = match words.peek() {
+            Some(&(_, word)) if word == "-" || word.eq_ignore_ascii_case("minus") => {
+                words.next();
+                false
+            }
+            Some(&(_, word)) if word == "+" || word.eq_ignore_ascii_case("plus") => {
+                words.next();
+                true
+            }
+            Some(_) => true,
+            None => return Err(ParseIntervalError::Empty),
+        };
+
+        let (mut years, mut months, mut weeks, mut days) = (None, None, None, None);
+        let mut components = 0;
+        while let Some(word) = words.next() {
+            let (_, text) = word;
+            if components > 0 && text.eq_ignore_ascii_case("and") {
+                continue;
+            }
+            let value: u32 = if text.starts_with(|c: char| c.is_ascii_digit()) {
+                text.parse().map_err(|_| ParseIntervalError::Overflow)?
+            } else if text.eq_ignore_ascii_case("a") || text.eq_ignore_ascii_case("an") {
+                1
+            } else {
+                return Err(unexpected(word, "a number"));
+            };
+            let unit = match words.next() {
+                Some(unit) => unit,
+                None => return Err(ParseIntervalError::MissingUnit(text.to_string())),
+            };
+            let (slot, name) = match unit.1.to_lowercase().as_str() {
+                "y" | "yr" | "yrs" | "year" | "years" => (&mut years, 'y'),
+                "m" | "mo" | "mos" | "mon" | "mons" | "month" | "months" => (&mut months, 'm'),
+                "w" | "wk" | "wks" | "week" | "weeks" => (&mut weeks, 'w'),
+                "d" | "day" | "days" => (&mut days, 'd'),
+                _ => return Err(unexpected(unit, "a unit")),
+            };
+            if slot.replace(value).is_some() {
+                return Err(ParseIntervalError::DuplicateUnit(name));
+            }
+            components += 1;
+        }
+        if components == 0 {
+            return Err(ParseIntervalError::Empty);
+        }
+
+        let months = i64::from(years.unwrap_or(0)) * 12 + i64::from(months.unwrap_or(0));
+        let days = i64::from(weeks.unwrap_or(0)) * 7 + i64::from(days.unwrap_or(0));
+        Interval::checked_from_months_days(months, days)
+            .map(|interval| interval.with_sign(positive))
+            .ok_or(ParseIntervalError::Overflow)
+    }
+}
+
+/// Reads a date for ```get_diff_str```.
+fn read_date(s: &str, format: Option<&str>) -> Result<NaiveDate, ParseError> {
+    match format {
+        Some(format) => NaiveDate::parse_from_str(s, format),
+        None => NaiveDate::parse_from_str(s, "%Y-%m-%d").or_else(|error| {
+            DateTime::parse_from_rfc3339(s)
+                .map(|datetime| datetime.naive_local().date())
+                .map_err(|_| error)
+        }),
+    }
+}
+
+/// Parses two dates and gives their difference like ```get_diff```. Without
+/// a ```format``` the dates are ```YYYY-MM-DD``` or RFC 3339 date and times
+/// like ```2020-01-31T23:00:00-05:00```, whose date is taken as written,
+/// in their own offset. With one, both are read with
+/// ```NaiveDate::parse_from_str```, see ```chrono::format::strftime```.
+///
+/// # Example
+///
+/// ```
+/// use datediff::{get_diff_str, Interval};
+///
+/// let diff = get_diff_str("2020-01-31", "2020-03-01T08:00:00Z", None).unwrap();
+///
+/// # assert_eq!(diff, Interval::new(0, 1, 1, true).unwrap());
+/// # assert_eq!(get_diff_str("31/01/2020", "01/03/2020", Some("%d/%m/%Y")), Ok(diff));
+/// # assert!(get_diff_str("2020-01-31", "tomorrow", None).is_err());
+/// ```
+pub fn get_diff_str(start: &str, end: &str, format: Option<&str>) -> Result<Interval, ParseError> {
+    Ok(get_diff(
+        &read_date(start.trim(), format)?,
+        &read_date(end.trim(), format)?,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{get_diff_str, Interval, ParseIntervalError};
+
+    #[test]
+    fn parse() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        assert_eq!("2y 3m 4d".parse(), Ok(interval(2, 3, 4, true)));
+        assert_eq!("2y3m4d".parse(), Ok(interval(2, 3, 4, true)));
+        assert_eq!("  4d 2Y ".parse(), Ok(interval(2, 0, 4, true)));
+        assert_eq!("-18m".parse(), Ok(interval(1, 6, 0, false)));
+        assert_eq!("+10d".parse(), Ok(interval(0, 0, 10, true)));
+        assert_eq!("- 0d".parse(), Ok(Interval::zero()));
+        assert_eq!("40d".parse::<Interval>().map(|i| i.days()), Ok(40));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let parse = |s: &str| s.parse::<Interval>();
+
+        assert_eq!(parse(""), Err(ParseIntervalError::Empty));
+        assert_eq!(parse("-"), Err(ParseIntervalError::Empty));
+        assert_eq!(
+            parse("y2"),
+            Err(ParseIntervalError::InvalidNumber("y2".to_string()))
+        );
+        assert_eq!(
+            parse("2y 3"),
+            Err(ParseIntervalError::MissingUnit("3".to_string()))
+        );
+        assert_eq!(
+            parse("2y 3 m"),
+            Err(ParseIntervalError::MissingUnit("3".to_string()))
+        );
+        assert_eq!(
+            parse("3w"),
+            Err(ParseIntervalError::UnknownUnit("w".to_string()))
+        );
+        assert_eq!(parse("1d 2d"), Err(ParseIntervalError::DuplicateUnit('d')));
+        assert_eq!(parse("99999999999y"), Err(ParseIntervalError::Overflow));
+        for text in &["4294967295y", "2147483647y 12m", "2147483648d"] {
+            assert_eq!(parse(text), Err(ParseIntervalError::Overflow), "{}", text);
+        }
+        assert_eq!(
+            parse("2147483647y 11m 2147483647d").map(|interval| interval.signed()),
+            Ok((i32::MAX, 11, i32::MAX))
+        );
+    }
+
+    #[test]
+    fn parse_loose() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+        for &(text, expected) in &[
+            ("2 years and 3 days", interval(2, 0, 3, true)),
+            ("2 years, 3 months and 4 days", interval(2, 3, 4, true)),
+            ("4 DAYS 2 Years", interval(2, 0, 4, true)),
+            ("minus 3 months", interval(0, 3, 0, false)),
+            ("- 1 year", interval(1, 0, 0, false)),
+            ("-2y3m", interval(2, 3, 0, false)),
+            ("plus a month", interval(0, 1, 0, true)),
+            ("an yr", interval(1, 0, 0, true)),
+            ("2 days and", interval(0, 0, 2, true)),
+            ("1 week, 2 days", interval(0, 0, 9, true)),
+            ("18 mos", interval(1, 6, 0, true)),
+            ("0 days", Interval::zero()),
+        ] {
+            assert_eq!(Interval::parse_loose(text), Ok(expected), "{}", text);
+        }
+
+        let unexpected = |token: &str, offset, expected| {
+            Err(ParseIntervalError::UnexpectedToken {
+                token: token.to_string(),
+                offset,
+                expected,
+            })
+        };
+        for (text, error) in vec![
+            ("", Err(ParseIntervalError::Empty)),
+            ("minus", Err(ParseIntervalError::Empty)),
+            ("and 2 days", unexpected("and", 0, "a number")),
+            (
+                "2 years 3",
+                Err(ParseIntervalError::MissingUnit("3".to_string())),
+            ),
+            ("2 hours", unexpected("hours", 2, "a unit")),
+            ("2 days 3 days", Err(ParseIntervalError::DuplicateUnit('d'))),
+            ("two days", unexpected("two", 0, "a number")),
+            ("1 day? 2", unexpected("?", 5, "a number")),
+            ("3 months - 2 days", unexpected("-", 9, "a number")),
+            ("99999999999 days", Err(ParseIntervalError::Overflow)),
+            ("4294967295 years", Err(ParseIntervalError::Overflow)),
+            ("2147483648 days", Err(ParseIntervalError::Overflow)),
+            ("306783379 weeks", Err(ParseIntervalError::Overflow)),
+        ] {
+            assert_eq!(Interval::parse_loose(text), error, "{}", text);
+        }
+    }
+
+    #[test]
+    fn diff_str() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+        for &(start, end, format, expected) in &[
+            ("2020-01-31", "2020-03-01", None, interval(0, 1, 1, true)),
+            (" 2020-03-01 ", "2020-01-31", None, interval(0, 1, 1, false)),
+            (
+                "2020-01-31T23:30:00-05:00",
+                "2020-02-29T00:00:00.5+01:00",
+                None,
+                interval(0, 0, 29, true),
+            ),
+            (
+                "1947-08-15",
+                "1950-01-26t12:00:00z",
+                None,
+                interval(2, 5, 11, true),
+            ),
+            (
+                "15.08.1947",
+                "26.01.1950",
+                Some("%d.%m.%Y"),
+                interval(2, 5, 11, true),
+            ),
+            (
+                "Aug 15 1947",
+                "Jan 26 1950",
+                Some("%b %d %Y"),
+                interval(2, 5, 11, true),
+            ),
+        ] {
+            assert_eq!(
+                get_diff_str(start, end, format),
+                Ok(expected),
+                "{} {}",
+                start,
+                end
+            );
+        }
+        for &(start, end, format) in &[
+            ("2020-02-30", "2020-03-01", None),
+            ("2020-01-01", "01/03/2020", None),
+            ("2020-01-01", "2020-03-01", Some("%d/%m/%Y")),
+            ("", "2020-03-01", None),
+        ] {
+            assert!(
+                get_diff_str(start, end, format).is_err(),
+                "{} {}",
+                start,
+                end
+            );
+        }
+    }
+}
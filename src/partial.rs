@@ -0,0 +1,218 @@
+use std::fmt;
+
+use chrono::{Datelike, NaiveDate};
+
+use crate::{days_in_month, get_diff, Interval};
+
+/// A month of a year without a day, e.g. a credit card expiry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct YearMonth {
+    year: i32,
+    month: u32,
+}
+
+impl YearMonth {
+    /// Gives ```None``` for an invalid month or a year out of the range of
+    /// ```NaiveDate```.
+    pub fn new(year: i32, month: u32) -> Option<YearMonth> {
+        NaiveDate::from_ymd_opt(year, month, 1)?;
+        NaiveDate::from_ymd_opt(year, month, days_in_month(year, month))?;
+        Some(YearMonth { year, month })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    pub fn first_day(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year, self.month, 1).unwrap()
+    }
+    pub fn last_day(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year, self.month, days_in_month(self.year, self.month))
+            .unwrap()
+    }
+}
+
+impl From<NaiveDate> for YearMonth {
+    fn from(date: NaiveDate) -> YearMonth {
+        YearMonth {
+            year: date.year(),
+            month: date.month(),
+        }
+    }
+}
+
+impl fmt::Display for YearMonth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}", self.year, self.month)
+    }
+}
+
+/// A year without a month or day, e.g. from historical records.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year {
+    year: i32,
+}
+
+impl Year {
+    /// Gives ```None``` for a year out of the range of ```NaiveDate```.
+    pub fn new(year: i32) -> Option<Year> {
+        NaiveDate::from_ymd_opt(year, 1, 1)?;
+        NaiveDate::from_ymd_opt(year, 12, 31)?;
+        Some(Year { year })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn first_day(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year, 1, 1).unwrap()
+    }
+    pub fn last_day(&self) -> NaiveDate {
+        NaiveDate::from_ymd_opt(self.year, 12, 31).unwrap()
+    }
+}
+
+impl From<NaiveDate> for Year {
+    fn from(date: NaiveDate) -> Year {
+        Year { year: date.year() }
+    }
+}
+
+impl fmt::Display for Year {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}", self.year)
+    }
+}
+
+/// A date that may lack its day, or its month and day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PartialDate {
+    Date(NaiveDate),
+    YearMonth(YearMonth),
+    Year(Year),
+}
+
+impl PartialDate {
+    /// Fills in the missing parts with ```fill```.
+    pub fn to_date(&self, fill: PartialFill) -> NaiveDate {
+        match (self, fill) {
+            (PartialDate::Date(date), _) => *date,
+            (PartialDate::YearMonth(month), PartialFill::FirstDay) => month.first_day(),
+            (PartialDate::YearMonth(month), PartialFill::LastDay) => month.last_day(),
+            (PartialDate::Year(year), PartialFill::FirstDay) => year.first_day(),
+            (PartialDate::Year(year), PartialFill::LastDay) => year.last_day(),
+        }
+    }
+}
+
+impl From<NaiveDate> for PartialDate {
+    fn from(date: NaiveDate) -> PartialDate {
+        PartialDate::Date(date)
+    }
+}
+
+impl From<YearMonth> for PartialDate {
+    fn from(month: YearMonth) -> PartialDate {
+        PartialDate::YearMonth(month)
+    }
+}
+
+impl From<Year> for PartialDate {
+    fn from(year: Year) -> PartialDate {
+        PartialDate::Year(year)
+    }
+}
+
+/// What ```get_diff_partial``` assumes for the missing parts of a date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PartialFill {
+    /// The first day of the month or year, e.g. 2021-03 is 2021-03-01.
+    #[default]
+    FirstDay,
+    /// The last day of the month or year, e.g. 2021-03 is 2021-03-31.
+    LastDay,
+}
+
+/// Takes two dates that may lack their day, or month and day, and gives the
+/// difference as Interval like ```get_diff```, after filling in the missing
+/// parts of both with ```fill```. Complete dates are used as they are.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::{get_diff_partial, Interval, PartialFill, Year, YearMonth};
+///
+/// let issued = NaiveDate::from_ymd_opt(2019, 6, 12).unwrap();
+/// let expiry = YearMonth::new(2023, 5).unwrap();
+///
+/// println!("Valid for {}", get_diff_partial(issued, expiry, PartialFill::LastDay));
+/// # assert_eq!(get_diff_partial(issued, expiry, PartialFill::LastDay), Interval::new(3, 11, 19, true).unwrap());
+/// # assert_eq!(get_diff_partial(issued, expiry, PartialFill::FirstDay), Interval::new(3, 10, 19, true).unwrap());
+/// # assert_eq!(get_diff_partial(Year::new(1850).unwrap(), Year::new(1900).unwrap(), PartialFill::default()), Interval::of_years(50));
+/// ```
+pub fn get_diff_partial<S, E>(start: S, end: E, fill: PartialFill) -> Interval
+where
+    S: Into<PartialDate>,
+    E: Into<PartialDate>,
+{
+    get_diff(&start.into().to_date(fill), &end.into().to_date(fill))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use crate::{get_diff_partial, Interval, PartialDate, PartialFill, Year, YearMonth};
+
+    #[test]
+    fn partial() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(YearMonth::new(2021, 13), None);
+        assert_eq!(YearMonth::new(2021, 0), None);
+        assert_eq!(Year::new(i32::MAX), None);
+        assert_eq!(
+            YearMonth::new(2020, 2).unwrap().last_day(),
+            date(2020, 2, 29)
+        );
+        assert_eq!(YearMonth::from(date(2021, 3, 9)).to_string(), "2021-03");
+        assert_eq!(Year::from(date(987, 3, 9)).to_string(), "0987");
+
+        let feb = YearMonth::new(2021, 2).unwrap();
+        let may = YearMonth::new(2021, 5).unwrap();
+        assert_eq!(
+            get_diff_partial(feb, may, PartialFill::FirstDay),
+            Interval::of_months(3)
+        );
+        // Feb 28 to May 31
+        assert_eq!(
+            get_diff_partial(feb, may, PartialFill::LastDay),
+            Interval::new(0, 3, 3, true).unwrap()
+        );
+        assert_eq!(
+            get_diff_partial(may, feb, PartialFill::FirstDay),
+            Interval::of_months(3).inverse()
+        );
+
+        let year = Year::new(2020).unwrap();
+        assert_eq!(
+            get_diff_partial(year, date(2020, 7, 1), PartialFill::FirstDay),
+            Interval::of_months(6)
+        );
+        assert_eq!(
+            get_diff_partial(year, date(2020, 7, 1), PartialFill::LastDay),
+            Interval::new(0, 5, 30, false).unwrap()
+        );
+        assert_eq!(
+            PartialDate::from(year).to_date(PartialFill::LastDay),
+            date(2020, 12, 31)
+        );
+    }
+}
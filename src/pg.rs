@@ -0,0 +1,337 @@
+use std::convert::TryFrom;
+
+#[cfg(feature = "diesel")]
+use diesel::{
+    deserialize::{self, FromSql},
+    pg::{data_types::PgInterval, Pg, PgValue},
+    serialize::{self, Output, ToSql},
+    sql_types,
+};
+#[cfg(feature = "sqlx")]
+use sqlx::{
+    encode::IsNull,
+    error::BoxDynError,
+    postgres::{
+        types::PgInterval as SqlxInterval, PgArgumentBuffer, PgHasArrayType, PgTypeInfo,
+        PgValueFormat, PgValueRef,
+    },
+    Decode, Encode, Postgres, Type,
+};
+
+#[cfg(feature = "diesel")]
+use crate::IntervalError;
+use crate::{Interval, SignedInterval};
+
+/// Total months and days with the sign applied, no microseconds. Fails with
+/// ```IntervalError::YearsOutOfRange``` when the months don't fit in the
+/// ```i32``` Postgres uses, i.e. from about 179 million years.
+///
+/// Needs the ```diesel``` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "diesel")]
+/// # {
+/// use std::convert::TryFrom;
+///
+/// use diesel::pg::data_types::PgInterval;
+///
+/// use datediff::Interval;
+///
+/// let interval = Interval::new(2, 3, 4, false).unwrap();
+///
+/// # assert_eq!(PgInterval::try_from(interval), Ok(PgInterval::new(0, -4, -27)));
+/// # }
+/// ```
+#[cfg(feature = "diesel")]
+impl TryFrom<Interval> for PgInterval {
+    type Error = IntervalError;
+
+    fn try_from(interval: Interval) -> Result<PgInterval, IntervalError> {
+        PgInterval::try_from(SignedInterval::from(interval))
+    }
+}
+
+/// Converts when the interval has no microseconds and months and days agree
+/// in sign. Fails with ```IntervalError::NotWholeDays``` or
+/// ```IntervalError::MixedSigns``` otherwise, use ```SignedInterval``` for
+/// the latter. Months are split into years and months, days are kept as
+/// they are since Postgres doesn't carry them into months.
+///
+/// Needs the ```diesel``` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "diesel")]
+/// # {
+/// use std::convert::TryFrom;
+///
+/// use diesel::pg::data_types::PgInterval;
+///
+/// use datediff::{Interval, IntervalError};
+///
+/// # assert_eq!(Interval::try_from(PgInterval::new(0, 4, 27)), Interval::new(2, 3, 4, true));
+/// # assert_eq!(Interval::try_from(PgInterval::new(0, -3, 1)), Err(IntervalError::MixedSigns));
+/// # assert_eq!(Interval::try_from(PgInterval::new(1, 0, 0)), Err(IntervalError::NotWholeDays(1)));
+/// # }
+/// ```
+#[cfg(feature = "diesel")]
+impl TryFrom<PgInterval> for Interval {
+    type Error = IntervalError;
+
+    fn try_from(interval: PgInterval) -> Result<Interval, IntervalError> {
+        Interval::try_from(SignedInterval::try_from(interval)?)
+    }
+}
+
+/// Same as for ```Interval```, but the components may have different
+/// signs, e.g. from ```SignedInterval::new(0, 1, -3)``` to 1 month -3 days.
+///
+/// Needs the ```diesel``` feature.
+#[cfg(feature = "diesel")]
+impl TryFrom<SignedInterval> for PgInterval {
+    type Error = IntervalError;
+
+    fn try_from(signed: SignedInterval) -> Result<PgInterval, IntervalError> {
+        let (months, days) = signed.to_months_days()?;
+        Ok(PgInterval::new(0, days, months))
+    }
+}
+
+/// Converts any interval without microseconds, fails with
+/// ```IntervalError::NotWholeDays``` otherwise. Months and days may have
+/// different signs, as they can in Postgres, e.g. ```'1 mon -3 days'```.
+///
+/// Needs the ```diesel``` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "diesel")]
+/// # {
+/// use std::convert::TryFrom;
+///
+/// use diesel::pg::data_types::PgInterval;
+///
+/// use datediff::SignedInterval;
+///
+/// # assert_eq!(SignedInterval::try_from(PgInterval::new(0, -3, 13)), Ok(SignedInterval::new(1, 1, -3)));
+/// # }
+/// ```
+#[cfg(feature = "diesel")]
+impl TryFrom<PgInterval> for SignedInterval {
+    type Error = IntervalError;
+
+    fn try_from(interval: PgInterval) -> Result<SignedInterval, IntervalError> {
+        SignedInterval::from_months_days(interval.months, interval.days, interval.microseconds)
+    }
+}
+
+/// Reads an INTERVAL column, see ```TryFrom<PgInterval>``` for the values
+/// that fail.
+///
+/// Needs the ```diesel``` feature.
+#[cfg(feature = "diesel")]
+impl FromSql<sql_types::Interval, Pg> for Interval {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Interval> {
+        let interval = <PgInterval as FromSql<sql_types::Interval, Pg>>::from_sql(value)?;
+        Ok(Interval::try_from(interval)?)
+    }
+}
+
+/// Writes an INTERVAL value, see ```TryFrom<Interval>``` for ```PgInterval```.
+///
+/// Needs the ```diesel``` feature.
+#[cfg(feature = "diesel")]
+impl ToSql<sql_types::Interval, Pg> for Interval {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let interval = PgInterval::try_from(*self)?;
+        ToSql::<sql_types::Interval, Pg>::to_sql(&interval, &mut out.reborrow())
+    }
+}
+
+/// Binds and fetches ```Interval``` as a Postgres INTERVAL, e.g. with
+/// ```query_as!```.
+///
+/// Needs the ```sqlx``` feature.
+#[cfg(feature = "sqlx")]
+impl Type<Postgres> for Interval {
+    fn type_info() -> PgTypeInfo {
+        <SqlxInterval as Type<Postgres>>::type_info()
+    }
+}
+
+/// Needs the ```sqlx``` feature.
+#[cfg(feature = "sqlx")]
+impl PgHasArrayType for Interval {
+    fn array_type_info() -> PgTypeInfo {
+        <SqlxInterval as PgHasArrayType>::array_type_info()
+    }
+}
+
+/// Writes total months and days with the sign applied and no microseconds
+/// in the binary format. Fails with ```IntervalError::YearsOutOfRange```
+/// when the months don't fit in an ```i32```.
+///
+/// Needs the ```sqlx``` feature.
+#[cfg(feature = "sqlx")]
+impl Encode<'_, Postgres> for Interval {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> Result<IsNull, BoxDynError> {
+        let (months, days) = SignedInterval::from(*self).to_months_days()?;
+        SqlxInterval {
+            months,
+            days,
+            microseconds: 0,
+        }
+        .encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        16
+    }
+}
+
+/// Reads the binary format, fails with ```IntervalError::NotWholeDays``` on
+/// a time part and ```IntervalError::MixedSigns``` when months and days
+/// have different signs, like ```TryFrom<SignedInterval>```.
+///
+/// Needs the ```sqlx``` feature.
+#[cfg(feature = "sqlx")]
+impl<'r> Decode<'r, Postgres> for Interval {
+    fn decode(value: PgValueRef<'r>) -> Result<Interval, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => decode_binary(value.as_bytes()?),
+            PgValueFormat::Text => Err("can't decode an INTERVAL in text format".into()),
+        }
+    }
+}
+
+/// Reads the 16 bytes of a binary INTERVAL, microseconds, days and months
+/// in network byte order.
+#[cfg(feature = "sqlx")]
+fn decode_binary(bytes: &[u8]) -> Result<Interval, BoxDynError> {
+    if bytes.len() != 16 {
+        return Err(format!("INTERVAL needs 16 bytes, got {}", bytes.len()).into());
+    }
+    let microseconds = i64::from_be_bytes(<[u8; 8]>::try_from(&bytes[..8])?);
+    let days = i32::from_be_bytes(<[u8; 4]>::try_from(&bytes[8..12])?);
+    let months = i32::from_be_bytes(<[u8; 4]>::try_from(&bytes[12..])?);
+    let signed = SignedInterval::from_months_days(months, days, microseconds)?;
+    Ok(Interval::try_from(signed)?)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Interval, IntervalError};
+
+    #[cfg(feature = "diesel")]
+    #[test]
+    fn diesel() {
+        use std::convert::TryFrom;
+
+        use diesel::{
+            deserialize::FromSqlRow,
+            expression::AsExpression,
+            pg::{data_types::PgInterval, Pg},
+            sql_types,
+        };
+
+        use crate::SignedInterval;
+
+        let interval = Interval::new(2, 3, 4, false).unwrap();
+        assert_eq!(
+            PgInterval::try_from(interval),
+            Ok(PgInterval::new(0, -4, -27))
+        );
+        assert_eq!(
+            Interval::try_from(PgInterval::new(0, -4, -27)),
+            Ok(interval)
+        );
+        assert_eq!(
+            PgInterval::try_from(Interval::MAX),
+            Err(IntervalError::YearsOutOfRange(Interval::MAX_YEARS))
+        );
+        assert_eq!(
+            Interval::try_from(PgInterval::new(86_400_000_000, 0, 0)),
+            Err(IntervalError::NotWholeDays(86_400_000_000))
+        );
+
+        // mixed signs only fit a SignedInterval
+        let pg = PgInterval::new(0, -3, 1);
+        assert_eq!(Interval::try_from(pg), Err(IntervalError::MixedSigns));
+        assert_eq!(
+            SignedInterval::try_from(pg),
+            Ok(SignedInterval::new(0, 1, -3))
+        );
+        assert_eq!(PgInterval::try_from(SignedInterval::new(0, 1, -3)), Ok(pg));
+
+        // bound and loaded as an INTERVAL column
+        fn interval_column<T>(_: T)
+        where
+            T: AsExpression<sql_types::Interval> + FromSqlRow<sql_types::Interval, Pg>,
+        {
+        }
+        interval_column(Interval::zero());
+    }
+
+    #[cfg(feature = "sqlx")]
+    #[test]
+    fn sqlx() {
+        use sqlx::{postgres::PgArgumentBuffer, Encode};
+
+        let encode = |interval: Interval| {
+            let mut buf = PgArgumentBuffer::default();
+            interval.encode_by_ref(&mut buf).map(|_| buf.to_vec())
+        };
+        for &(interval, expected) in &[
+            (
+                Interval::zero(),
+                [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+            ),
+            (
+                Interval::new(2, 3, 4, true).unwrap(),
+                [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 27],
+            ),
+            (
+                Interval::new(0, 1, 3, false).unwrap(),
+                [
+                    0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 253, 255, 255, 255, 255,
+                ],
+            ),
+        ] {
+            assert_eq!(encode(interval).unwrap(), expected, "{}", interval);
+            assert_eq!(
+                super::decode_binary(&expected).unwrap(),
+                interval,
+                "{}",
+                interval
+            );
+        }
+
+        let decode_error = |bytes: [u8; 16]| {
+            super::decode_binary(&bytes)
+                .unwrap_err()
+                .downcast_ref::<IntervalError>()
+                .cloned()
+        };
+        // 1.5 seconds, i.e. 1500000 microseconds
+        assert_eq!(
+            decode_error([0, 0, 0, 0, 0, 22, 227, 96, 0, 0, 0, 0, 0, 0, 0, 0]),
+            Some(IntervalError::NotWholeDays(1_500_000))
+        );
+        // 1 month -3 days
+        assert_eq!(
+            decode_error([0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 253, 0, 0, 0, 1]),
+            Some(IntervalError::MixedSigns)
+        );
+        assert!(super::decode_binary(&[0; 12]).is_err());
+        assert_eq!(
+            encode(Interval::MAX)
+                .unwrap_err()
+                .downcast_ref::<IntervalError>(),
+            Some(&IntervalError::YearsOutOfRange(Interval::MAX_YEARS))
+        );
+    }
+}
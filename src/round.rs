@@ -0,0 +1,656 @@
+use std::cmp::Ordering;
+
+use chrono::NaiveDate;
+
+use crate::{get_diff, Interval, Unit};
+
+/// How ```Interval::round_to``` treats the part below the target unit.
+/// Rounding works on the magnitude, so negative intervals round away from
+/// or towards zero just like positive ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum RoundingMode {
+    /// Drop the remainder.
+    Truncate,
+    /// Round up whenever there is a remainder.
+    Up,
+    /// Round up when the remainder is at least half a unit.
+    HalfUp,
+    /// Round up when the remainder is more than half a unit.
+    HalfDown,
+    /// Round up when the remainder is more than half a unit, or exactly half
+    /// and the whole units are odd, so ties go to an even count.
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Rounds ```whole``` units given how the remainder compares to half a unit.
+    fn round(self, whole: u64, remainder: Ordering, half: Ordering) -> u64 {
+        let up = match self {
+            RoundingMode::Truncate => false,
+            RoundingMode::Up => remainder == Ordering::Greater,
+            RoundingMode::HalfUp => half != Ordering::Less,
+            RoundingMode::HalfDown => half == Ordering::Greater,
+            RoundingMode::HalfEven => {
+                half == Ordering::Greater || (half == Ordering::Equal && whole % 2 == 1)
+            }
+        };
+        if up {
+            whole + 1
+        } else {
+            whole
+        }
+    }
+}
+
+impl Interval {
+    /// Rounds the interval to a whole number of ```unit```s, e.g. for
+    /// displaying "2 years" while keeping the exact interval around.
+    ///
+    /// Without an anchor, days past 30, more than ```get_diff``` ever gives,
+    /// count as months of 30 days first. Half a year is 6 months, half a
+    /// quarter is 1 month 15 days and half a month is 15 days. Rounding to
+    /// half months gives a whole number of months and maybe 15 days.
+    /// Rounding to fortnights or weeks rounds the days to a multiple of 14
+    /// or 7 and rounding to days gives the interval back as it is.
+    ///
+    /// # Panics
+    ///
+    /// Panics when rounding up goes past ```Interval::MAX```, see
+    /// ```Interval::checked_round_to```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::{Interval, RoundingMode, Unit};
+    ///
+    /// let interval = Interval::new(1, 7, 20, true).unwrap();
+    ///
+    /// # assert_eq!(interval.round_to(Unit::Year, RoundingMode::HalfUp), Interval::of_years(2));
+    /// # assert_eq!(interval.round_to(Unit::Year, RoundingMode::Truncate), Interval::of_years(1));
+    /// # assert_eq!(interval.round_to(Unit::Month, RoundingMode::HalfUp), Interval::of_months(20));
+    /// # assert_eq!(Interval::of_days(100).round_to(Unit::Month, RoundingMode::HalfUp), Interval::of_months(3));
+    /// ```
+    pub fn round_to(&self, unit: Unit, mode: RoundingMode) -> Interval {
+        self.checked_round_to(unit, mode)
+            .expect("interval overflow")
+    }
+
+    /// Same as ```Interval::round_to```, but gives ```None``` instead of
+    /// panicking when the rounded interval doesn't fit.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::{Interval, RoundingMode, Unit};
+    ///
+    /// # assert_eq!(Interval::MAX.checked_round_to(Unit::Year, RoundingMode::Up), None);
+    /// # assert!(Interval::MAX.checked_round_to(Unit::Year, RoundingMode::Truncate).is_some());
+    /// ```
+    pub fn checked_round_to(&self, unit: Unit, mode: RoundingMode) -> Option<Interval> {
+        let months = u64::from(self.years) * 12 + u64::from(self.months);
+        //days that don't fit in a month are carried as 30 days each
+        let (carried, days) = match self.days {
+            days if days > 30 => (months + u64::from(days / 30), days % 30),
+            days => (months, days),
+        };
+        let rounded = match unit {
+            Unit::Year => {
+                let below = ((carried % 12) as u32, days);
+                let years = mode.round(carried / 12, below.cmp(&(0, 0)), below.cmp(&(6, 0)));
+                Interval::checked_from_months_days(years as i64 * 12, 0)
+            }
+            Unit::Quarter => {
+                let below = (carried % 3, days);
+                let quarters = mode.round(carried / 3, below.cmp(&(0, 0)), below.cmp(&(1, 15)));
+                Interval::checked_from_months_days(quarters as i64 * 3, 0)
+            }
+            Unit::Month => {
+                let months = mode.round(carried, days.cmp(&0), days.cmp(&15));
+                Interval::checked_from_months_days(months as i64, 0)
+            }
+            Unit::SemiMonth => {
+                let half = days.min(15) / 15;
+                let below = days - half * 15;
+                let halves = mode.round(
+                    carried * 2 + u64::from(half),
+                    below.cmp(&0),
+                    (below * 2).cmp(&15),
+                );
+                Interval::checked_from_months_days(halves as i64 / 2, halves as i64 % 2 * 15)
+            }
+            Unit::Fortnight => {
+                let below = self.days % 14;
+                let fortnights = mode.round(
+                    u64::from(self.days / 14),
+                    below.cmp(&0),
+                    (below * 2).cmp(&14),
+                );
+                Interval::checked_from_months_days(months as i64, fortnights as i64 * 14)
+            }
+            Unit::Week => {
+                let below = self.days % 7;
+                let weeks =
+                    mode.round(u64::from(self.days / 7), below.cmp(&0), (below * 2).cmp(&7));
+                Interval::checked_from_months_days(months as i64, weeks as i64 * 7)
+            }
+            Unit::Day => Some(*self),
+        };
+        rounded.map(|rounded| rounded.with_sign(self.positive))
+    }
+
+    /// Zeroes all components finer than ```unit```, keeping the sign, e.g.
+    /// truncating to months drops the days.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::{Interval, Unit};
+    ///
+    /// let interval = Interval::new(2, 3, 4, false).unwrap();
+    ///
+    /// # assert_eq!(interval.truncate(Unit::Month), Interval::new(2, 3, 0, false).unwrap());
+    /// # assert_eq!(interval.truncate(Unit::Year), Interval::new(2, 0, 0, false).unwrap());
+    /// # assert_eq!(interval.truncate(Unit::Day), interval);
+    /// ```
+    pub fn truncate(&self, unit: Unit) -> Interval {
+        let truncated = match unit {
+            Unit::Year => Interval {
+                months: 0,
+                days: 0,
+                ..*self
+            },
+            Unit::Quarter => Interval {
+                months: self.months / 3 * 3,
+                days: 0,
+                ..*self
+            },
+            Unit::Month => Interval { days: 0, ..*self },
+            Unit::SemiMonth => Interval {
+                days: self.days.min(15) / 15 * 15,
+                ..*self
+            },
+            Unit::Fortnight => Interval {
+                days: self.days / 14 * 14,
+                ..*self
+            },
+            Unit::Week => Interval {
+                days: self.days / 7 * 7,
+                ..*self
+            },
+            Unit::Day => *self,
+        };
+        truncated.with_sign(self.positive)
+    }
+
+    /// Keeps only the ```n``` most significant non-zero components, e.g.
+    /// 2 years 3 months for 2 years 3 months 17 days with ```n``` of 2.
+    /// The dropped components are truncated, not rounded.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::Interval;
+    ///
+    /// let interval = Interval::new(2, 3, 17, true).unwrap();
+    ///
+    /// # assert_eq!(interval.top_units(2), Interval::new(2, 3, 0, true).unwrap());
+    /// # assert_eq!(Interval::new(2, 0, 17, true).unwrap().top_units(2), Interval::new(2, 0, 17, true).unwrap());
+    /// # assert_eq!(interval.top_units(0), Interval::zero());
+    /// ```
+    pub fn top_units(&self, n: usize) -> Interval {
+        let mut kept = 0;
+        let mut keep = |value: u32| {
+            if value == 0 || kept >= n {
+                0
+            } else {
+                kept += 1;
+                value
+            }
+        };
+        Interval {
+            years: keep(self.years),
+            months: keep(self.months),
+            days: keep(self.days),
+            positive: self.positive,
+        }
+        .with_sign(self.positive)
+    }
+}
+
+/// Counts whole ```unit```s from ```start``` to ```end```, rounding what's
+/// left over with ```mode```, e.g. for age rounding rules where 1 year 6
+/// months is 2 years with ```RoundingMode::HalfUp``` and 1 year with
+/// ```RoundingMode::HalfDown``` or ```RoundingMode::Truncate```.
+///
+/// Years, quarters, months and half months are rounded from ```get_diff```
+/// as in ```Interval::round_to```, fortnights, weeks and days from the days
+/// elapsed. Negative when ```end``` is before ```start```.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::{get_diff_rounded, RoundingMode, Unit};
+///
+/// let birth = NaiveDate::from_ymd_opt(1990, 1, 15).unwrap();
+/// let today = NaiveDate::from_ymd_opt(2021, 7, 15).unwrap();
+///
+/// println!("Insurance age is {}", get_diff_rounded(&birth, &today, Unit::Year, RoundingMode::HalfUp));
+/// # assert_eq!(get_diff_rounded(&birth, &today, Unit::Year, RoundingMode::HalfUp), 32);
+/// # assert_eq!(get_diff_rounded(&birth, &today, Unit::Year, RoundingMode::HalfDown), 31);
+/// # assert_eq!(get_diff_rounded(&birth, &today, Unit::Year, RoundingMode::HalfEven), 32);
+/// # assert_eq!(get_diff_rounded(&today, &birth, Unit::Year, RoundingMode::HalfUp), -32);
+/// ```
+pub fn get_diff_rounded(start: &NaiveDate, end: &NaiveDate, unit: Unit, mode: RoundingMode) -> i64 {
+    let days = end.signed_duration_since(*start).num_days();
+    match unit {
+        Unit::Year => get_diff(start, end).round_to(unit, mode).total_months() / 12,
+        Unit::Quarter => get_diff(start, end).round_to(unit, mode).total_months() / 3,
+        Unit::Month => get_diff(start, end).round_to(unit, mode).total_months(),
+        Unit::SemiMonth => {
+            let rounded = get_diff(start, end).round_to(unit, mode);
+            rounded.total_months() * 2 + rounded.days_i64() / 15
+        }
+        Unit::Fortnight => {
+            let (fortnights, below) = (days.unsigned_abs() / 14, days.unsigned_abs() % 14);
+            days.signum() * mode.round(fortnights, below.cmp(&0), (below * 2).cmp(&14)) as i64
+        }
+        Unit::Week => {
+            let (weeks, below) = (days.unsigned_abs() / 7, days.unsigned_abs() % 7);
+            days.signum() * mode.round(weeks, below.cmp(&0), (below * 2).cmp(&7)) as i64
+        }
+        Unit::Day => days,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use crate::{Interval, RoundingMode, Unit};
+
+    #[test]
+    fn round_to() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        let cases = [
+            (
+                interval(1, 6, 0, true),
+                Unit::Year,
+                RoundingMode::HalfUp,
+                interval(2, 0, 0, true),
+            ),
+            (
+                interval(1, 5, 30, true),
+                Unit::Year,
+                RoundingMode::HalfUp,
+                interval(1, 0, 0, true),
+            ),
+            (
+                interval(1, 0, 1, false),
+                Unit::Year,
+                RoundingMode::Up,
+                interval(2, 0, 0, false),
+            ),
+            (
+                interval(1, 0, 0, true),
+                Unit::Year,
+                RoundingMode::Up,
+                interval(1, 0, 0, true),
+            ),
+            (
+                interval(1, 11, 30, true),
+                Unit::Year,
+                RoundingMode::Truncate,
+                interval(1, 0, 0, true),
+            ),
+            (
+                interval(0, 11, 15, true),
+                Unit::Month,
+                RoundingMode::HalfUp,
+                interval(1, 0, 0, true),
+            ),
+            (
+                interval(0, 11, 14, false),
+                Unit::Month,
+                RoundingMode::HalfUp,
+                interval(0, 11, 0, false),
+            ),
+            (
+                interval(0, 0, 3, false),
+                Unit::Month,
+                RoundingMode::Truncate,
+                Interval::zero(),
+            ),
+            (
+                interval(2, 3, 4, false),
+                Unit::Day,
+                RoundingMode::Up,
+                interval(2, 3, 4, false),
+            ),
+            (
+                interval(1, 4, 15, true),
+                Unit::Quarter,
+                RoundingMode::HalfUp,
+                interval(1, 6, 0, true),
+            ),
+            (
+                interval(1, 4, 14, true),
+                Unit::Quarter,
+                RoundingMode::HalfUp,
+                interval(1, 3, 0, true),
+            ),
+            (
+                interval(0, 2, 4, false),
+                Unit::Week,
+                RoundingMode::HalfUp,
+                interval(0, 2, 7, false),
+            ),
+            (
+                interval(0, 2, 10, false),
+                Unit::Week,
+                RoundingMode::HalfUp,
+                interval(0, 2, 7, false),
+            ),
+        ];
+        for (interval, unit, mode, expected) in cases.iter() {
+            assert_eq!(
+                interval.round_to(*unit, *mode),
+                *expected,
+                "{} {:?} {:?}",
+                interval,
+                unit,
+                mode
+            );
+        }
+    }
+
+    #[test]
+    fn round_to_days_past_a_month() {
+        for &(interval, unit, expected) in &[
+            (Interval::of_days(400), Unit::Year, Interval::of_years(1)),
+            (Interval::of_days(540), Unit::Year, Interval::of_years(2)),
+            (Interval::of_days(100), Unit::Month, Interval::of_months(3)),
+            (Interval::of_days(45), Unit::Month, Interval::of_months(2)),
+            (Interval::of_days(30), Unit::Month, Interval::of_months(1)),
+            (Interval::of_days(31), Unit::Year, Interval::zero()),
+            (
+                Interval::of_days(100),
+                Unit::Quarter,
+                Interval::of_months(3),
+            ),
+            (
+                Interval::of_days(50),
+                Unit::SemiMonth,
+                Interval::builder().months(1).days(15).build_unchecked(),
+            ),
+            (Interval::of_days(45), Unit::Week, Interval::of_days(42)),
+        ] {
+            assert_eq!(
+                interval.round_to(unit, RoundingMode::HalfUp),
+                expected,
+                "{} {:?}",
+                interval,
+                unit
+            );
+        }
+        assert_eq!(
+            Interval::of_days(400)
+                .inverse()
+                .round_to(Unit::Year, RoundingMode::HalfUp),
+            Interval::of_years(1).inverse()
+        );
+    }
+
+    #[test]
+    fn round_to_overflow() {
+        assert_eq!(
+            Interval::MAX.checked_round_to(Unit::Year, RoundingMode::Up),
+            None
+        );
+        let days = Interval::of_days(i32::MAX as u32);
+        assert_eq!(days.checked_round_to(Unit::Week, RoundingMode::Up), None);
+        assert_eq!(
+            days.checked_round_to(Unit::Week, RoundingMode::Truncate),
+            Some(Interval::of_days(i32::MAX as u32 / 7 * 7))
+        );
+        assert_eq!(
+            Interval::MAX.checked_round_to(Unit::Day, RoundingMode::Up),
+            Some(Interval::MAX)
+        );
+        assert_eq!(
+            Interval::MIN.checked_round_to(Unit::Month, RoundingMode::Truncate),
+            Some(Interval::MIN.truncate(Unit::Month))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "interval overflow")]
+    fn round_to_panics() {
+        Interval::MAX.round_to(Unit::Year, RoundingMode::Up);
+    }
+
+    #[test]
+    fn get_diff_rounded() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let modes = [
+            RoundingMode::Truncate,
+            RoundingMode::Up,
+            RoundingMode::HalfUp,
+            RoundingMode::HalfDown,
+            RoundingMode::HalfEven,
+        ];
+
+        // start, end, unit, expected for each mode
+        let cases = [
+            (
+                date(2020, 1, 1),
+                date(2021, 7, 1),
+                Unit::Year,
+                [1, 2, 2, 1, 2],
+            ),
+            (
+                date(2020, 1, 1),
+                date(2022, 7, 1),
+                Unit::Year,
+                [2, 3, 3, 2, 2],
+            ),
+            (
+                date(2020, 1, 1),
+                date(2022, 7, 2),
+                Unit::Year,
+                [2, 3, 3, 3, 3],
+            ),
+            (
+                date(2022, 7, 1),
+                date(2020, 1, 1),
+                Unit::Year,
+                [-2, -3, -3, -2, -2],
+            ),
+            (
+                date(2020, 1, 1),
+                date(2020, 1, 1),
+                Unit::Year,
+                [0, 0, 0, 0, 0],
+            ),
+            (
+                date(2021, 1, 1),
+                date(2021, 2, 16),
+                Unit::Month,
+                [1, 2, 2, 1, 2],
+            ),
+            (
+                date(2021, 1, 1),
+                date(2021, 5, 16),
+                Unit::Quarter,
+                [1, 2, 2, 1, 2],
+            ),
+            (
+                date(2021, 1, 1),
+                date(2021, 1, 12),
+                Unit::Week,
+                [1, 2, 2, 2, 2],
+            ),
+            (
+                date(2021, 1, 1),
+                date(2021, 1, 11),
+                Unit::Week,
+                [1, 2, 1, 1, 1],
+            ),
+            (
+                date(2021, 1, 1),
+                date(2021, 1, 26),
+                Unit::Week,
+                [3, 4, 4, 4, 4],
+            ),
+            (
+                date(2021, 1, 26),
+                date(2021, 1, 1),
+                Unit::Week,
+                [-3, -4, -4, -4, -4],
+            ),
+            (
+                date(2021, 1, 1),
+                date(2021, 3, 1),
+                Unit::Day,
+                [59, 59, 59, 59, 59],
+            ),
+        ];
+        for (start, end, unit, expected) in cases.iter() {
+            for (mode, expected) in modes.iter().zip(expected.iter()) {
+                assert_eq!(
+                    super::get_diff_rounded(start, end, *unit, *mode),
+                    *expected,
+                    "{} {} {:?} {:?}",
+                    start,
+                    end,
+                    unit,
+                    mode
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn payroll_units() {
+        let interval = |y, m, d| Interval::new(y, m, d, true).unwrap();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let semi_month = |i: Interval, mode| i.round_to(Unit::SemiMonth, mode);
+        assert_eq!(
+            semi_month(interval(0, 1, 7), RoundingMode::HalfUp),
+            interval(0, 1, 0)
+        );
+        assert_eq!(
+            semi_month(interval(0, 1, 8), RoundingMode::HalfUp),
+            interval(0, 1, 15)
+        );
+        assert_eq!(
+            semi_month(interval(0, 1, 23), RoundingMode::HalfUp),
+            interval(0, 2, 0)
+        );
+        assert_eq!(
+            semi_month(interval(0, 1, 16), RoundingMode::Truncate),
+            interval(0, 1, 15)
+        );
+        assert_eq!(
+            interval(0, 1, 16).truncate(Unit::SemiMonth),
+            interval(0, 1, 15)
+        );
+
+        let fortnight = |i: Interval, mode| i.round_to(Unit::Fortnight, mode);
+        assert_eq!(
+            fortnight(interval(0, 1, 7), RoundingMode::HalfUp),
+            interval(0, 1, 14)
+        );
+        assert_eq!(
+            fortnight(interval(0, 1, 6), RoundingMode::HalfUp),
+            interval(0, 1, 0)
+        );
+        assert_eq!(
+            interval(0, 0, 29).truncate(Unit::Fortnight),
+            interval(0, 0, 28)
+        );
+
+        let (start, end) = (date(2021, 1, 1), date(2021, 2, 24));
+        // 1 month 23 days, 54 days
+        let rounded = |unit, mode| super::get_diff_rounded(&start, &end, unit, mode);
+        assert_eq!(rounded(Unit::SemiMonth, RoundingMode::Truncate), 3);
+        assert_eq!(rounded(Unit::SemiMonth, RoundingMode::HalfUp), 4);
+        assert_eq!(rounded(Unit::Fortnight, RoundingMode::Truncate), 3);
+        assert_eq!(rounded(Unit::Fortnight, RoundingMode::HalfUp), 4);
+        assert_eq!(
+            super::get_diff_rounded(&end, &start, Unit::SemiMonth, RoundingMode::HalfUp),
+            -4
+        );
+    }
+
+    #[test]
+    fn truncate() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        assert_eq!(
+            interval(0, 0, 12, false).truncate(Unit::Month),
+            Interval::zero()
+        );
+        assert!(interval(0, 0, 12, false).truncate(Unit::Month).positive());
+        assert_eq!(
+            interval(1, 11, 30, true).truncate(Unit::Quarter),
+            interval(1, 9, 0, true)
+        );
+        assert_eq!(
+            interval(1, 11, 30, true).truncate(Unit::Week),
+            interval(1, 11, 28, true)
+        );
+        assert_eq!(
+            interval(1, 11, 30, true).truncate(Unit::Year),
+            interval(1, 0, 0, true)
+        );
+        let units = [
+            Unit::Year,
+            Unit::Quarter,
+            Unit::Month,
+            Unit::SemiMonth,
+            Unit::Fortnight,
+            Unit::Week,
+            Unit::Day,
+        ];
+        for unit in units.iter() {
+            let interval = interval(1, 11, 30, false);
+            assert_eq!(
+                interval.truncate(*unit),
+                interval.round_to(*unit, RoundingMode::Truncate)
+            );
+        }
+    }
+
+    #[test]
+    fn top_units() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+
+        assert_eq!(
+            interval(2, 3, 17, false).top_units(1),
+            interval(2, 0, 0, false)
+        );
+        assert_eq!(
+            interval(2, 3, 17, false).top_units(3),
+            interval(2, 3, 17, false)
+        );
+        assert_eq!(
+            interval(2, 3, 17, false).top_units(10),
+            interval(2, 3, 17, false)
+        );
+        assert_eq!(
+            interval(0, 3, 17, true).top_units(1),
+            interval(0, 3, 0, true)
+        );
+        assert_eq!(
+            interval(0, 0, 17, true).top_units(2),
+            interval(0, 0, 17, true)
+        );
+        assert_eq!(interval(0, 0, 17, false).top_units(0), Interval::zero());
+    }
+}
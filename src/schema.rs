@@ -0,0 +1,104 @@
+//! ```JsonSchema``` for ```Interval``` and the options structs, e.g. to
+//! document them in an OpenAPI spec. The options derive theirs from the
+//! fields ```Serialize``` writes.
+//!
+//! Needs the ```schemars``` feature.
+
+use std::borrow::Cow;
+
+use schemars::{json_schema, JsonSchema, Schema, SchemaGenerator};
+
+use crate::Interval;
+
+/// Describes the ISO 8601 duration string ```Interval``` is serialized as in
+/// JSON.
+///
+/// Needs the ```schemars``` feature.
+impl JsonSchema for Interval {
+    fn schema_name() -> Cow<'static, str> {
+        "Interval".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "type": "string",
+            "format": "duration",
+            "pattern": r"^-?P((\d+Y(\d+M)?(\d+W)?(\d+D)?|\d+M(\d+W)?(\d+D)?|\d+W(\d+D)?|\d+D)(T(0+H(0+M)?(0+S)?|0+M(0+S)?|0+S))?|T(0+H(0+M)?(0+S)?|0+M(0+S)?|0+S))$",
+            "description": "ISO 8601 duration of years, months, weeks and days, e.g. P2Y3M4D",
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{DiffOptions, DisplayOptions, HumanizeThresholds, Interval};
+
+    #[test]
+    fn json_schema() {
+        let schema = schemars::schema_for!(Interval);
+        assert_eq!(schema.get("type").and_then(|t| t.as_str()), Some("string"));
+        assert_eq!(
+            schema.get("format").and_then(|f| f.as_str()),
+            Some("duration")
+        );
+        assert_eq!(
+            schema.get("title").and_then(|t| t.as_str()),
+            Some("Interval")
+        );
+
+        let pattern = schema.get("pattern").and_then(|p| p.as_str()).unwrap();
+        let pattern = regex::Regex::new(pattern).unwrap();
+        for &(text, valid) in &[
+            ("P2Y3M4D", true),
+            ("-P5D", true),
+            ("P0D", true),
+            ("P1W", true),
+            ("P1M2W3D", true),
+            ("P1DT0H0M0S", true),
+            ("PT0S", true),
+            ("-PT00M", true),
+            ("P", false),
+            ("PT", false),
+            ("-P", false),
+            ("P1DT", false),
+            ("PT1S", false),
+            ("P1D2Y", false),
+        ] {
+            assert_eq!(pattern.is_match(text), valid, "{}", text);
+        }
+        // the pattern can't bound the numbers, so this matches but is out of range
+        assert!(pattern.is_match("P99999999999D"));
+        assert!(Interval::from_iso8601("P99999999999D").is_err());
+    }
+
+    #[test]
+    fn options() {
+        let schema = schemars::schema_for!(DiffOptions);
+        let properties = schema
+            .get("properties")
+            .and_then(|p| p.as_object())
+            .unwrap();
+        for name in &[
+            "algorithm",
+            "inclusive_end",
+            "symmetric",
+            "month_end",
+            "leap_day",
+            "rounding",
+            "week_start",
+        ] {
+            assert!(properties.contains_key(*name), "{}", name);
+        }
+        // every field has a default
+        assert_eq!(schema.get("required"), None);
+
+        let schema = schemars::schema_for!(DisplayOptions);
+        assert!(schema
+            .get("properties")
+            .unwrap()
+            .get("sign_style")
+            .is_some());
+        let schema = schemars::schema_for!(HumanizeThresholds);
+        assert!(schema.get("properties").unwrap().get("just_now").is_some());
+    }
+}
@@ -0,0 +1,384 @@
+//! Serde representations of ```Interval``` for ```#[serde(with = "...")]```,
+//! when a field needs another one than the ISO 8601 string or tuple of its
+//! ```Serialize``` implementation:
+//!
+//! - ```iso8601```: ```"P2Y3M4D"```, also in binary formats
+//! - ```struct_repr```: ```{"years": 2, "months": 3, "days": 4, "positive": true}```
+//! - ```tuple_repr```: ```[2, 3, 4, true]```, also in human readable formats
+//! - ```postgres```: ```"2 years 3 mons 4 days"``` as Postgres writes it
+//!
+//! Needs the ```serde``` feature.
+//!
+//! # Example
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # {
+//! use serde::{Deserialize, Serialize};
+//!
+//! use datediff::Interval;
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Contract {
+//!     #[serde(with = "datediff::serde::postgres")]
+//!     term: Interval,
+//!     #[serde(with = "datediff::serde::struct_repr")]
+//!     notice: Interval,
+//! }
+//! # }
+//! ```
+
+use std::fmt;
+
+use ::serde::{
+    de::{self, SeqAccess, Visitor},
+    ser::SerializeTuple,
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{Interval, IntervalError};
+
+/// Builds the interval the way ```Interval::from_iso8601``` does, months
+/// over 11 carry into years and days are kept as they are, so anything
+/// serialized reads back. Only years over ```Interval::MAX_YEARS``` and
+/// days over ```i32::MAX``` fail.
+fn from_parts<E: de::Error>(
+    years: u32,
+    months: u32,
+    days: u32,
+    positive: bool,
+) -> Result<Interval, E> {
+    let total_months = i64::from(years) * 12 + i64::from(months);
+    match Interval::checked_from_months_days(total_months, i64::from(days)) {
+        Some(interval) => Ok(interval.with_sign(positive)),
+        None if days > i32::MAX as u32 => Err(E::custom(IntervalError::DaysOutOfRange(days))),
+        None => Err(E::custom(IntervalError::YearsOutOfRange(years))),
+    }
+}
+
+/// An ISO 8601 duration like ```"P2Y3M4D"``` for human readable formats
+/// such as JSON and YAML, and a ```(years, months, days, positive)``` tuple
+/// for binary ones such as bincode.
+///
+/// Needs the ```serde``` feature.
+impl Serialize for Interval {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_iso8601())
+        } else {
+            let mut tuple = serializer.serialize_tuple(4)?;
+            tuple.serialize_element(&self.years)?;
+            tuple.serialize_element(&self.months)?;
+            tuple.serialize_element(&self.days)?;
+            tuple.serialize_element(&self.positive)?;
+            tuple.end()
+        }
+    }
+}
+
+struct IntervalVisitor;
+
+impl<'de> Visitor<'de> for IntervalVisitor {
+    type Value = Interval;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "an ISO 8601 duration or a (years, months, days, positive) tuple"
+        )
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Interval, E> {
+        Interval::from_iso8601(s).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Interval, A::Error> {
+        let mut next = |index| {
+            seq.next_element()?
+                .ok_or_else(|| de::Error::invalid_length(index, &self))
+        };
+        let years = next(0)?;
+        let months = next(1)?;
+        let days = next(2)?;
+        let positive = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(3, &self))?;
+        from_parts(years, months, days, positive)
+    }
+}
+
+impl<'de> Deserialize<'de> for Interval {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Interval, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(IntervalVisitor)
+        } else {
+            deserializer.deserialize_tuple(4, IntervalVisitor)
+        }
+    }
+}
+
+/// Always the ISO 8601 duration string, like ```Interval::to_iso8601```.
+pub mod iso8601 {
+    use ::serde::{Deserializer, Serializer};
+
+    use super::IntervalVisitor;
+    use crate::Interval;
+
+    pub fn serialize<S: Serializer>(interval: &Interval, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&interval.to_iso8601())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Interval, D::Error> {
+        deserializer.deserialize_str(IntervalVisitor)
+    }
+}
+
+/// A struct with ```years```, ```months```, ```days``` and ```positive```.
+pub mod struct_repr {
+    use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::from_parts;
+    use crate::Interval;
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "Interval")]
+    struct Repr {
+        years: u32,
+        months: u32,
+        days: u32,
+        positive: bool,
+    }
+
+    pub fn serialize<S: Serializer>(interval: &Interval, serializer: S) -> Result<S::Ok, S::Error> {
+        Repr {
+            years: interval.years,
+            months: interval.months,
+            days: interval.days,
+            positive: interval.positive,
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Interval, D::Error> {
+        let repr = Repr::deserialize(deserializer)?;
+        from_parts(repr.years, repr.months, repr.days, repr.positive)
+    }
+}
+
+/// Always the ```(years, months, days, positive)``` tuple.
+pub mod tuple_repr {
+    use ::serde::{Deserializer, Serialize, Serializer};
+
+    use super::IntervalVisitor;
+    use crate::Interval;
+
+    pub fn serialize<S: Serializer>(interval: &Interval, serializer: S) -> Result<S::Ok, S::Error> {
+        (
+            interval.years,
+            interval.months,
+            interval.days,
+            interval.positive,
+        )
+            .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Interval, D::Error> {
+        deserializer.deserialize_tuple(4, IntervalVisitor)
+    }
+}
+
+/// A Postgres interval string, see ```Interval::to_postgres_string``` and
+/// ```Interval::from_postgres_str```.
+pub mod postgres {
+    use ::serde::{de, Deserialize, Deserializer, Serializer};
+
+    use crate::Interval;
+
+    pub fn serialize<S: Serializer>(interval: &Interval, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&interval.to_postgres_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Interval, D::Error> {
+        let text = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+        Interval::from_postgres_str(&text).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use ::serde::{Deserialize, Serialize};
+    use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Token};
+
+    use crate::{DiffOptions, HumanizeThresholds, Interval, RoundingMode, Unit};
+
+    #[test]
+    fn serde() {
+        let interval = Interval::new(2, 3, 4, false).unwrap();
+        assert_tokens(&interval.readable(), &[Token::Str("-P2Y3M4D")]);
+        assert_tokens(&Interval::zero().readable(), &[Token::Str("P0D")]);
+        assert_tokens(
+            &interval.compact(),
+            &[
+                Token::Tuple { len: 4 },
+                Token::U32(2),
+                Token::U32(3),
+                Token::U32(4),
+                Token::Bool(false),
+                Token::TupleEnd,
+            ],
+        );
+
+        assert_de_tokens_error::<serde_test::Readable<Interval>>(
+            &[Token::Str("3 months")],
+            "invalid format: \"3 months\"",
+        );
+        assert_de_tokens_error::<serde_test::Compact<Interval>>(
+            &[
+                Token::Tuple { len: 4 },
+                Token::U32(u32::MAX),
+                Token::U32(0),
+                Token::U32(0),
+                Token::Bool(true),
+                Token::TupleEnd,
+            ],
+            "years out of range: 4294967295 (expected 0..=2147483647)",
+        );
+
+        // built like from_iso8601, so days over 30 read back in both
+        let days = Interval::of_days(45);
+        assert_tokens(&days.readable(), &[Token::Str("P45D")]);
+        assert_tokens(
+            &days.compact(),
+            &[
+                Token::Tuple { len: 4 },
+                Token::U32(0),
+                Token::U32(0),
+                Token::U32(45),
+                Token::Bool(true),
+                Token::TupleEnd,
+            ],
+        );
+        assert_de_tokens(
+            &Interval::of_years(1).compact(),
+            &[
+                Token::Tuple { len: 4 },
+                Token::U32(0),
+                Token::U32(12),
+                Token::U32(0),
+                Token::Bool(true),
+                Token::TupleEnd,
+            ],
+        );
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+    struct Fields {
+        #[serde(with = "crate::serde::iso8601")]
+        iso8601: Interval,
+        #[serde(with = "crate::serde::struct_repr")]
+        struct_repr: Interval,
+        #[serde(with = "crate::serde::tuple_repr")]
+        tuple_repr: Interval,
+        #[serde(with = "crate::serde::postgres")]
+        postgres: Interval,
+    }
+
+    #[test]
+    fn with_modules() {
+        for &(interval, iso8601, (years, months, days), postgres) in &[
+            (
+                Interval::new(2, 3, 4, false).unwrap(),
+                "-P2Y3M4D",
+                (2, 3, 4),
+                "-2 years -3 mons -4 days",
+            ),
+            (Interval::of_days(45), "P45D", (0, 0, 45), "45 days"),
+        ] {
+            let fields = Fields {
+                iso8601: interval,
+                struct_repr: interval,
+                tuple_repr: interval,
+                postgres: interval,
+            };
+            let tokens = [
+                Token::Struct {
+                    name: "Fields",
+                    len: 4,
+                },
+                Token::Str("iso8601"),
+                Token::Str(iso8601),
+                Token::Str("struct_repr"),
+                Token::Struct {
+                    name: "Interval",
+                    len: 4,
+                },
+                Token::Str("years"),
+                Token::U32(years),
+                Token::Str("months"),
+                Token::U32(months),
+                Token::Str("days"),
+                Token::U32(days),
+                Token::Str("positive"),
+                Token::Bool(interval.positive()),
+                Token::StructEnd,
+                Token::Str("tuple_repr"),
+                Token::Tuple { len: 4 },
+                Token::U32(years),
+                Token::U32(months),
+                Token::U32(days),
+                Token::Bool(interval.positive()),
+                Token::TupleEnd,
+                Token::Str("postgres"),
+                Token::Str(postgres),
+                Token::StructEnd,
+            ];
+            // the same whether the format is human readable or not
+            assert_tokens(&fields.readable(), &tokens);
+            assert_tokens(&fields.compact(), &tokens);
+        }
+    }
+
+    #[test]
+    fn options() {
+        assert_tokens(
+            &HumanizeThresholds::new().with_weeks(14),
+            &[
+                Token::Struct {
+                    name: "HumanizeThresholds",
+                    len: 3,
+                },
+                Token::Str("just_now"),
+                Token::U32(1),
+                Token::Str("weeks"),
+                Token::U32(14),
+                Token::Str("years"),
+                Token::U32(12),
+                Token::StructEnd,
+            ],
+        );
+
+        // missing fields take their defaults
+        assert_de_tokens(
+            &DiffOptions::new().with_rounding(Unit::Month, RoundingMode::HalfUp),
+            &[
+                Token::Struct {
+                    name: "DiffOptions",
+                    len: 1,
+                },
+                Token::Str("rounding"),
+                Token::Some,
+                Token::Tuple { len: 2 },
+                Token::UnitVariant {
+                    name: "Unit",
+                    variant: "Month",
+                },
+                Token::UnitVariant {
+                    name: "RoundingMode",
+                    variant: "HalfUp",
+                },
+                Token::TupleEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,386 @@
+use std::{
+    convert::TryFrom,
+    fmt,
+    ops::{Add, Neg, Sub},
+};
+
+use chrono::NaiveDate;
+
+use crate::{checked_add_months, get_diff, plural, EomPolicy, Interval, IntervalError};
+
+/// Holds years, months and days that may each have their own sign, like a
+/// Postgres interval such as "+1 month -3 days". Unlike ```Interval```
+/// it can represent the result of any component-wise arithmetic exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SignedInterval {
+    years: i32,
+    months: i32,
+    days: i32,
+}
+
+impl SignedInterval {
+    /// Builds a SignedInterval from its components, no range is enforced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::NaiveDate;
+    ///
+    /// use datediff::SignedInterval;
+    ///
+    /// let interval = SignedInterval::new(0, 1, -3);
+    ///
+    /// // 2020-01-10 + 1 month = 2020-02-10, - 3 days = 2020-02-07
+    /// let date = NaiveDate::from_ymd_opt(2020, 1, 10).unwrap();
+    /// # assert_eq!(interval.to_interval_at(date).days(), 28);
+    /// ```
+    pub fn new(years: i32, months: i32, days: i32) -> SignedInterval {
+        SignedInterval {
+            years,
+            months,
+            days,
+        }
+    }
+
+    pub fn years(&self) -> i32 {
+        self.years
+    }
+    pub fn months(&self) -> i32 {
+        self.months
+    }
+    pub fn days(&self) -> i32 {
+        self.days
+    }
+
+    /// Tells whether all components are zero.
+    pub fn is_zero(&self) -> bool {
+        self.years == 0 && self.months == 0 && self.days == 0
+    }
+
+    /// Moves ```date``` by the interval, months first and then days,
+    /// the same way as ```Interval::checked_apply_to```.
+    pub fn checked_apply_to(&self, date: NaiveDate, policy: EomPolicy) -> Option<NaiveDate> {
+        let months = i64::from(self.years) * 12 + i64::from(self.months);
+        checked_add_months(date, months, policy)?
+            .checked_add_signed(chrono::Duration::days(i64::from(self.days)))
+    }
+
+    /// Resolves mixed signs by applying the interval to ```anchor``` with
+    /// ```EomPolicy::Clamp``` and taking the difference to the date reached.
+    /// Panics when out of range.
+    pub fn to_interval_at(&self, anchor: NaiveDate) -> Interval {
+        let end = self
+            .checked_apply_to(anchor, EomPolicy::Clamp)
+            .expect("date out of range");
+        get_diff(&anchor, &end)
+    }
+
+    /// Same as ```+```, but gives ```None``` instead of panicking when a
+    /// component overflows an ```i32```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::SignedInterval;
+    ///
+    /// let most = SignedInterval::new(i32::MAX, 0, 0);
+    ///
+    /// # assert_eq!(most.checked_add(SignedInterval::new(1, 0, 0)), None);
+    /// # assert_eq!(most.checked_add(SignedInterval::new(-1, 1, 0)), Some(SignedInterval::new(i32::MAX - 1, 1, 0)));
+    /// ```
+    pub fn checked_add(self, other: SignedInterval) -> Option<SignedInterval> {
+        Some(SignedInterval::new(
+            self.years.checked_add(other.years)?,
+            self.months.checked_add(other.months)?,
+            self.days.checked_add(other.days)?,
+        ))
+    }
+
+    /// Same as ```-```, but gives ```None``` instead of panicking when a
+    /// component overflows an ```i32```.
+    pub fn checked_sub(self, other: SignedInterval) -> Option<SignedInterval> {
+        Some(SignedInterval::new(
+            self.years.checked_sub(other.years)?,
+            self.months.checked_sub(other.months)?,
+            self.days.checked_sub(other.days)?,
+        ))
+    }
+
+    /// Same as unary ```-```, but gives ```None``` instead of panicking
+    /// when a component is ```i32::MIN```.
+    pub fn checked_neg(self) -> Option<SignedInterval> {
+        Some(SignedInterval::new(
+            self.years.checked_neg()?,
+            self.months.checked_neg()?,
+            self.days.checked_neg()?,
+        ))
+    }
+}
+
+/// Applies the sign of the interval to every component. This never fails,
+/// ```Interval``` keeps years, months and days within an ```i32```.
+impl From<Interval> for SignedInterval {
+    fn from(interval: Interval) -> SignedInterval {
+        let (years, months, days) = interval.signed();
+        SignedInterval::new(years, months, days)
+    }
+}
+
+/// Converts when all non-zero components agree in sign, fails with
+/// ```IntervalError::MixedSigns``` otherwise, see
+/// ```SignedInterval::to_interval_at``` for those. Components outside the
+/// usual ranges are kept as they are, except for ```i32::MIN``` which has
+/// no positive counterpart and fails with the matching ```OutOfRange```
+/// error.
+///
+/// # Example
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use datediff::{Interval, IntervalError, SignedInterval};
+///
+/// # assert_eq!(Interval::try_from(SignedInterval::new(-2, -3, 0)), Interval::new(2, 3, 0, false));
+/// # assert_eq!(Interval::try_from(SignedInterval::new(0, 1, -3)), Err(IntervalError::MixedSigns));
+/// ```
+impl TryFrom<SignedInterval> for Interval {
+    type Error = IntervalError;
+
+    fn try_from(signed: SignedInterval) -> Result<Interval, IntervalError> {
+        let parts = [signed.years, signed.months, signed.days];
+        let positive = parts.iter().all(|&part| part >= 0);
+        if !positive && !parts.iter().all(|&part| part <= 0) {
+            return Err(IntervalError::MixedSigns);
+        }
+        let limit = i32::MAX as u32;
+        let (years, months, days) = (
+            signed.years.unsigned_abs(),
+            signed.months.unsigned_abs(),
+            signed.days.unsigned_abs(),
+        );
+        if years > limit {
+            return Err(IntervalError::YearsOutOfRange(years));
+        }
+        if months > limit {
+            return Err(IntervalError::MonthsOutOfRange(months));
+        }
+        if days > limit {
+            return Err(IntervalError::DaysOutOfRange(days));
+        }
+        Ok(Interval::builder()
+            .years(years)
+            .months(months)
+            .days(days)
+            .build_unchecked()
+            .with_sign(positive))
+    }
+}
+
+/// Adds component-wise, without any carrying or borrowing.
+///
+/// Panics with "interval overflow" when a component overflows an
+/// ```i32```, see ```SignedInterval::checked_add```.
+impl Add for SignedInterval {
+    type Output = SignedInterval;
+
+    fn add(self, other: SignedInterval) -> SignedInterval {
+        self.checked_add(other).expect("interval overflow")
+    }
+}
+
+/// Subtracts component-wise, without any carrying or borrowing.
+///
+/// Panics with "interval overflow" when a component overflows an
+/// ```i32```, see ```SignedInterval::checked_sub```.
+impl Sub for SignedInterval {
+    type Output = SignedInterval;
+
+    fn sub(self, other: SignedInterval) -> SignedInterval {
+        self.checked_sub(other).expect("interval overflow")
+    }
+}
+
+/// Negates every component.
+///
+/// Panics with "interval overflow" when a component is ```i32::MIN```, see
+/// ```SignedInterval::checked_neg```.
+impl Neg for SignedInterval {
+    type Output = SignedInterval;
+
+    fn neg(self) -> SignedInterval {
+        self.checked_neg().expect("interval overflow")
+    }
+}
+
+impl fmt::Display for SignedInterval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "({:+} year{} {:+} month{} {:+} day{})",
+            self.years,
+            plural(i64::from(self.years)),
+            self.months,
+            plural(i64::from(self.months)),
+            self.days,
+            plural(i64::from(self.days))
+        )
+    }
+}
+
+/// Total months and days, the way Postgres and Arrow intervals hold them
+/// next to a part below a day.
+#[cfg(any(feature = "arrow", feature = "diesel", feature = "sqlx"))]
+impl SignedInterval {
+    /// Fails with ```IntervalError::YearsOutOfRange``` when the months don't
+    /// fit in an ```i32```, i.e. from about 179 million years.
+    pub(crate) fn to_months_days(self) -> Result<(i32, i32), IntervalError> {
+        let months = i64::from(self.years) * 12 + i64::from(self.months);
+        let months = i32::try_from(months)
+            .map_err(|_| IntervalError::YearsOutOfRange(self.years.unsigned_abs()))?;
+        Ok((months, self.days))
+    }
+
+    /// Splits ```months``` into years and months, fails with
+    /// ```IntervalError::NotWholeDays``` when ```sub_day```, e.g. the
+    /// microseconds of a Postgres INTERVAL, isn't zero.
+    pub(crate) fn from_months_days(
+        months: i32,
+        days: i32,
+        sub_day: i64,
+    ) -> Result<SignedInterval, IntervalError> {
+        if sub_day != 0 {
+            return Err(IntervalError::NotWholeDays(sub_day));
+        }
+        Ok(SignedInterval::new(months / 12, months % 12, days))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::TryFrom;
+
+    use chrono::NaiveDate;
+
+    use crate::{Interval, IntervalError, SignedInterval};
+
+    #[test]
+    fn conversions() {
+        let interval = Interval::new(2, 3, 4, false).unwrap();
+        let signed = SignedInterval::from(interval);
+        assert_eq!(signed, SignedInterval::new(-2, -3, -4));
+        assert_eq!(Interval::try_from(signed), Ok(interval));
+        assert_eq!(
+            Interval::try_from(SignedInterval::default()),
+            Ok(Interval::zero())
+        );
+        assert_eq!(
+            Interval::try_from(SignedInterval::new(1, 0, -1)),
+            Err(IntervalError::MixedSigns)
+        );
+        assert_eq!(
+            Interval::try_from(SignedInterval::new(0, 14, 0)).map(|i| i.months()),
+            Ok(14)
+        );
+        assert_eq!(
+            Interval::try_from(SignedInterval::new(0, 0, i32::MIN)),
+            Err(IntervalError::DaysOutOfRange(1 << 31))
+        );
+        assert_eq!(
+            Interval::try_from(SignedInterval::new(i32::MIN, 0, 0)),
+            Err(IntervalError::YearsOutOfRange(1 << 31))
+        );
+        assert_eq!(
+            Interval::try_from(SignedInterval::new(-i32::MAX, 0, -i32::MAX)).map(|i| i.signed()),
+            Ok((-i32::MAX, 0, -i32::MAX))
+        );
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = SignedInterval::from(Interval::of_months(1));
+        let b = SignedInterval::from(Interval::of_days(3));
+        assert_eq!(a - b, SignedInterval::new(0, 1, -3));
+        assert_eq!(-(a - b), SignedInterval::new(0, -1, 3));
+        assert!((a - a).is_zero());
+        assert_eq!((a - b).to_string(), "(+0 years +1 month -3 days)");
+
+        let anchor = NaiveDate::from_ymd_opt(2020, 3, 1).unwrap();
+        assert_eq!(
+            (a - b).to_interval_at(anchor),
+            Interval::new(0, 0, 28, true).unwrap()
+        );
+        assert_eq!(
+            (b - a).to_interval_at(anchor),
+            Interval::new(0, 0, 26, false).unwrap()
+        );
+
+        let (max, min) = (
+            SignedInterval::new(i32::MAX, 0, 0),
+            SignedInterval::new(0, 0, i32::MIN),
+        );
+        let one = SignedInterval::new(1, 1, 1);
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(min.checked_sub(one), None);
+        assert_eq!(min.checked_neg(), None);
+        assert_eq!(
+            max.checked_neg(),
+            Some(SignedInterval::new(-i32::MAX, 0, 0))
+        );
+        assert_eq!(
+            SignedInterval::new(-1, 0, 0).checked_sub(SignedInterval::new(i32::MIN, 0, 0)),
+            Some(max)
+        );
+        assert_eq!(max.checked_add(-one), Some(max - one));
+    }
+
+    #[cfg(any(feature = "arrow", feature = "diesel", feature = "sqlx"))]
+    #[test]
+    fn months_days() {
+        for &(signed, months, days) in &[
+            (SignedInterval::default(), 0, 0),
+            (SignedInterval::new(0, 0, 30), 0, 30),
+            (SignedInterval::new(1, 11, 0), 23, 0),
+            (SignedInterval::new(-2, -3, -4), -27, -4),
+            (SignedInterval::new(0, 1, -3), 1, -3),
+            (SignedInterval::new(-2, -1, 20), -25, 20),
+            (
+                SignedInterval::new(178_956_970, 7, i32::MIN),
+                i32::MAX,
+                i32::MIN,
+            ),
+            (SignedInterval::new(-178_956_970, -8, 0), i32::MIN, 0),
+        ] {
+            assert_eq!(signed.to_months_days(), Ok((months, days)), "{}", signed);
+            assert_eq!(
+                SignedInterval::from_months_days(months, days, 0),
+                Ok(signed),
+                "{}",
+                signed
+            );
+        }
+        assert_eq!(
+            SignedInterval::from(Interval::MAX).to_months_days(),
+            Err(IntervalError::YearsOutOfRange(Interval::MAX_YEARS))
+        );
+        assert_eq!(
+            SignedInterval::new(-178_956_970, -9, 0).to_months_days(),
+            Err(IntervalError::YearsOutOfRange(178_956_970))
+        );
+        assert_eq!(
+            SignedInterval::from_months_days(0, 1, -1),
+            Err(IntervalError::NotWholeDays(-1))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "interval overflow")]
+    fn add_overflow() {
+        let _ = SignedInterval::new(i32::MAX, 0, 0) + SignedInterval::new(1, 0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "interval overflow")]
+    fn neg_overflow() {
+        let _ = -SignedInterval::new(i32::MIN, 0, 0);
+    }
+}
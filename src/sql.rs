@@ -0,0 +1,360 @@
+use std::convert::TryFrom;
+
+use crate::{Interval, ParseIntervalError, SignedInterval};
+
+impl SignedInterval {
+    /// Formats the interval like PostgreSQL does with its default
+    /// ```IntervalStyle```, e.g. ```1 year 2 mons 3 days``` or
+    /// ```-1 mons +3 days```, and ```00:00:00``` when zero. Years and months
+    /// are combined first since Postgres only keeps total months.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::SignedInterval;
+    ///
+    /// # assert_eq!(SignedInterval::new(2, 3, 4).to_postgres_string(), "2 years 3 mons 4 days");
+    /// # assert_eq!(SignedInterval::new(0, -1, 3).to_postgres_string(), "-1 mons +3 days");
+    /// # assert_eq!(SignedInterval::new(1, -1, 0).to_postgres_string(), "11 mons");
+    /// ```
+    pub fn to_postgres_string(&self) -> String {
+        let months = i64::from(self.years()) * 12 + i64::from(self.months());
+        let parts = [
+            (months / 12, "year"),
+            (months % 12, "mon"),
+            (i64::from(self.days()), "day"),
+        ];
+        let mut text = String::new();
+        let mut negative_before = false;
+        for &(value, unit) in parts.iter().filter(|&&(value, _)| value != 0) {
+            if !text.is_empty() {
+                text.push(' ');
+            }
+            //a positive value after a negative one gets an explicit sign
+            let sign = if negative_before && value > 0 {
+                "+"
+            } else {
+                ""
+            };
+            let plural = if value == 1 { "" } else { "s" };
+            text.push_str(&format!("{}{} {}{}", sign, value, unit, plural));
+            negative_before = value < 0;
+        }
+        if text.is_empty() {
+            text.push_str("00:00:00");
+        }
+        text
+    }
+
+    /// Parses PostgreSQL interval text like ```2 years 3 mons 4 days```,
+    /// ```-1 mons +3 days``` or ```1 year ago```. Each number may have its
+    /// own sign and units may be singular, plural or abbreviated as Postgres
+    /// accepts them (```y```, ```mon```, ```w```, ```d```, ...). A time of
+    /// day such as ```00:00:00``` is accepted only when zero. Months over 11
+    /// carry into years like in Postgres.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::SignedInterval;
+    ///
+    /// # assert_eq!(SignedInterval::from_postgres_str("-1 mons +3 days"), Ok(SignedInterval::new(0, -1, 3)));
+    /// # assert_eq!(SignedInterval::from_postgres_str("@ 1 year 2 weeks ago"), Ok(SignedInterval::new(-1, 0, -14)));
+    /// # assert_eq!(SignedInterval::from_postgres_str("00:00:00"), Ok(SignedInterval::default()));
+    /// ```
+    pub fn from_postgres_str(s: &str) -> Result<SignedInterval, ParseIntervalError> {
+        let mut words: Vec<&str> = s.split_whitespace().collect();
+        if words.first() == Some(&"@") {
+            words.remove(0);
+        }
+        let ago = matches!(words.last(), Some(word) if word.eq_ignore_ascii_case("ago"));
+        if ago {
+            words.pop();
+        }
+        if words.is_empty() {
+            return Err(ParseIntervalError::Empty);
+        }
+
+        let (mut months, mut days) = (0i64, 0i64);
+        let mut seen = Vec::new();
+        let mut words = words.into_iter();
+        while let Some(word) = words.next() {
+            if word.contains(':') {
+                if !word
+                    .trim_start_matches(['+', '-'])
+                    .split(':')
+                    .all(|part| !part.is_empty() && part.bytes().all(|b| b == b'0'))
+                {
+                    return Err(ParseIntervalError::NonZeroTime(word.to_string()));
+                }
+                continue;
+            }
+            let value: i64 = match word.parse() {
+                Ok(value) => value,
+                Err(_)
+                    if word
+                        .trim_start_matches(['+', '-'])
+                        .bytes()
+                        .all(|b| b.is_ascii_digit()) =>
+                {
+                    return Err(ParseIntervalError::Overflow)
+                }
+                Err(_) => return Err(ParseIntervalError::InvalidNumber(word.to_string())),
+            };
+            let unit = words
+                .next()
+                .ok_or_else(|| ParseIntervalError::MissingUnit(word.to_string()))?;
+            let (scale, into_days, key) = match unit.to_ascii_lowercase().as_str() {
+                "y" | "yr" | "yrs" | "year" | "years" => (12, false, 'y'),
+                "mon" | "mons" | "month" | "months" => (1, false, 'm'),
+                "w" | "week" | "weeks" => (7, true, 'w'),
+                "d" | "day" | "days" => (1, true, 'd'),
+                _ => return Err(ParseIntervalError::UnknownUnit(unit.to_string())),
+            };
+            if seen.contains(&key) {
+                return Err(ParseIntervalError::DuplicateUnit(key));
+            }
+            seen.push(key);
+            let total = if into_days { &mut days } else { &mut months };
+            *total = value
+                .checked_mul(scale)
+                .and_then(|value| total.checked_add(value))
+                .ok_or(ParseIntervalError::Overflow)?;
+        }
+
+        if ago {
+            months = -months;
+            days = -days;
+        }
+        let years = i32::try_from(months / 12).map_err(|_| ParseIntervalError::Overflow)?;
+        let days = i32::try_from(days).map_err(|_| ParseIntervalError::Overflow)?;
+        Ok(SignedInterval::new(years, (months % 12) as i32, days))
+    }
+}
+
+impl Interval {
+    /// Formats the interval like PostgreSQL does, e.g.
+    /// ```2 years 3 mons 4 days``` or ```-5 days```, see
+    /// ```SignedInterval::to_postgres_string```.
+    pub fn to_postgres_string(&self) -> String {
+        SignedInterval::from(*self).to_postgres_string()
+    }
+
+    /// Parses PostgreSQL interval text, see
+    /// ```SignedInterval::from_postgres_str```. Fails with
+    /// ```ParseIntervalError::MixedSigns``` for text like
+    /// ```-1 mons +3 days```, which only a SignedInterval can hold.
+    pub fn from_postgres_str(s: &str) -> Result<Interval, ParseIntervalError> {
+        Interval::try_from(SignedInterval::from_postgres_str(s)?)
+            .map_err(|_| ParseIntervalError::MixedSigns)
+    }
+}
+
+/// SQL dialect for ```Interval::to_sql_literal```.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SqlDialect {
+    /// Standard SQL interval literals, which Oracle accepts too.
+    Standard,
+    /// PostgreSQL, one literal in its own interval syntax.
+    Postgres,
+    /// MySQL, which only has interval expressions to add to a date.
+    MySql,
+    /// Oracle, like the standard.
+    Oracle,
+}
+
+impl Interval {
+    /// Formats the interval as SQL for ```dialect```, to embed in generated
+    /// queries like ```SELECT hired + <literal>```.
+    ///
+    /// Standard SQL and Oracle keep years and months apart from days, so an
+    /// interval with both is two literals added together, e.g.
+    /// ```INTERVAL '2-3' YEAR TO MONTH + INTERVAL '4' DAY```. The leading
+    /// precision is given when over the default of 2 digits, as in
+    /// ```INTERVAL '400' DAY(3)```. MySQL gives
+    /// ```INTERVAL '2-3' YEAR_MONTH + INTERVAL 4 DAY```, which is only valid
+    /// after a date and ```+```. Postgres gives a single literal like
+    /// ```INTERVAL '2 years 3 mons 4 days'```.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use datediff::{Interval, SqlDialect};
+    ///
+    /// let interval = Interval::new(2, 3, 4, true).unwrap();
+    ///
+    /// println!("SELECT hired + {}", interval.to_sql_literal(SqlDialect::Standard));
+    /// # assert_eq!(interval.to_sql_literal(SqlDialect::Standard), "INTERVAL '2-3' YEAR TO MONTH + INTERVAL '4' DAY");
+    /// # assert_eq!(interval.to_sql_literal(SqlDialect::MySql), "INTERVAL '2-3' YEAR_MONTH + INTERVAL 4 DAY");
+    /// # assert_eq!(interval.to_sql_literal(SqlDialect::Postgres), "INTERVAL '2 years 3 mons 4 days'");
+    /// # assert_eq!(Interval::of_days(4).inverse().to_sql_literal(SqlDialect::Oracle), "INTERVAL '-4' DAY");
+    /// ```
+    pub fn to_sql_literal(&self, dialect: SqlDialect) -> String {
+        let sign = if self.positive { "" } else { "-" };
+        let precision = |value: u32| match value.to_string().len() {
+            digits if digits > 2 => format!("({})", digits),
+            _ => String::new(),
+        };
+        let mut literals = Vec::new();
+        match dialect {
+            SqlDialect::Postgres => {
+                return format!("INTERVAL '{}'", self.to_postgres_string());
+            }
+            SqlDialect::Standard | SqlDialect::Oracle => {
+                if self.years != 0 || self.months != 0 {
+                    literals.push(format!(
+                        "INTERVAL '{}{}-{}' YEAR{} TO MONTH",
+                        sign,
+                        self.years,
+                        self.months,
+                        precision(self.years)
+                    ));
+                }
+                if self.days != 0 || literals.is_empty() {
+                    literals.push(format!(
+                        "INTERVAL '{}{}' DAY{}",
+                        sign,
+                        self.days,
+                        precision(self.days)
+                    ));
+                }
+            }
+            SqlDialect::MySql => {
+                if self.years != 0 || self.months != 0 {
+                    literals.push(format!(
+                        "INTERVAL '{}{}-{}' YEAR_MONTH",
+                        sign, self.years, self.months
+                    ));
+                }
+                if self.days != 0 || literals.is_empty() {
+                    literals.push(format!("INTERVAL {}{} DAY", sign, self.days));
+                }
+            }
+        }
+        literals.join(" + ")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Interval, ParseIntervalError, SignedInterval, SqlDialect};
+
+    #[test]
+    fn postgres() {
+        let signed = SignedInterval::new;
+
+        // SELECT interval '...' and its output
+        for &(text, expected) in &[
+            ("2 years 3 mons 4 days", signed(2, 3, 4)),
+            ("1 year 1 mon 1 day", signed(1, 1, 1)),
+            ("-1 mons +3 days", signed(0, -1, 3)),
+            ("-1 years -2 mons", signed(-1, -2, 0)),
+            ("1 year -3 days", signed(1, 0, -3)),
+            ("-1 years +2 days", signed(-1, 0, 2)),
+            ("-5 days", signed(0, 0, -5)),
+            ("40 days", signed(0, 0, 40)),
+            ("00:00:00", signed(0, 0, 0)),
+        ] {
+            assert_eq!(expected.to_postgres_string(), text);
+            assert_eq!(
+                SignedInterval::from_postgres_str(text),
+                Ok(expected),
+                "{}",
+                text
+            );
+        }
+
+        let parse = SignedInterval::from_postgres_str;
+        assert_eq!(parse("14 months"), Ok(signed(1, 2, 0)));
+        assert_eq!(parse("1 year -2 mons"), Ok(signed(0, 10, 0)));
+        assert_eq!(parse("1 Year 2 Weeks"), Ok(signed(1, 0, 14)));
+        assert_eq!(parse("3 d 00:00"), Ok(signed(0, 0, 3)));
+        assert_eq!(parse("2 y 1 mon ago"), Ok(signed(-2, -1, 0)));
+        assert_eq!(parse("1 week 2 days"), Ok(signed(0, 0, 9)));
+        assert_eq!(parse(" "), Err(ParseIntervalError::Empty));
+        assert_eq!(parse("ago"), Err(ParseIntervalError::Empty));
+        assert_eq!(
+            parse("1 day 01:00:00"),
+            Err(ParseIntervalError::NonZeroTime("01:00:00".to_string()))
+        );
+        assert_eq!(
+            parse("1.5 days"),
+            Err(ParseIntervalError::InvalidNumber("1.5".to_string()))
+        );
+        assert_eq!(
+            parse("2 days 3"),
+            Err(ParseIntervalError::MissingUnit("3".to_string()))
+        );
+        assert_eq!(
+            parse("2 fortnights"),
+            Err(ParseIntervalError::UnknownUnit("fortnights".to_string()))
+        );
+        assert_eq!(
+            parse("1 day 2 days"),
+            Err(ParseIntervalError::DuplicateUnit('d'))
+        );
+        assert_eq!(parse("99999999999 days"), Err(ParseIntervalError::Overflow));
+        assert_eq!(
+            parse("99999999999999999999 days"),
+            Err(ParseIntervalError::Overflow)
+        );
+
+        let interval = Interval::new(2, 3, 4, false).unwrap();
+        assert_eq!(interval.to_postgres_string(), "-2 years -3 mons -4 days");
+        assert_eq!(
+            Interval::from_postgres_str("-2 years -3 mons -4 days"),
+            Ok(interval)
+        );
+        assert_eq!(
+            Interval::from_postgres_str("-1 mons +3 days"),
+            Err(ParseIntervalError::MixedSigns)
+        );
+    }
+
+    #[test]
+    fn sql_literals() {
+        let interval = |y, m, d, p| Interval::new(y, m, d, p).unwrap();
+        let literal = |i: Interval, dialect| i.to_sql_literal(dialect);
+
+        for &(i, standard, mysql) in &[
+            (
+                interval(2, 3, 4, true),
+                "INTERVAL '2-3' YEAR TO MONTH + INTERVAL '4' DAY",
+                "INTERVAL '2-3' YEAR_MONTH + INTERVAL 4 DAY",
+            ),
+            (
+                interval(0, 5, 0, false),
+                "INTERVAL '-0-5' YEAR TO MONTH",
+                "INTERVAL '-0-5' YEAR_MONTH",
+            ),
+            (
+                interval(0, 0, 4, true),
+                "INTERVAL '4' DAY",
+                "INTERVAL 4 DAY",
+            ),
+            (
+                Interval::of_days(400),
+                "INTERVAL '400' DAY(3)",
+                "INTERVAL 400 DAY",
+            ),
+            (
+                interval(150, 0, 0, false),
+                "INTERVAL '-150-0' YEAR(3) TO MONTH",
+                "INTERVAL '-150-0' YEAR_MONTH",
+            ),
+            (Interval::zero(), "INTERVAL '0' DAY", "INTERVAL 0 DAY"),
+        ] {
+            assert_eq!(literal(i, SqlDialect::Standard), standard, "{}", i);
+            assert_eq!(literal(i, SqlDialect::Oracle), standard, "{}", i);
+            assert_eq!(literal(i, SqlDialect::MySql), mysql, "{}", i);
+        }
+        assert_eq!(
+            literal(interval(1, 0, 2, false), SqlDialect::Postgres),
+            "INTERVAL '-1 years -2 days'"
+        );
+        assert_eq!(
+            literal(Interval::zero(), SqlDialect::Postgres),
+            "INTERVAL '00:00:00'"
+        );
+    }
+}
@@ -0,0 +1,116 @@
+//! Statistics over collections of intervals, e.g. average customer lifetime.
+//!
+//! Intervals are measured in days from a common anchor date, so the mean
+//! and median are turned back into an ```Interval``` starting at the anchor.
+//! Every function gives ```None``` for an empty slice.
+//!
+//! # Example
+//!
+//! ```
+//! use chrono::NaiveDate;
+//!
+//! use datediff::{stats, Interval};
+//!
+//! let lifetimes = [Interval::of_months(3), Interval::of_months(5), Interval::new(1, 1, 0, true).unwrap()];
+//! let anchor = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+//!
+//! println!("Average lifetime is {}", stats::mean(&lifetimes, anchor).unwrap());
+//! # assert_eq!(stats::median(&lifetimes, anchor), Some(Interval::of_months(5)));
+//! # assert_eq!(stats::max(&lifetimes, anchor), Some(Interval::new(1, 1, 0, true).unwrap()));
+//! ```
+
+use chrono::{Duration, NaiveDate};
+
+use crate::{get_diff, Interval};
+
+fn from_days(days: i64, anchor: NaiveDate) -> Interval {
+    get_diff(&anchor, &(anchor + Duration::days(days)))
+}
+
+/// Mean of the intervals, rounded to the nearest day.
+pub fn mean(intervals: &[Interval], anchor: NaiveDate) -> Option<Interval> {
+    if intervals.is_empty() {
+        return None;
+    }
+    let total: i64 = intervals.iter().map(|i| i.total_days(anchor)).sum();
+    let count = intervals.len() as i64;
+    //round half away from zero
+    let mean = (2 * total + total.signum() * count) / (2 * count);
+    Some(from_days(mean, anchor))
+}
+
+/// Median of the intervals. For an even count it's the mean of the two
+/// middle intervals, rounded to the nearest day.
+pub fn median(intervals: &[Interval], anchor: NaiveDate) -> Option<Interval> {
+    if intervals.is_empty() {
+        return None;
+    }
+    let mut days: Vec<i64> = intervals.iter().map(|i| i.total_days(anchor)).collect();
+    days.sort_unstable();
+    let middle = days.len() / 2;
+    if days.len() % 2 == 1 {
+        Some(from_days(days[middle], anchor))
+    } else {
+        let total = days[middle - 1] + days[middle];
+        Some(from_days((total + total.signum()) / 2, anchor))
+    }
+}
+
+/// Shortest interval at ```anchor```, the first one on ties.
+pub fn min(intervals: &[Interval], anchor: NaiveDate) -> Option<Interval> {
+    intervals.iter().copied().min_by(|a, b| a.cmp_at(b, anchor))
+}
+
+/// Longest interval at ```anchor```, the last one on ties.
+pub fn max(intervals: &[Interval], anchor: NaiveDate) -> Option<Interval> {
+    intervals.iter().copied().max_by(|a, b| a.cmp_at(b, anchor))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::NaiveDate;
+
+    use crate::{stats, Interval};
+
+    #[test]
+    fn stats() {
+        let anchor = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+        let intervals = [
+            Interval::of_days(10),
+            Interval::of_months(1),
+            Interval::new(0, 0, 4, false).unwrap(),
+            Interval::of_days(31),
+        ];
+
+        // 10 + 31 - 4 + 31 = 68 days, 17 on average
+        assert_eq!(stats::mean(&intervals, anchor), Some(Interval::of_days(17)));
+        // (10 + 31) / 2 rounds up to 21 days
+        assert_eq!(
+            stats::median(&intervals, anchor),
+            Some(Interval::of_days(21))
+        );
+        assert_eq!(
+            stats::min(&intervals, anchor),
+            Some(Interval::new(0, 0, 4, false).unwrap())
+        );
+        assert_eq!(stats::max(&intervals, anchor), Some(Interval::of_days(31)));
+        assert_eq!(
+            stats::median(&intervals[..3], anchor),
+            Some(Interval::of_days(10))
+        );
+
+        let negative = [
+            Interval::of_days(3).inverse(),
+            Interval::of_days(4).inverse(),
+        ];
+        assert_eq!(
+            stats::mean(&negative, anchor),
+            Some(Interval::of_days(4).inverse())
+        );
+
+        assert_eq!(stats::mean(&[], anchor), None);
+        assert_eq!(stats::median(&[], anchor), None);
+        assert_eq!(stats::min(&[], anchor), None);
+        assert_eq!(stats::max(&[], anchor), None);
+    }
+}
@@ -0,0 +1,290 @@
+#[cfg(feature = "tz")]
+use std::{error, fmt};
+
+use chrono::{DateTime, TimeZone};
+#[cfg(feature = "tz")]
+use chrono::{Duration, LocalResult, NaiveDateTime, Offset};
+#[cfg(feature = "tz")]
+use chrono_tz::Tz;
+
+use crate::{get_diff_datetime, DateTimeInterval};
+
+/// How ```get_diff_tz``` reads the two instants.
+///
+/// They only differ when the UTC offset changes in between, e.g. across
+/// the start of daylight saving time in Berlin, 2021-03-27 12:00 to
+/// 2021-03-28 12:00 local time is 1 day on the wall clock but 23 hours of
+/// absolute time, since 02:00 to 03:00 was skipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TzMode {
+    /// Calendar components as shown on each instant's local clock, so a day
+    /// is a day whatever its length. A time repeated when clocks go back
+    /// reads the same both times.
+    #[default]
+    WallClock,
+    /// Exact elapsed time, broken down on the UTC calendar so every day is
+    /// 24 hours.
+    Absolute,
+}
+
+/// Takes two ```chrono::DateTime``` and gives the difference as
+/// DateTimeInterval, read as set by ```mode```.
+///
+/// Both modes agree on the sign, except with ```TzMode::WallClock``` around
+/// a backward transition, where the later instant can show the earlier
+/// time and the difference is negative.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{FixedOffset, TimeZone};
+///
+/// use datediff::{get_diff_tz, TzMode};
+///
+/// let winter = FixedOffset::east_opt(3600).unwrap();
+/// let summer = FixedOffset::east_opt(2 * 3600).unwrap();
+///
+/// let start = winter.with_ymd_and_hms(2021, 3, 27, 12, 0, 0).unwrap();
+/// let end = summer.with_ymd_and_hms(2021, 3, 28, 12, 0, 0).unwrap();
+///
+/// println!("Wall clock: {}", get_diff_tz(&start, &end, TzMode::WallClock));
+/// println!("Elapsed: {}", get_diff_tz(&start, &end, TzMode::Absolute));
+/// # assert_eq!(get_diff_tz(&start, &end, TzMode::WallClock).days(), 1);
+/// # assert_eq!(get_diff_tz(&start, &end, TzMode::WallClock).hours(), 0);
+/// # assert_eq!(get_diff_tz(&start, &end, TzMode::Absolute).days(), 0);
+/// # assert_eq!(get_diff_tz(&start, &end, TzMode::Absolute).hours(), 23);
+/// ```
+pub fn get_diff_tz<Tz: TimeZone>(
+    start: &DateTime<Tz>,
+    end: &DateTime<Tz>,
+    mode: TzMode,
+) -> DateTimeInterval {
+    match mode {
+        TzMode::WallClock => get_diff_datetime(&start.naive_local(), &end.naive_local()),
+        TzMode::Absolute => get_diff_datetime(&start.naive_utc(), &end.naive_utc()),
+    }
+}
+
+/// Counts the local midnights crossed from ```start``` to ```end```, i.e.
+/// the days between their dates on the local calendar, which is what "how
+/// many days apart" means to people there. 23:00 to 01:00 the next day is
+/// 1 day, and a day shortened or lengthened by a transition still counts
+/// as 1. Negative when ```end``` is on an earlier local date.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{FixedOffset, TimeZone};
+///
+/// use datediff::calendar_days_between_tz;
+///
+/// let tokyo = FixedOffset::east_opt(9 * 3600).unwrap();
+/// let late = tokyo.with_ymd_and_hms(2021, 3, 1, 23, 0, 0).unwrap();
+/// let early = tokyo.with_ymd_and_hms(2021, 3, 2, 1, 0, 0).unwrap();
+///
+/// # assert_eq!(calendar_days_between_tz(&late, &early), 1);
+/// # assert_eq!(calendar_days_between_tz(&early, &late), -1);
+/// ```
+pub fn calendar_days_between_tz<Tz: TimeZone>(start: &DateTime<Tz>, end: &DateTime<Tz>) -> i64 {
+    end.naive_local()
+        .date()
+        .signed_duration_since(start.naive_local().date())
+        .num_days()
+}
+
+/// Error returned when a zone name isn't in the IANA time zone database.
+#[cfg(feature = "tz")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownZoneError(String);
+
+#[cfg(feature = "tz")]
+impl UnknownZoneError {
+    /// The zone name that was given.
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(feature = "tz")]
+impl fmt::Display for UnknownZoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown time zone: {:?}", self.0)
+    }
+}
+
+#[cfg(feature = "tz")]
+impl error::Error for UnknownZoneError {}
+
+/// Reads ```local``` as a time on the clocks of ```zone```. A time shown
+/// twice when clocks go back is the first of the two instants, and a time
+/// skipped when clocks go forward is moved forward by the length of the
+/// gap, so 02:30 on the day Berlin skips 02:00 to 03:00 is 03:30.
+///
+/// Needs the ```tz``` feature.
+#[cfg(feature = "tz")]
+pub fn localize(zone: Tz, local: &NaiveDateTime) -> DateTime<Tz> {
+    match zone.from_local_datetime(local) {
+        LocalResult::Single(datetime) | LocalResult::Ambiguous(datetime, _) => datetime,
+        LocalResult::None => {
+            //read with the offset in effect before the gap
+            let before = zone
+                .offset_from_utc_datetime(&(*local - Duration::days(1)))
+                .fix();
+            zone.from_utc_datetime(
+                &(*local - Duration::seconds(i64::from(before.local_minus_utc()))),
+            )
+        }
+    }
+}
+
+/// Takes an IANA zone name like ```"Europe/Berlin"``` and two local times
+/// there, and gives the difference like ```get_diff_tz```. Times skipped or
+/// repeated by a transition are resolved as ```localize``` does.
+///
+/// Needs the ```tz``` feature.
+///
+/// # Example
+///
+/// ```
+/// # #[cfg(feature = "tz")]
+/// # {
+/// use chrono::NaiveDate;
+///
+/// use datediff::{get_diff_in_zone, TzMode};
+///
+/// let at = |d, h| NaiveDate::from_ymd_opt(2021, 3, d).unwrap().and_hms_opt(h, 0, 0).unwrap();
+///
+/// let elapsed = get_diff_in_zone("Europe/Berlin", &at(27, 12), &at(28, 12), TzMode::Absolute).unwrap();
+/// # assert_eq!((elapsed.days(), elapsed.hours()), (0, 23));
+/// # assert!(get_diff_in_zone("Mars/Olympus_Mons", &at(27, 12), &at(28, 12), TzMode::Absolute).is_err());
+/// # }
+/// ```
+#[cfg(feature = "tz")]
+pub fn get_diff_in_zone(
+    zone: &str,
+    start: &NaiveDateTime,
+    end: &NaiveDateTime,
+    mode: TzMode,
+) -> Result<DateTimeInterval, UnknownZoneError> {
+    let zone: Tz = zone
+        .parse()
+        .map_err(|_| UnknownZoneError(zone.to_string()))?;
+    Ok(get_diff_tz(
+        &localize(zone, start),
+        &localize(zone, end),
+        mode,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{FixedOffset, TimeZone, Utc};
+
+    use crate::{calendar_days_between_tz, get_diff_tz, TzMode};
+
+    #[test]
+    fn tz() {
+        let cet = FixedOffset::east_opt(3600).unwrap();
+        let cest = FixedOffset::east_opt(2 * 3600).unwrap();
+
+        // spring forward, 2021-03-28 02:00 CET became 03:00 CEST
+        let start = cet.with_ymd_and_hms(2021, 3, 28, 1, 30, 0).unwrap();
+        let end = cest.with_ymd_and_hms(2021, 3, 28, 3, 30, 0).unwrap();
+        let wall = get_diff_tz(&start, &end, TzMode::WallClock);
+        let absolute = get_diff_tz(&start, &end, TzMode::Absolute);
+        assert_eq!((wall.days(), wall.hours()), (0, 2));
+        assert_eq!((absolute.days(), absolute.hours()), (0, 1));
+
+        // fall back, 2021-10-31 03:00 CEST became 02:00 CET
+        let start = cest.with_ymd_and_hms(2021, 10, 30, 12, 0, 0).unwrap();
+        let end = cet.with_ymd_and_hms(2021, 10, 31, 12, 0, 0).unwrap();
+        let wall = get_diff_tz(&start, &end, TzMode::WallClock);
+        let absolute = get_diff_tz(&start, &end, TzMode::Absolute);
+        assert_eq!((wall.days(), wall.hours()), (1, 0));
+        assert_eq!((absolute.days(), absolute.hours()), (1, 1));
+
+        // the repeated hour reads backwards on the wall clock
+        let first = cest.with_ymd_and_hms(2021, 10, 31, 2, 40, 0).unwrap();
+        let second = cet.with_ymd_and_hms(2021, 10, 31, 2, 10, 0).unwrap();
+        let wall = get_diff_tz(&first, &second, TzMode::WallClock);
+        let absolute = get_diff_tz(&first, &second, TzMode::Absolute);
+        assert_eq!((wall.minutes(), wall.positive()), (30, false));
+        assert_eq!((absolute.minutes(), absolute.positive()), (30, true));
+
+        // local midnights, not 24 hour chunks
+        let start = cet.with_ymd_and_hms(2021, 3, 27, 23, 30, 0).unwrap();
+        let end = cest.with_ymd_and_hms(2021, 3, 29, 0, 10, 0).unwrap();
+        assert_eq!(calendar_days_between_tz(&start, &end), 2);
+        assert_eq!(get_diff_tz(&start, &end, TzMode::Absolute).days(), 0);
+        let utc = Utc.with_ymd_and_hms(2021, 3, 1, 23, 30, 0).unwrap();
+        assert_eq!(calendar_days_between_tz(&utc, &utc), 0);
+        assert_eq!(
+            calendar_days_between_tz(&utc, &Utc.with_ymd_and_hms(2021, 2, 28, 0, 0, 0).unwrap()),
+            -1
+        );
+
+        // without offset changes both modes agree
+        let start = Utc.with_ymd_and_hms(2020, 1, 31, 22, 0, 0).unwrap();
+        let end = Utc.with_ymd_and_hms(2021, 3, 1, 6, 15, 0).unwrap();
+        assert_eq!(
+            get_diff_tz(&start, &end, TzMode::WallClock),
+            get_diff_tz(&start, &end, TzMode::Absolute)
+        );
+    }
+
+    #[cfg(feature = "tz")]
+    #[test]
+    fn zone() {
+        use chrono::{NaiveDate, Timelike};
+        use chrono_tz::{Europe::Berlin, Pacific::Apia};
+
+        use crate::{get_diff_in_zone, localize};
+
+        let at = |y, m, d, h, min| {
+            NaiveDate::from_ymd_opt(y, m, d)
+                .unwrap()
+                .and_hms_opt(h, min, 0)
+                .unwrap()
+        };
+
+        // skipped and repeated times
+        let skipped = localize(Berlin, &at(2021, 3, 28, 2, 30));
+        assert_eq!((skipped.hour(), skipped.minute()), (3, 30));
+        let repeated = localize(Berlin, &at(2021, 10, 31, 2, 30));
+        assert_eq!(repeated.naive_utc(), at(2021, 10, 31, 0, 30));
+        // Samoa skipped 2011-12-30 entirely
+        let skipped = localize(Apia, &at(2011, 12, 30, 12, 0));
+        assert_eq!(skipped.naive_local(), at(2011, 12, 31, 12, 0));
+
+        let diff =
+            |start, end, mode| get_diff_in_zone("Europe/Berlin", &start, &end, mode).unwrap();
+        let wall = diff(
+            at(2021, 3, 27, 12, 0),
+            at(2021, 3, 28, 12, 0),
+            TzMode::WallClock,
+        );
+        let absolute = diff(
+            at(2021, 3, 27, 12, 0),
+            at(2021, 3, 28, 12, 0),
+            TzMode::Absolute,
+        );
+        assert_eq!((wall.days(), wall.hours()), (1, 0));
+        assert_eq!((absolute.days(), absolute.hours()), (0, 23));
+        let absolute = diff(
+            at(2021, 10, 31, 0, 0),
+            at(2021, 11, 1, 0, 0),
+            TzMode::Absolute,
+        );
+        assert_eq!((absolute.days(), absolute.hours()), (1, 1));
+
+        let error = get_diff_in_zone(
+            "Europe/Atlantis",
+            &at(2021, 1, 1, 0, 0),
+            &at(2021, 1, 1, 0, 0),
+            TzMode::WallClock,
+        )
+        .unwrap_err();
+        assert_eq!(error.name(), "Europe/Atlantis");
+        assert_eq!(error.to_string(), "unknown time zone: \"Europe/Atlantis\"");
+    }
+}
@@ -0,0 +1,626 @@
+use std::mem;
+
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::{add_months, days_in_month, get_diff};
+
+/// Calendar unit used to count or round differences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum Unit {
+    Year,
+    Quarter,
+    Month,
+    /// Half a month, from the 1st to the 15th and from the 16th to the end
+    /// of the month, as in semimonthly payroll.
+    SemiMonth,
+    /// Two weeks, as in biweekly payroll.
+    Fortnight,
+    Week,
+    Day,
+}
+
+/// Quarter of the year ```date``` falls in, from 1 to 4.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::quarter_of;
+///
+/// # assert_eq!(quarter_of(&NaiveDate::from_ymd_opt(2021, 3, 31).unwrap()), 1);
+/// # assert_eq!(quarter_of(&NaiveDate::from_ymd_opt(2021, 4, 1).unwrap()), 2);
+/// # assert_eq!(quarter_of(&NaiveDate::from_ymd_opt(2021, 12, 31).unwrap()), 4);
+/// ```
+pub fn quarter_of(date: &NaiveDate) -> u32 {
+    date.month0() / 3 + 1
+}
+
+/// Gives no of days in a given quarter (1 to 4) of given year, 90 or 91
+/// for the first quarter and 91 or 92 for the others. Panics for invalid
+/// input.
+///
+/// # Example
+///
+/// ```
+/// use datediff::days_in_quarter;
+///
+/// # assert_eq!(days_in_quarter(2021, 1), 90);
+/// # assert_eq!(days_in_quarter(2020, 1), 91);
+/// # assert_eq!(days_in_quarter(2020, 2), 91);
+/// # assert_eq!(days_in_quarter(2020, 4), 92);
+/// ```
+pub fn days_in_quarter(year: i32, quarter: u32) -> u32 {
+    assert!((1..=4).contains(&quarter), "invalid quarter {}", quarter);
+    let first = (quarter - 1) * 3 + 1;
+    (first..first + 3)
+        .map(|month| days_in_month(year, month))
+        .sum()
+}
+
+/// Counts complete quarters elapsed from ```start``` to ```end```, i.e. the
+/// months of ```get_diff``` divided by 3. Negative when ```end``` is before
+/// ```start```. See ```diff_in``` with ```Unit::Quarter``` to count quarter
+/// boundaries crossed instead.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::quarters_between;
+///
+/// let start = NaiveDate::from_ymd_opt(2021, 2, 15).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2021, 11, 14).unwrap();
+///
+/// println!("{} whole quarters", quarters_between(&start, &end));
+/// # assert_eq!(quarters_between(&start, &end), 2);
+/// # assert_eq!(quarters_between(&end, &start), -2);
+/// ```
+pub fn quarters_between(start: &NaiveDate, end: &NaiveDate) -> i64 {
+    get_diff(start, end).total_months() / 3
+}
+
+fn quarter_index(date: &NaiveDate) -> i64 {
+    i64::from(date.year()) * 4 + i64::from(date.month0() / 3)
+}
+
+fn month_index(date: &NaiveDate) -> i64 {
+    i64::from(date.year()) * 12 + i64::from(date.month0())
+}
+
+fn semi_month_index(date: &NaiveDate) -> i64 {
+    month_index(date) * 2 + if date.day() > 15 { 1 } else { 0 }
+}
+
+/// Counts the boundaries crossed between ```start``` and ```end``` of
+/// fortnights starting on ```anchor``` and every 14 days before and after
+/// it, e.g. a pay date. Negative when ```end``` is before ```start```.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::fortnights_between;
+///
+/// let date = |m, d| NaiveDate::from_ymd_opt(2021, m, d).unwrap();
+/// let payday = date(1, 8);
+///
+/// # assert_eq!(fortnights_between(&date(1, 21), &date(1, 22), &payday), 1);
+/// # assert_eq!(fortnights_between(&date(1, 22), &date(2, 4), &payday), 0);
+/// # assert_eq!(fortnights_between(&date(2, 5), &date(1, 1), &payday), -3);
+/// ```
+pub fn fortnights_between(start: &NaiveDate, end: &NaiveDate, anchor: &NaiveDate) -> i64 {
+    let index = |date: &NaiveDate| {
+        date.signed_duration_since(*anchor)
+            .num_days()
+            .div_euclid(14)
+    };
+    index(end) - index(start)
+}
+
+/// ISO 8601 week-numbering year and week (1 to 53) of ```date```. Days
+/// early in January can belong to the last week of the previous year, and
+/// days late in December to week 1 of the next.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::iso_week_of;
+///
+/// # assert_eq!(iso_week_of(&NaiveDate::from_ymd_opt(2021, 1, 3).unwrap()), (2020, 53));
+/// # assert_eq!(iso_week_of(&NaiveDate::from_ymd_opt(2021, 1, 4).unwrap()), (2021, 1));
+/// # assert_eq!(iso_week_of(&NaiveDate::from_ymd_opt(2024, 12, 30).unwrap()), (2025, 1));
+/// ```
+pub fn iso_week_of(date: &NaiveDate) -> (i32, u32) {
+    let week = date.iso_week();
+    (week.year(), week.week())
+}
+
+/// Gives no of ISO 8601 weeks in a given week-numbering year, 52 or 53.
+///
+/// # Example
+///
+/// ```
+/// use datediff::iso_weeks_in_year;
+///
+/// # assert_eq!(iso_weeks_in_year(2020), 53);
+/// # assert_eq!(iso_weeks_in_year(2021), 52);
+/// ```
+pub fn iso_weeks_in_year(year: i32) -> u32 {
+    //Dec 28 is always in the last week of its year
+    NaiveDate::from_ymd_opt(year, 12, 28)
+        .expect("year out of range")
+        .iso_week()
+        .week()
+}
+
+/// Counts the ISO 8601 week boundaries crossed between ```start``` and
+/// ```end```, i.e. the Mondays passed, so Sunday to Monday is 1 week.
+/// Negative when ```end``` is before ```start```. See
+/// ```full_weeks_between``` to count 7 day weeks instead.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::{full_weeks_between, iso_weeks_between};
+///
+/// let sunday = NaiveDate::from_ymd_opt(2021, 1, 3).unwrap();
+/// let monday = NaiveDate::from_ymd_opt(2021, 1, 4).unwrap();
+///
+/// # assert_eq!(iso_weeks_between(&sunday, &monday), 1);
+/// # assert_eq!(full_weeks_between(&sunday, &monday), 0);
+/// ```
+pub fn iso_weeks_between(start: &NaiveDate, end: &NaiveDate) -> i64 {
+    weeks_between(start, end, Weekday::Mon)
+}
+
+/// Counts full 7 day weeks elapsed from ```start``` to ```end``` whatever
+/// the weekday. Negative when ```end``` is before ```start```.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::full_weeks_between;
+///
+/// let start = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2021, 1, 14).unwrap();
+///
+/// # assert_eq!(full_weeks_between(&start, &end), 1);
+/// # assert_eq!(full_weeks_between(&end, &start), -1);
+/// ```
+pub fn full_weeks_between(start: &NaiveDate, end: &NaiveDate) -> i64 {
+    end.signed_duration_since(*start).num_days() / 7
+}
+
+/// Number of weeks starting on ```week_start``` since the start of the
+/// common era.
+fn week_index(date: &NaiveDate, week_start: Weekday) -> i64 {
+    //0001-01-01 was a Monday and numbered 1
+    (i64::from(date.num_days_from_ce()) - 1 - i64::from(week_start.num_days_from_monday()))
+        .div_euclid(7)
+}
+
+/// Counts the week boundaries crossed between ```start``` and ```end``` for
+/// weeks starting on ```week_start```, e.g. Sunday in the US, Monday in
+/// Europe or Saturday in much of the Middle East. Negative when ```end``` is
+/// before ```start```.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+///
+/// use datediff::weeks_between;
+///
+/// let friday = NaiveDate::from_ymd_opt(2021, 1, 8).unwrap();
+/// let saturday = NaiveDate::from_ymd_opt(2021, 1, 9).unwrap();
+///
+/// # assert_eq!(weeks_between(&friday, &saturday, Weekday::Sat), 1);
+/// # assert_eq!(weeks_between(&friday, &saturday, Weekday::Sun), 0);
+/// ```
+pub fn weeks_between(start: &NaiveDate, end: &NaiveDate, week_start: Weekday) -> i64 {
+    week_index(end, week_start) - week_index(start, week_start)
+}
+
+/// Week of the year ```date``` falls in for weeks starting on
+/// ```week_start```, counting the week with Jan 1 as week 1 like SQL
+/// Server's ```DATEPART(week, date)```. See ```iso_week_of``` for ISO 8601
+/// week numbers.
+///
+/// # Example
+///
+/// ```
+/// use chrono::{NaiveDate, Weekday};
+///
+/// use datediff::week_of_year;
+///
+/// // Jan 1 2021 was a Friday
+/// let date = |m, d| NaiveDate::from_ymd_opt(2021, m, d).unwrap();
+///
+/// # assert_eq!(week_of_year(&date(1, 2), Weekday::Sun), 1);
+/// # assert_eq!(week_of_year(&date(1, 3), Weekday::Sun), 2);
+/// # assert_eq!(week_of_year(&date(1, 3), Weekday::Mon), 1);
+/// # assert_eq!(week_of_year(&date(12, 31), Weekday::Sun), 53);
+/// ```
+pub fn week_of_year(date: &NaiveDate, week_start: Weekday) -> u32 {
+    let new_year = NaiveDate::from_ymd_opt(date.year(), 1, 1).expect("date out of range");
+    weeks_between(&new_year, date, week_start) as u32 + 1
+}
+
+/// Counts the ```unit``` boundaries crossed between ```start``` and ```end```,
+/// like SQL Server's ```DATEDIFF```. Dec 31 to Jan 1 is 1 year even though
+/// only a day has passed, and weeks start on Sunday. Fortnights start on
+/// every other Sunday from 0001-01-07, see ```fortnights_between``` to pick
+/// the start. Negative when ```end``` is before ```start```.
+///
+/// This differs from ```get_diff```, which only counts full elapsed units.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::{diff_in, Unit};
+///
+/// let start = NaiveDate::from_ymd_opt(2019, 12, 31).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2020, 1, 1).unwrap();
+///
+/// println!("Years crossed: {}", diff_in(&start, &end, Unit::Year));
+/// # assert_eq!(diff_in(&start, &end, Unit::Year), 1);
+/// # assert_eq!(diff_in(&start, &end, Unit::Quarter), 1);
+/// # assert_eq!(diff_in(&start, &end, Unit::Month), 1);
+/// # assert_eq!(diff_in(&start, &end, Unit::Week), 0);
+/// # assert_eq!(diff_in(&start, &end, Unit::Day), 1);
+/// # assert_eq!(diff_in(&end, &start, Unit::Year), -1);
+/// ```
+pub fn diff_in(start: &NaiveDate, end: &NaiveDate, unit: Unit) -> i64 {
+    match unit {
+        Unit::Year => i64::from(end.year()) - i64::from(start.year()),
+        Unit::Quarter => quarter_index(end) - quarter_index(start),
+        Unit::Month => month_index(end) - month_index(start),
+        Unit::SemiMonth => semi_month_index(end) - semi_month_index(start),
+        Unit::Fortnight => {
+            fortnights_between(start, end, &NaiveDate::from_ymd_opt(1, 1, 7).unwrap())
+        }
+        Unit::Week => weeks_between(start, end, Weekday::Sun),
+        Unit::Day => end.signed_duration_since(*start).num_days(),
+    }
+}
+
+impl Unit {
+    /// Position from the largest unit to the smallest.
+    fn rank(self) -> u8 {
+        match self {
+            Unit::Year => 0,
+            Unit::Quarter => 1,
+            Unit::Month => 2,
+            Unit::SemiMonth => 3,
+            Unit::Fortnight => 4,
+            Unit::Week => 5,
+            Unit::Day => 6,
+        }
+    }
+}
+
+/// Breaks the difference from ```start``` to ```end``` down into the given
+/// ```units``` only, largest first, e.g. months and days, or weeks and days.
+/// Each unit takes as many whole units as fit and the rest falls to the
+/// next one, so the smallest unit gets the remainder. Whatever is below the
+/// smallest unit is dropped, e.g. the days when only months are asked for.
+///
+/// Months are counted like ```get_diff``` does, a half month is 15 days.
+/// The units may be in any order, repeated ones are counted once. All counts
+/// are negative when ```end``` is before ```start```.
+///
+/// # Example
+///
+/// ```
+/// use chrono::NaiveDate;
+///
+/// use datediff::{get_diff_units, Unit};
+///
+/// let start = NaiveDate::from_ymd_opt(2019, 11, 20).unwrap();
+/// let end = NaiveDate::from_ymd_opt(2021, 2, 3).unwrap();
+///
+/// # assert_eq!(get_diff_units(&start, &end, &[Unit::Month, Unit::Day]), [(Unit::Month, 14), (Unit::Day, 14)]);
+/// # assert_eq!(get_diff_units(&start, &end, &[Unit::Day, Unit::Week]), [(Unit::Week, 63), (Unit::Day, 0)]);
+/// # assert_eq!(get_diff_units(&end, &start, &[Unit::Year]), [(Unit::Year, -1)]);
+/// ```
+pub fn get_diff_units(start: &NaiveDate, end: &NaiveDate, units: &[Unit]) -> Vec<(Unit, i64)> {
+    let (earlier, later, sign) = if start <= end {
+        (*start, *end, 1)
+    } else {
+        (*end, *start, -1)
+    };
+    let mut units = units.to_vec();
+    units.sort_by_key(|unit| unit.rank());
+    units.dedup();
+
+    let mut months = get_diff(&earlier, &later).total_months();
+    let mut months_taken = 0;
+    //days left, known once all month based units are taken
+    let mut days: Option<i64> = None;
+    let days_after = |months_taken| {
+        later
+            .signed_duration_since(add_months(earlier, months_taken))
+            .num_days()
+    };
+    let mut counts = Vec::with_capacity(units.len());
+    for unit in units {
+        let count = match unit {
+            Unit::Year | Unit::Quarter | Unit::Month => {
+                let size = match unit {
+                    Unit::Year => 12,
+                    Unit::Quarter => 3,
+                    _ => 1,
+                };
+                let count = months / size;
+                months -= count * size;
+                months_taken += count * size;
+                count
+            }
+            Unit::SemiMonth => {
+                let whole = mem::take(&mut months);
+                months_taken += whole;
+                let rest = days_after(months_taken);
+                let half = rest >= 15;
+                days = Some(if half { rest - 15 } else { rest });
+                whole * 2 + i64::from(half)
+            }
+            Unit::Fortnight | Unit::Week | Unit::Day => {
+                let size = match unit {
+                    Unit::Fortnight => 14,
+                    Unit::Week => 7,
+                    _ => 1,
+                };
+                let rest = days.unwrap_or_else(|| days_after(months_taken));
+                days = Some(rest % size);
+                rest / size
+            }
+        };
+        counts.push((unit, sign * count));
+    }
+    counts
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+    use crate::{
+        days_in_quarter, diff_in, fortnights_between, full_weeks_between, get_diff_units,
+        iso_week_of, iso_weeks_between, iso_weeks_in_year, quarter_of, quarters_between,
+        week_of_year, weeks_between, Unit,
+    };
+
+    #[test]
+    fn sql_server_vectors() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // SELECT DATEDIFF(<unit>, start, end) on SQL Server
+        let cases = [
+            (date(2005, 12, 31), date(2006, 1, 1), [1, 1, 1, 1, 1]),
+            (date(2020, 1, 1), date(2020, 12, 31), [0, 3, 11, 52, 365]),
+            (date(2020, 3, 31), date(2020, 4, 1), [0, 1, 1, 0, 1]),
+            (date(2021, 1, 2), date(2021, 1, 3), [0, 0, 0, 1, 1]),
+            (date(2021, 1, 3), date(2021, 1, 9), [0, 0, 0, 0, 6]),
+            (date(2020, 2, 29), date(2024, 2, 28), [4, 16, 48, 209, 1460]),
+        ];
+        let units = [
+            Unit::Year,
+            Unit::Quarter,
+            Unit::Month,
+            Unit::Week,
+            Unit::Day,
+        ];
+        for (start, end, expected) in cases.iter() {
+            for (unit, expected) in units.iter().zip(expected.iter()) {
+                assert_eq!(
+                    diff_in(start, end, *unit),
+                    *expected,
+                    "{} {} {:?}",
+                    start,
+                    end,
+                    unit
+                );
+                assert_eq!(
+                    diff_in(end, start, *unit),
+                    -*expected,
+                    "{} {} {:?}",
+                    end,
+                    start,
+                    unit
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn quarters() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        for year in [1900, 2000, 2020, 2021].iter() {
+            let total: u32 = (1..=4).map(|q| days_in_quarter(*year, q)).sum();
+            let days = date(year + 1, 1, 1)
+                .signed_duration_since(date(*year, 1, 1))
+                .num_days();
+            assert_eq!(i64::from(total), days, "{}", year);
+        }
+        let months = (1..=12).map(|m| quarter_of(&date(2021, m, 1)));
+        assert_eq!(
+            months.collect::<Vec<_>>(),
+            [1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4]
+        );
+
+        // whole quarters elapsed against quarter boundaries crossed
+        let cases = [
+            (date(2021, 3, 31), date(2021, 4, 1), 0, 1),
+            (date(2021, 1, 1), date(2021, 4, 1), 1, 1),
+            (date(2021, 1, 15), date(2021, 7, 14), 1, 2),
+            (date(2020, 11, 30), date(2021, 2, 28), 0, 1),
+            (date(2020, 11, 30), date(2022, 3, 1), 5, 5),
+        ];
+        for (start, end, elapsed, crossed) in cases.iter() {
+            assert_eq!(quarters_between(start, end), *elapsed, "{} {}", start, end);
+            assert_eq!(quarters_between(end, start), -*elapsed, "{} {}", end, start);
+            assert_eq!(diff_in(start, end, Unit::Quarter), *crossed);
+        }
+    }
+
+    #[test]
+    fn iso_weeks() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // start, end, ISO boundaries, full weeks
+        let cases = [
+            (date(2021, 1, 3), date(2021, 1, 4), 1, 0),
+            (date(2021, 1, 4), date(2021, 1, 10), 0, 0),
+            (date(2021, 1, 4), date(2021, 1, 11), 1, 1),
+            (date(2021, 1, 10), date(2021, 1, 18), 2, 1),
+            (date(2020, 12, 28), date(2021, 12, 27), 52, 52),
+            (date(2020, 1, 1), date(2021, 1, 1), 52, 52),
+            (date(2019, 12, 30), date(2021, 1, 4), 53, 53),
+        ];
+        for (start, end, iso, full) in cases.iter() {
+            assert_eq!(iso_weeks_between(start, end), *iso, "{} {}", start, end);
+            assert_eq!(iso_weeks_between(end, start), -*iso, "{} {}", end, start);
+            assert_eq!(full_weeks_between(start, end), *full, "{} {}", start, end);
+            assert_eq!(full_weeks_between(end, start), -*full, "{} {}", end, start);
+        }
+
+        // one boundary per week of every week-numbering year
+        for year in 1999..2030 {
+            let first = NaiveDate::from_isoywd_opt(year, 1, chrono::Weekday::Mon).unwrap();
+            let next = NaiveDate::from_isoywd_opt(year + 1, 1, chrono::Weekday::Mon).unwrap();
+            assert_eq!(
+                iso_weeks_between(&first, &next),
+                i64::from(iso_weeks_in_year(year))
+            );
+            assert_eq!(iso_week_of(&first), (year, 1));
+        }
+    }
+
+    #[test]
+    fn week_start() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let start = date(2020, 12, 1);
+
+        for offset in 0..60 {
+            let a = start + Duration::days(offset);
+            for length in 0..30 {
+                let b = a + Duration::days(length);
+                assert_eq!(
+                    weeks_between(&a, &b, Weekday::Sun),
+                    diff_in(&a, &b, Unit::Week)
+                );
+                assert_eq!(
+                    weeks_between(&b, &a, Weekday::Mon),
+                    iso_weeks_between(&b, &a)
+                );
+                // every start of week passed is a boundary
+                for week_start in [Weekday::Sat, Weekday::Wed].iter() {
+                    let crossed = a
+                        .iter_days()
+                        .skip(1)
+                        .take(length as usize)
+                        .filter(|day| day.weekday() == *week_start)
+                        .count();
+                    assert_eq!(weeks_between(&a, &b, *week_start), crossed as i64);
+                }
+            }
+        }
+
+        assert_eq!(week_of_year(&date(2021, 1, 1), Weekday::Sat), 1);
+        assert_eq!(week_of_year(&date(2021, 1, 2), Weekday::Sat), 2);
+        assert_eq!(week_of_year(&date(2023, 1, 1), Weekday::Sun), 1);
+        assert_eq!(week_of_year(&date(2023, 1, 2), Weekday::Mon), 2);
+    }
+
+    #[test]
+    fn payroll_units() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let semi_months = [
+            (date(2021, 1, 15), date(2021, 1, 16), 1),
+            (date(2021, 1, 1), date(2021, 1, 15), 0),
+            (date(2021, 1, 16), date(2021, 1, 31), 0),
+            (date(2021, 1, 31), date(2021, 2, 1), 1),
+            (date(2021, 1, 10), date(2021, 12, 20), 23),
+            (date(2020, 12, 31), date(2021, 1, 16), 2),
+        ];
+        for (start, end, expected) in semi_months.iter() {
+            assert_eq!(diff_in(start, end, Unit::SemiMonth), *expected);
+            assert_eq!(diff_in(end, start, Unit::SemiMonth), -*expected);
+        }
+
+        // fortnights of diff_in start on every other Sunday
+        let starts: Vec<_> = date(2021, 1, 1)
+            .iter_days()
+            .take(60)
+            .filter(|day| diff_in(&day.pred_opt().unwrap(), day, Unit::Fortnight) == 1)
+            .collect();
+        assert!(starts.len() >= 4);
+        for pair in starts.windows(2) {
+            assert_eq!(pair[0].weekday(), Weekday::Sun);
+            assert_eq!(pair[1].signed_duration_since(pair[0]), Duration::days(14));
+        }
+        let anchor = date(2021, 1, 8);
+        for offset in -30..30 {
+            let day = anchor + Duration::days(offset);
+            assert_eq!(
+                fortnights_between(&anchor, &day, &anchor),
+                offset.div_euclid(14)
+            );
+        }
+    }
+
+    #[test]
+    fn units() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let (start, end) = (date(2019, 11, 20), date(2021, 2, 3));
+        // 1 year 2 months 14 days, 441 days
+
+        let cases: [(&[Unit], &[i64]); 8] = [
+            (&[Unit::Year, Unit::Month, Unit::Day], &[1, 2, 14]),
+            (&[Unit::Month, Unit::Day], &[14, 14]),
+            (&[Unit::Week, Unit::Day], &[63, 0]),
+            (&[Unit::Year, Unit::Week, Unit::Day], &[1, 10, 5]),
+            (&[Unit::Quarter, Unit::Month], &[4, 2]),
+            (&[Unit::SemiMonth, Unit::Day], &[28, 14]),
+            (&[Unit::Month, Unit::Fortnight], &[14, 1]),
+            (&[Unit::Day], &[441]),
+        ];
+        for (units, expected) in cases.iter() {
+            let counts = get_diff_units(&start, &end, units);
+            let mut sorted = units.to_vec();
+            sorted.sort_by_key(|unit| unit.rank());
+            assert_eq!(
+                counts,
+                sorted
+                    .iter()
+                    .copied()
+                    .zip(expected.iter().copied())
+                    .collect::<Vec<_>>(),
+                "{:?}",
+                units
+            );
+            let negative = get_diff_units(&end, &start, units);
+            assert!(counts.iter().zip(negative.iter()).all(|(a, b)| a.1 == -b.1));
+        }
+        assert_eq!(
+            get_diff_units(&start, &end, &[Unit::Day, Unit::Month, Unit::Day]),
+            [(Unit::Month, 14), (Unit::Day, 14)]
+        );
+        assert!(get_diff_units(&start, &end, &[]).is_empty());
+    }
+}
@@ -0,0 +1,183 @@
+use std::{
+    convert::TryFrom,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+use crate::{get_diff_datetime, DateTimeInterval};
+
+/// Days from 0001-01-01 to 1970-01-01, as counted by ```num_days_from_ce```.
+const UNIX_EPOCH_DAYS_FROM_CE: i64 = 719_163;
+
+/// UTC date and time ```seconds``` and ```nanos``` after the Unix epoch,
+/// ```None``` outside of chrono's range.
+fn from_unix(seconds: i64, nanos: u32) -> Option<NaiveDateTime> {
+    let days = i32::try_from(seconds.div_euclid(86_400) + UNIX_EPOCH_DAYS_FROM_CE).ok()?;
+    let date = NaiveDate::from_num_days_from_ce_opt(days)?;
+    let time =
+        NaiveTime::from_num_seconds_from_midnight_opt(seconds.rem_euclid(86_400) as u32, nanos)?;
+    Some(date.and_time(time))
+}
+
+/// Takes two Unix timestamps in seconds and gives the difference of the UTC
+/// dates and times they stand for, like ```get_diff_datetime```. Gives
+/// ```None``` when either is outside of the dates chrono supports.
+///
+/// # Example
+///
+/// ```
+/// use datediff::get_diff_unix;
+///
+/// // 2021-01-31 12:00:00 UTC and 2021-03-01 06:30:00 UTC
+/// let diff = get_diff_unix(1_612_094_400, 1_614_580_200).unwrap();
+///
+/// println!("Duration is {}", diff);
+/// # assert_eq!((diff.years(), diff.months(), diff.days()), (0, 0, 28));
+/// # assert_eq!((diff.hours(), diff.minutes()), (18, 30));
+/// # assert_eq!(get_diff_unix(0, i64::MAX), None);
+/// ```
+pub fn get_diff_unix(start: i64, end: i64) -> Option<DateTimeInterval> {
+    Some(get_diff_datetime(
+        &from_unix(start, 0)?,
+        &from_unix(end, 0)?,
+    ))
+}
+
+/// Same as ```get_diff_unix``` for timestamps in milliseconds, as used by
+/// JavaScript and many APIs. The milliseconds are kept in
+/// ```DateTimeInterval::milliseconds```.
+///
+/// # Example
+///
+/// ```
+/// use datediff::get_diff_unix_millis;
+///
+/// let diff = get_diff_unix_millis(1_612_094_400_250, 1_612_094_401_000).unwrap();
+///
+/// # assert_eq!((diff.seconds(), diff.milliseconds()), (0, 750));
+/// # assert_eq!(get_diff_unix_millis(-1, 0).unwrap().milliseconds(), 1);
+/// ```
+pub fn get_diff_unix_millis(start: i64, end: i64) -> Option<DateTimeInterval> {
+    let from_millis = |millis: i64| {
+        from_unix(
+            millis.div_euclid(1000),
+            millis.rem_euclid(1000) as u32 * 1_000_000,
+        )
+    };
+    Some(get_diff_datetime(&from_millis(start)?, &from_millis(end)?))
+}
+
+/// UTC date and time of ```time```, ```None``` outside of chrono's range.
+fn from_system_time(time: SystemTime) -> Option<NaiveDateTime> {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(after) => from_unix(i64::try_from(after.as_secs()).ok()?, after.subsec_nanos()),
+        Err(error) => {
+            //round the seconds up so the nanoseconds count forward again
+            let before = error.duration();
+            let seconds = i64::try_from(before.as_secs()).ok()?;
+            match before.subsec_nanos() {
+                0 => from_unix(-seconds, 0),
+                nanos => from_unix(-seconds - 1, 1_000_000_000 - nanos),
+            }
+        }
+    }
+}
+
+/// Takes two ```std::time::SystemTime``` and gives the difference of the UTC
+/// dates and times they stand for, like ```get_diff_datetime```. Gives
+/// ```None``` when either is outside of the dates chrono supports.
+///
+/// # Example
+///
+/// ```
+/// use std::time::{Duration, SystemTime};
+///
+/// use datediff::get_diff_systemtime;
+///
+/// let now = SystemTime::now();
+/// let later = now + Duration::from_secs(90 * 60);
+///
+/// let diff = get_diff_systemtime(now, later).unwrap();
+/// # assert_eq!((diff.days(), diff.hours(), diff.minutes()), (0, 1, 30));
+/// # assert_eq!(diff.nanoseconds(), 0);
+/// ```
+pub fn get_diff_systemtime(start: SystemTime, end: SystemTime) -> Option<DateTimeInterval> {
+    Some(get_diff_datetime(
+        &from_system_time(start)?,
+        &from_system_time(end)?,
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    use chrono::NaiveDate;
+
+    use crate::{get_diff_datetime, get_diff_systemtime, get_diff_unix, get_diff_unix_millis};
+
+    #[test]
+    fn unix() {
+        let at = |y, m, d, h, min, s| {
+            NaiveDate::from_ymd_opt(y, m, d)
+                .unwrap()
+                .and_hms_opt(h, min, s)
+                .unwrap()
+        };
+        let epoch = at(1970, 1, 1, 0, 0, 0);
+
+        for &(seconds, expected) in &[
+            (0, epoch),
+            (-1, at(1969, 12, 31, 23, 59, 59)),
+            (-86_400, at(1969, 12, 31, 0, 0, 0)),
+            (951_782_400, at(2000, 2, 29, 0, 0, 0)),
+            (2_147_483_648, at(2038, 1, 19, 3, 14, 8)),
+            (-62_135_596_800, at(1, 1, 1, 0, 0, 0)),
+        ] {
+            assert_eq!(
+                get_diff_unix(0, seconds),
+                Some(get_diff_datetime(&epoch, &expected)),
+                "{}",
+                seconds
+            );
+            assert_eq!(
+                get_diff_unix_millis(seconds * 1000, 0),
+                Some(get_diff_datetime(&expected, &epoch)),
+                "{}",
+                seconds
+            );
+        }
+
+        assert_eq!(get_diff_unix(i64::MIN, 0), None);
+        assert_eq!(get_diff_unix(0, i64::MAX), None);
+        assert_eq!(get_diff_unix_millis(i64::MIN, i64::MAX), None);
+
+        let diff = get_diff_unix_millis(-1_500, 1_250).unwrap();
+        assert_eq!((diff.seconds(), diff.milliseconds()), (2, 750));
+        assert!(diff.positive());
+    }
+
+    #[test]
+    fn systemtime() {
+        for &millis in &[0, 1, 999, 1000, 1_612_094_400_250, 951_782_400_000] {
+            for &other in &[0, -1, -999, -1000, -1001, -86_400_000, 1_614_580_200_999] {
+                let system = |millis: i64| {
+                    let offset = Duration::from_millis(millis.unsigned_abs());
+                    if millis < 0 {
+                        UNIX_EPOCH - offset
+                    } else {
+                        UNIX_EPOCH + offset
+                    }
+                };
+                assert_eq!(
+                    get_diff_systemtime(system(millis), system(other)),
+                    get_diff_unix_millis(millis, other),
+                    "{} {}",
+                    millis,
+                    other
+                );
+            }
+        }
+    }
+}